@@ -0,0 +1,193 @@
+use crate::{BigInt, BigUInt, Rational, RoundingMode};
+use crate::rational::pow10;
+
+#[cfg(test)]
+mod test;
+
+mod traits_std;
+
+mod traits_math;
+
+mod math;
+
+/// A decimal number value * 10^-scale with a fixed number of fractional digits.
+///
+/// Addition, subtraction and multiplication are exact, the result has the larger scale of both
+/// operands for `+` and `-` and the sum of both scales for `*`. Division and reducing the scale
+/// take an explicit [RoundingMode].
+///
+/// Values compare equal by value, so `1.50 == 1.5`, while [BigDecimal::scale] still tells them
+/// apart.
+#[derive(Clone)]
+pub struct BigDecimal {
+    value: BigInt,
+    scale: u32,
+}
+
+impl BigDecimal {
+    /// Create a BigDecimal with value zero and scale zero
+    pub fn new() -> BigDecimal {
+        BigDecimal {
+            value: BigInt::new(),
+            scale: 0,
+        }
+    }
+
+    /// Create the BigDecimal value * 10^-scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigDecimal, BigInt};
+    /// let amount = BigDecimal::from_parts(BigInt::from(-123456), 2);
+    /// assert_eq!(amount.to_string(), "-1234.56");
+    /// ```
+    pub fn from_parts(value: BigInt, scale: u32) -> BigDecimal {
+        BigDecimal { value, scale }
+    }
+
+    /// Parse a decimal number like `-1234.5600`, the scale is the number of fractional digits.
+    ///
+    /// # Arguments
+    /// * src - an optional sign, at least one digit and an optional fraction of at least one digit
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigDecimal;
+    /// let amount = BigDecimal::from_dec_str("-1234.5600").expect("invalid amount");
+    /// assert_eq!(amount.scale(), 4);
+    /// assert_eq!(amount.value().to_dec_str(), "-12345600");
+    /// assert!(BigDecimal::from_dec_str("12.").is_err());
+    /// ```
+    pub fn from_dec_str(src: &str) -> Result<BigDecimal, String> {
+        let (negative, body) = match src.strip_prefix('-') {
+            Some(body) => (true, body),
+            None => (false, src.strip_prefix('+').unwrap_or(src)),
+        };
+        let (int_part, frac_part) = match body.split_once('.') {
+            Some((int_part, frac_part)) => {
+                if frac_part.is_empty() {
+                    return Err(format!("missing digits after the decimal point: '{}'", src));
+                }
+                (int_part, frac_part)
+            }
+            None => (body, ""),
+        };
+        if int_part.is_empty() {
+            return Err(format!("missing digits before the decimal point: '{}'", src));
+        }
+        let scale = u32::try_from(frac_part.len())
+            .map_err(|_| format!("too many fractional digits: {}", frac_part.len()))?;
+        let magnitude = BigUInt::from_dec_str(&format!("{}{}", int_part, frac_part))?;
+        Ok(BigDecimal {
+            value: BigInt::from_sign_magnitude(negative, magnitude),
+            scale,
+        })
+    }
+
+    /// Round a Rational to a BigDecimal with the given scale.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigDecimal, Rational, RoundingMode};
+    /// let third = BigDecimal::from_rational(&Rational::from((-1, 3)), 3, RoundingMode::Floor);
+    /// assert_eq!(third.to_string(), "-0.334");
+    /// ```
+    pub fn from_rational(value: &Rational, scale: u32, mode: RoundingMode) -> BigDecimal {
+        let numerator = value.numerator().mul_with(&pow10(scale as usize));
+        BigDecimal::from_quotient(value.is_negative(), &numerator, value.denominator(), scale, mode)
+    }
+
+    /// Convert to the exactly equal Rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigDecimal, Rational};
+    /// let amount = BigDecimal::from_dec_str("-0.250").expect("invalid amount");
+    /// assert_eq!(amount.to_rational(), Rational::from((-1, 4)));
+    /// ```
+    pub fn to_rational(&self) -> Rational {
+        Rational::from(self.value.clone()).div_by(&Rational::from(pow10(self.scale as usize)))
+    }
+
+    /// Change the scale, rounding if the scale is reduced.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigDecimal, RoundingMode};
+    /// let amount = BigDecimal::from_dec_str("2.345").expect("invalid amount");
+    /// assert_eq!(amount.rescale(2, RoundingMode::HalfEven).to_string(), "2.34");
+    /// assert_eq!(amount.rescale(2, RoundingMode::HalfAwayFromZero).to_string(), "2.35");
+    /// assert_eq!(amount.rescale(5, RoundingMode::HalfEven).to_string(), "2.34500");
+    /// ```
+    pub fn rescale(&self, scale: u32, mode: RoundingMode) -> BigDecimal {
+        if scale >= self.scale {
+            BigDecimal {
+                value: self.value.mul_with(&BigInt::from(pow10((scale - self.scale) as usize))),
+                scale,
+            }
+        } else {
+            BigDecimal::from_quotient(self.value.is_negative(), self.value.magnitude(),
+                                      &pow10((self.scale - scale) as usize), scale, mode)
+        }
+    }
+
+    #[inline]
+    pub fn value(&self) -> &BigInt {
+        &self.value
+    }
+
+    #[inline]
+    pub fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        self.value.is_positive()
+    }
+
+    pub fn abs(&self) -> BigDecimal {
+        BigDecimal {
+            value: self.value.abs(),
+            scale: self.scale,
+        }
+    }
+
+    /// Create the BigDecimal +/- numerator / denominator * 10^-scale, rounded to an integer value
+    fn from_quotient(negative: bool, numerator: &BigUInt, denominator: &BigUInt, scale: u32,
+                     mode: RoundingMode) -> BigDecimal {
+        assert!(!denominator.is_zero(), "division by zero");
+        let (mut quotient, remainder) = numerator.div_mod(denominator);
+        if mode.round_up(negative, &quotient, &remainder, denominator) {
+            quotient.add_into(&1u32.into());
+        }
+        BigDecimal {
+            value: BigInt::from_sign_magnitude(negative, quotient),
+            scale,
+        }
+    }
+
+    /// The values of self and other as integers at the larger of both scales
+    fn aligned(&self, other: &Self) -> (BigInt, BigInt, u32) {
+        let scale = u32::max(self.scale, other.scale);
+        (self.rescale(scale, RoundingMode::HalfEven).value,
+         other.rescale(scale, RoundingMode::HalfEven).value,
+         scale)
+    }
+}
+
+impl Default for BigDecimal {
+    fn default() -> Self {
+        BigDecimal::new()
+    }
+}
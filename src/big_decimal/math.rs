@@ -0,0 +1,77 @@
+use crate::{BigInt, RoundingMode};
+use crate::rational::pow10;
+use super::BigDecimal;
+
+impl BigDecimal {
+    /// Add two BigDecimals, the result has the larger scale of both.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigDecimal;
+    /// let a = BigDecimal::from_dec_str("10.5").expect("invalid amount");
+    /// let b = BigDecimal::from_dec_str("-0.25").expect("invalid amount");
+    /// assert_eq!(a.add_to(&b).to_string(), "10.25");
+    /// ```
+    pub fn add_to(&self, other: &Self) -> BigDecimal {
+        let (lhs, rhs, scale) = self.aligned(other);
+        BigDecimal::from_parts(lhs.add_to(&rhs), scale)
+    }
+
+    pub fn add_into(&mut self, other: &Self) {
+        *self = self.add_to(other);
+    }
+
+    /// Subtract other from self, the result has the larger scale of both.
+    pub fn sub_from(&self, other: &Self) -> BigDecimal {
+        let (lhs, rhs, scale) = self.aligned(other);
+        BigDecimal::from_parts(lhs.sub_from(&rhs), scale)
+    }
+
+    pub fn sub_into(&mut self, other: &Self) {
+        *self = self.sub_from(other);
+    }
+
+    /// Multiply two BigDecimals, the result has the sum of both scales.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigDecimal;
+    /// let price = BigDecimal::from_dec_str("19.99").expect("invalid amount");
+    /// let quantity = BigDecimal::from_dec_str("-1.5").expect("invalid amount");
+    /// assert_eq!(price.mul_by(&quantity).to_string(), "-29.985");
+    /// ```
+    pub fn mul_by(&self, other: &Self) -> BigDecimal {
+        let value = self.value.mul_with(&other.value);
+        BigDecimal::from_parts(BigInt::from_sign_magnitude(value.is_negative(), value.as_unsigned()),
+                               self.scale + other.scale)
+    }
+
+    pub fn mul_into(&mut self, other: &Self) {
+        *self = self.mul_by(other);
+    }
+
+    /// Divide self by other, rounding the quotient to the given scale.
+    ///
+    /// # Panics
+    /// Panics if other is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigDecimal, RoundingMode};
+    /// let total = BigDecimal::from_dec_str("100.00").expect("invalid amount");
+    /// let parts = BigDecimal::from_dec_str("3").expect("invalid amount");
+    /// assert_eq!(total.div_to_scale(&parts, 2, RoundingMode::HalfEven).to_string(), "33.33");
+    /// assert_eq!(total.div_to_scale(&parts, 2, RoundingMode::Ceiling).to_string(), "33.34");
+    /// ```
+    pub fn div_to_scale(&self, other: &Self, scale: u32, mode: RoundingMode) -> BigDecimal {
+        // self / other = (a / b) * 10^(other.scale - self.scale), the result value is
+        // a * 10^(scale + other.scale - self.scale) / b
+        let exp = scale as i64 + other.scale as i64 - self.scale as i64;
+        let (numerator, denominator) = if exp >= 0 {
+            (self.value.magnitude().mul_with(&pow10(exp as usize)), other.value.magnitude().clone())
+        } else {
+            (self.value.magnitude().clone(), other.value.magnitude().mul_with(&pow10(exp.unsigned_abs() as usize)))
+        };
+        BigDecimal::from_quotient(self.is_negative() ^ other.is_negative(), &numerator, &denominator, scale, mode)
+    }
+}
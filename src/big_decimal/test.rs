@@ -0,0 +1,88 @@
+use crate::{BigDecimal, BigInt, Rational, RoundingMode};
+
+fn dec(src: &str) -> BigDecimal {
+    BigDecimal::from_dec_str(src).expect("invalid decimal")
+}
+
+#[test]
+fn test_parse_format() {
+    for src in ["0", "-1234.5600", "0.001", "-0.10", "123456789012345678901234567890123456789012.000000001"] {
+        assert_eq!(dec(src).to_string(), src);
+    }
+    assert_eq!(dec("+7.50").to_string(), "7.50");
+    assert_eq!(dec("-0.00").to_string(), "0.00");
+    assert_eq!(dec("-1234.5600").scale(), 4);
+    assert_eq!("12.5".parse::<BigDecimal>(), Ok(dec("12.50")));
+    for src in ["", "-", ".5", "1.", "1.2.3", "1,5", "--1", "1e5"] {
+        assert!(BigDecimal::from_dec_str(src).is_err(), "{}", src);
+    }
+}
+
+#[test]
+fn test_add_sub_mul() {
+    let a = dec("1234.56");
+    let b = dec("-0.005");
+    assert_eq!(a.add_to(&b).to_string(), "1234.555");
+    assert_eq!((&a - &b).to_string(), "1234.565");
+    assert_eq!((&b - &a).to_string(), "-1234.565");
+    assert_eq!((&a * &b).to_string(), "-6.17280");
+    assert_eq!((&b * &b).to_string(), "0.000025");
+    assert_eq!((&a - &a).to_string(), "0.00");
+    assert_eq!((&a * &BigDecimal::new()).to_string(), "0.00");
+
+    let mut c = dec("99999999999999999999999999999999999999999.99");
+    c += dec("0.01");
+    assert_eq!(c.to_string(), "100000000000000000000000000000000000000000.00");
+    c -= dec("0.001");
+    assert_eq!(c.to_string(), "99999999999999999999999999999999999999999.999");
+    c *= dec("-2");
+    assert_eq!(c.to_string(), "-199999999999999999999999999999999999999999.998");
+}
+
+#[test]
+fn test_div_rescale() {
+    let one = dec("1.00");
+    let three = dec("3");
+    assert_eq!(one.div_to_scale(&three, 4, RoundingMode::HalfEven).to_string(), "0.3333");
+    assert_eq!(one.div_to_scale(&three, 4, RoundingMode::Ceiling).to_string(), "0.3334");
+    assert_eq!(one.div_to_scale(&dec("-3"), 4, RoundingMode::Floor).to_string(), "-0.3334");
+    assert_eq!(one.div_to_scale(&dec("-3"), 4, RoundingMode::TowardZero).to_string(), "-0.3333");
+    assert_eq!(dec("1000").div_to_scale(&dec("0.0003"), 0, RoundingMode::HalfEven).to_string(), "3333333");
+    assert_eq!(dec("10").div_to_scale(&dec("4"), 0, RoundingMode::HalfEven).to_string(), "2");
+    assert_eq!(dec("10").div_to_scale(&dec("4"), 0, RoundingMode::HalfUp).to_string(), "3");
+
+    assert_eq!(dec("-2.5").rescale(0, RoundingMode::HalfEven).to_string(), "-2");
+    assert_eq!(dec("-2.5").rescale(0, RoundingMode::HalfAwayFromZero).to_string(), "-3");
+    assert_eq!(dec("-2.5").rescale(0, RoundingMode::HalfUp).to_string(), "-2");
+    assert_eq!(dec("-2.5").rescale(3, RoundingMode::HalfEven).to_string(), "-2.500");
+    assert_eq!(dec("0.04").rescale(1, RoundingMode::Floor).to_string(), "0.0");
+    assert_eq!(dec("-0.04").rescale(1, RoundingMode::TowardZero).to_string(), "0.0");
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    dec("1").div_to_scale(&dec("0.00"), 2, RoundingMode::HalfEven);
+}
+
+#[test]
+fn test_cmp() {
+    assert_eq!(dec("1.50"), dec("1.5"));
+    assert!(dec("1.51") > dec("1.5"));
+    assert!(dec("-1.51") < dec("-1.5"));
+    assert!(dec("-0.01") < BigDecimal::new());
+    assert_eq!(BigDecimal::from(-15i64), dec("-15.000"));
+    assert_eq!(BigDecimal::from(BigInt::from(7)), dec("7"));
+}
+
+#[test]
+fn test_rational_conversion() {
+    assert_eq!(dec("-1234.5600").to_rational(), Rational::from((-30864, 25)));
+    assert_eq!(Rational::from(dec("0.000")), Rational::new());
+    assert_eq!(BigDecimal::try_from(Rational::from((-30864, 25))).map(|d| d.to_string()), Ok("-1234.56".to_string()));
+    assert_eq!(BigDecimal::try_from(&Rational::from((1, 1024))).map(|d| d.scale()), Ok(10));
+    assert_eq!(BigDecimal::try_from(&Rational::from(42)).map(|d| d.to_string()), Ok("42".to_string()));
+    assert!(BigDecimal::try_from(&Rational::from((1, 3))).is_err());
+    assert!(BigDecimal::try_from(&Rational::from((7, 30))).is_err());
+    assert_eq!(BigDecimal::from_rational(&Rational::from((2, 3)), 2, RoundingMode::HalfEven).to_string(), "0.67");
+}
@@ -0,0 +1,108 @@
+use std::ops::{ AddAssign, Add, SubAssign, Sub, MulAssign, Mul};
+
+use super::BigDecimal;
+
+impl Add for BigDecimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add_to(&other)
+    }
+}
+
+impl<'a> Add<&'a Self> for BigDecimal {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl<'a> Add<&'a BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn add(self, other: &'a BigDecimal) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl AddAssign for BigDecimal {
+    fn add_assign(&mut self, other: Self) {
+        self.add_into(&other);
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for BigDecimal {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.add_into(other);
+    }
+}
+
+impl Sub for BigDecimal {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_from(&other)
+    }
+}
+
+impl<'a> Sub<&'a Self> for BigDecimal {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl<'a> Sub<&'a BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+    fn sub(self, other: &'a BigDecimal) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl SubAssign for BigDecimal {
+    fn sub_assign(&mut self, other: Self) {
+        self.sub_into(&other)
+    }
+}
+
+impl<'a> SubAssign<&'a Self> for BigDecimal {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.sub_into(other)
+    }
+}
+
+impl Mul<Self> for BigDecimal {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_by(&other)
+    }
+}
+
+impl<'a> Mul<&'a Self> for BigDecimal {
+    type Output = Self;
+
+    fn mul(self, other: &'a Self) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl<'a> Mul<&'a BigDecimal> for &BigDecimal {
+    type Output = BigDecimal;
+
+    fn mul(self, other: &'a BigDecimal) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl MulAssign for BigDecimal {
+    fn mul_assign(&mut self, other: Self) {
+        self.mul_into(&other)
+    }
+}
+
+impl<'a> MulAssign<&'a Self> for BigDecimal {
+    fn mul_assign(&mut self, other: &'a Self) {
+        self.mul_into(other)
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{BigDecimal, BigInt, BigUInt, Rational};
+use std::fmt::{Debug, Formatter, Display};
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+impl PartialEq for BigDecimal {
+    /// BigDecimals are compared by value, the scale is ignored
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BigDecimal {}
+
+impl PartialOrd for BigDecimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigDecimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (lhs, rhs, _) = self.aligned(other);
+        lhs.cmp(&rhs)
+    }
+}
+
+impl From<BigInt> for BigDecimal {
+    fn from(value: BigInt) -> Self {
+        BigDecimal::from_parts(value, 0)
+    }
+}
+
+impl From<i64> for BigDecimal {
+    fn from(value: i64) -> Self {
+        BigDecimal::from_parts(BigInt::from_sign_magnitude(value < 0, BigUInt::from(value.unsigned_abs())), 0)
+    }
+}
+
+impl From<BigDecimal> for Rational {
+    fn from(value: BigDecimal) -> Self {
+        value.to_rational()
+    }
+}
+
+impl TryFrom<&Rational> for BigDecimal {
+    type Error = String;
+
+    /// Convert a Rational to a BigDecimal with the smallest scale that represents it exactly.
+    ///
+    /// Only fractions whose reduced denominator has no prime factors other than 2 and 5 have a
+    /// finite decimal representation, all others are rejected.
+    fn try_from(value: &Rational) -> Result<Self, Self::Error> {
+        let two = BigUInt::from(2u32);
+        let five = BigUInt::from(5u32);
+        let mut denominator = value.denominator().clone();
+        let mut twos = 0u32;
+        let mut fives = 0u32;
+        loop {
+            let (quotient, remainder) = denominator.div_mod(&two);
+            if !remainder.is_zero() {
+                break;
+            }
+            denominator = quotient;
+            twos += 1;
+        }
+        loop {
+            let (quotient, remainder) = denominator.div_mod(&five);
+            if !remainder.is_zero() {
+                break;
+            }
+            denominator = quotient;
+            fives += 1;
+        }
+        if denominator != BigUInt::from(1u32) {
+            return Err(format!("{} has no finite decimal representation", value));
+        }
+        Ok(BigDecimal::from_rational(value, u32::max(twos, fives), crate::RoundingMode::HalfEven))
+    }
+}
+
+impl TryFrom<Rational> for BigDecimal {
+    type Error = String;
+
+    fn try_from(value: Rational) -> Result<Self, Self::Error> {
+        BigDecimal::try_from(&value)
+    }
+}
+
+impl FromStr for BigDecimal {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        BigDecimal::from_dec_str(src)
+    }
+}
+
+impl Debug for BigDecimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?}, scale: {})", self.value, self.scale)
+    }
+}
+
+impl Display for BigDecimal {
+    /// Display the BigDecimal with exactly scale fractional digits like `-1234.5600`
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let digits = self.value.magnitude().to_dec_string();
+        let scale = self.scale as usize;
+        let sign = if self.is_negative() { "-" } else { "" };
+        if scale == 0 {
+            write!(f, "{}{}", sign, digits)
+        } else if digits.len() <= scale {
+            write!(f, "{}0.{}{}", sign, "0".repeat(scale - digits.len()), digits)
+        } else {
+            let (int_part, frac_part) = digits.split_at(digits.len() - scale);
+            write!(f, "{}{}.{}", sign, int_part, frac_part)
+        }
+    }
+}
@@ -0,0 +1,298 @@
+use crate::{BigInt, BigUInt, Rational, RoundingMode};
+
+#[cfg(test)]
+mod test;
+
+mod traits_std;
+
+mod traits_math;
+
+mod math;
+
+/// A binary floating point number mantissa * 2^exponent of arbitrary precision.
+///
+/// Every value carries its precision, the maximum number of bits of the mantissa. Operations
+/// on two values round their exact result to the larger of both precisions, using the given
+/// rounding mode or, for the operators, [RoundingMode::HalfEven]. The mantissa is kept free of
+/// trailing zero bits, so equal values have equal representations.
+#[derive(Clone)]
+pub struct BigFloat {
+    mantissa: BigInt,
+    exponent: i64,
+    precision: usize,
+}
+
+impl BigFloat {
+    /// Create a BigFloat with value zero and the given precision in bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigFloat;
+    /// let bf = BigFloat::new(64);
+    /// assert!(bf.is_zero());
+    /// assert_eq!(bf.precision(), 64);
+    /// ```
+    pub fn new(precision: usize) -> BigFloat {
+        assert!(precision > 0, "precision must be at least one bit");
+        BigFloat {
+            mantissa: BigInt::new(),
+            exponent: 0,
+            precision,
+        }
+    }
+
+    /// Create a BigFloat from an integer, rounded to precision bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, BigInt, RoundingMode};
+    /// let bf = BigFloat::from_big_int(&BigInt::from(1023), 8, RoundingMode::HalfEven);
+    /// assert_eq!(bf.to_f64(), 1024.0);
+    /// let bf = BigFloat::from_big_int(&BigInt::from(1023), 8, RoundingMode::TowardZero);
+    /// assert_eq!(bf.to_f64(), 1020.0);
+    /// ```
+    pub fn from_big_int(value: &BigInt, precision: usize, mode: RoundingMode) -> BigFloat {
+        BigFloat::from_parts(value.is_negative(), value.magnitude().clone(), 0, precision, mode)
+    }
+
+    /// Create a BigFloat from a Rational, rounded to precision bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, Rational, RoundingMode};
+    /// let third = Rational::from((1, 3));
+    /// let down = BigFloat::from_rational(&third, 53, RoundingMode::Floor);
+    /// let up = BigFloat::from_rational(&third, 53, RoundingMode::Ceiling);
+    /// assert!(down.to_rational() < third && third < up.to_rational());
+    /// assert_eq!(BigFloat::from_rational(&third, 53, RoundingMode::HalfEven).to_f64(), 1.0 / 3.0);
+    /// ```
+    pub fn from_rational(value: &Rational, precision: usize, mode: RoundingMode) -> BigFloat {
+        BigFloat::from_quotient(value.is_negative(), value.numerator(), 0, value.denominator(), precision, mode)
+    }
+
+    /// Create a BigFloat holding the exact value of an f64, with a precision of 53 bits.
+    ///
+    /// # Returns
+    /// The BigFloat or an error if value is infinite or NaN
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigFloat;
+    /// let bf = BigFloat::from_f64(-0.75).expect("invalid f64");
+    /// assert_eq!(bf.mantissa().to_i64(), Some(-3));
+    /// assert_eq!(bf.exponent(), -2);
+    /// assert!(BigFloat::from_f64(f64::NAN).is_err());
+    /// ```
+    pub fn from_f64(value: f64) -> Result<BigFloat, String> {
+        if !value.is_finite() {
+            return Err(format!("cannot convert {} to BigFloat", value));
+        }
+        let bits = value.to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i64;
+        let fraction = bits & 0xF_FFFF_FFFF_FFFF;
+        let (mantissa, exponent) = if biased == 0 {
+            // zero or subnormal
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased - 1075)
+        };
+        Ok(BigFloat::from_parts(value.is_sign_negative(), BigUInt::from(mantissa), exponent,
+                                f64::MANTISSA_DIGITS as usize, RoundingMode::HalfEven))
+    }
+
+    /// Convert to the nearest f64, ties to even.
+    ///
+    /// Values too large for f64 become infinite, values too small become zero or subnormal
+    /// numbers, rounded correctly in both cases.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, Rational, RoundingMode};
+    /// let bf = BigFloat::from_rational(&Rational::from((1, 10)), 200, RoundingMode::HalfEven);
+    /// assert_eq!(bf.to_f64(), 0.1);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        if self.is_zero() {
+            return if self.is_negative() { -0.0 } else { 0.0 };
+        }
+        // round to 53 bits or, for subnormal results, to a multiple of 2^-1074
+        let lsb = i64::max(self.top() - f64::MANTISSA_DIGITS as i64, -1074);
+        let (mantissa, exponent) = BigFloat::round_parts(
+            self.is_negative(), self.mantissa.magnitude(), self.exponent, lsb, RoundingMode::HalfEven);
+        let res = if mantissa.is_zero() {
+            0.0
+        } else if exponent + mantissa.length() as i64 > 1024 {
+            f64::INFINITY
+        } else {
+            let mantissa = mantissa.to_u64().expect("Unexpected mantissa larger than 53 bits") as f64;
+            // both factors and the product are exactly representable
+            mantissa * pow2_f64(exponent)
+        };
+        if self.is_negative() { -res } else { res }
+    }
+
+    /// Convert to the exactly equal Rational.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, Rational};
+    /// let bf = BigFloat::from_f64(-2.5).expect("invalid f64");
+    /// assert_eq!(bf.to_rational(), Rational::from((-5, 2)));
+    /// ```
+    pub fn to_rational(&self) -> Rational {
+        let magnitude = self.mantissa.magnitude().clone();
+        if self.exponent >= 0 {
+            Rational::from(BigInt::from_sign_magnitude(self.is_negative(), magnitude << self.exponent as usize))
+        } else {
+            // the mantissa is odd so the fraction is reduced
+            Rational::from((BigInt::from_sign_magnitude(self.is_negative(), magnitude),
+                            BigInt::from(BigUInt::from(1u32) << self.exponent.unsigned_abs() as usize)))
+        }
+    }
+
+    /// Round to a new precision.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, RoundingMode};
+    /// let bf = BigFloat::from_f64(1.0 + 1.0 / 1024.0).expect("invalid f64");
+    /// assert_eq!(bf.with_precision(8, RoundingMode::Ceiling).to_f64(), 1.0 + 1.0 / 128.0);
+    /// assert_eq!(bf.with_precision(8, RoundingMode::Floor).to_f64(), 1.0);
+    /// ```
+    pub fn with_precision(&self, precision: usize, mode: RoundingMode) -> BigFloat {
+        BigFloat::from_parts(self.is_negative(), self.mantissa.magnitude().clone(), self.exponent, precision, mode)
+    }
+
+    /// Format as a decimal number with a fixed number of fractional digits
+    pub fn to_decimal_string(&self, digits: usize, mode: RoundingMode) -> String {
+        self.to_rational().to_decimal_string(digits, mode)
+    }
+
+    /// Format in scientific notation like `1.2345e-7`
+    pub fn to_scientific_string(&self, significant_digits: usize, mode: RoundingMode) -> String {
+        self.to_rational().to_scientific_string(significant_digits, mode)
+    }
+
+    #[inline]
+    pub fn precision(&self) -> usize {
+        self.precision
+    }
+
+    #[inline]
+    pub fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    #[inline]
+    pub fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    #[inline]
+    pub fn is_negative(&self) -> bool {
+        self.mantissa.is_negative()
+    }
+
+    #[inline]
+    pub fn is_positive(&self) -> bool {
+        self.mantissa.is_positive()
+    }
+
+    pub fn abs(&self) -> BigFloat {
+        BigFloat {
+            mantissa: self.mantissa.abs(),
+            exponent: self.exponent,
+            precision: self.precision,
+        }
+    }
+
+    /// The smallest t with |self| < 2^t, self must not be zero
+    #[inline]
+    fn top(&self) -> i64 {
+        self.exponent + self.mantissa.magnitude().length() as i64
+    }
+
+    /// Round the magnitude * 2^exponent to a multiple of 2^lsb.
+    ///
+    /// Returns the rounded magnitude and its exponent, which is the larger of exponent and lsb.
+    fn round_parts(negative: bool, magnitude: &BigUInt, exponent: i64, lsb: i64, mode: RoundingMode) -> (BigUInt, i64) {
+        if exponent >= lsb {
+            return (magnitude.clone(), exponent);
+        }
+        let shift = (lsb - exponent) as usize;
+        let divisor = BigUInt::from(1u32) << shift;
+        let (mut quotient, remainder) = if shift >= magnitude.length() {
+            (BigUInt::new(), magnitude.clone())
+        } else {
+            let quotient = magnitude.clone() >> shift;
+            let remainder = magnitude.sub_from(&(quotient.clone() << shift));
+            (quotient, remainder)
+        };
+        if mode.round_up(negative, &quotient, &remainder, &divisor) {
+            quotient.add_into(&1u32.into());
+        }
+        (quotient, lsb)
+    }
+
+    /// Create a BigFloat from the exact value +/- magnitude * 2^exponent, rounded to precision bits
+    fn from_parts(negative: bool, magnitude: BigUInt, exponent: i64, precision: usize, mode: RoundingMode) -> BigFloat {
+        assert!(precision > 0, "precision must be at least one bit");
+        let length = magnitude.length() as i64;
+        let (mut magnitude, mut exponent) = if length > precision as i64 {
+            BigFloat::round_parts(negative, &magnitude, exponent, exponent + length - precision as i64, mode)
+        } else {
+            (magnitude, exponent)
+        };
+        if magnitude.is_zero() {
+            exponent = 0;
+        } else {
+            let zeros = magnitude.trailing_zeros() as usize;
+            if zeros > 0 {
+                magnitude >>= zeros;
+                exponent += zeros as i64;
+            }
+        }
+        BigFloat {
+            mantissa: BigInt::from_sign_magnitude(negative, magnitude),
+            exponent,
+            precision,
+        }
+    }
+
+    /// Create a BigFloat from +/- numerator * 2^exponent / denominator, rounded to precision bits
+    fn from_quotient(negative: bool, numerator: &BigUInt, exponent: i64, denominator: &BigUInt,
+                     precision: usize, mode: RoundingMode) -> BigFloat {
+        assert!(!denominator.is_zero(), "division by zero");
+        // scale the numerator so the quotient has at least precision + 2 bits
+        let shift = usize::try_from(precision as i64 + 2 + denominator.length() as i64 - numerator.length() as i64)
+            .unwrap_or(0);
+        let (quotient, remainder) = (numerator.clone() << shift).div_mod(denominator);
+        BigFloat::from_sticky(negative, quotient, !remainder.is_zero(), exponent - shift as i64, precision, mode)
+    }
+
+    /// Create a BigFloat from a truncated result +/- (magnitude + f) * 2^exponent with 0 <= f < 1
+    /// and f > 0 if inexact. The magnitude must have more than precision bits.
+    fn from_sticky(negative: bool, magnitude: BigUInt, inexact: bool, exponent: i64, precision: usize,
+                   mode: RoundingMode) -> BigFloat {
+        // appending a sticky bit keeps the rounding decision of the exact value
+        let mut magnitude = magnitude << 1;
+        if inexact {
+            magnitude.add_into(&1u32.into());
+        }
+        BigFloat::from_parts(negative, magnitude, exponent - 1, precision, mode)
+    }
+}
+
+/// Calculate 2^exponent as f64, exponent must be in the range -1074..=1023
+fn pow2_f64(exponent: i64) -> f64 {
+    if exponent >= -1022 {
+        f64::from_bits(((exponent + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1u64 << (exponent + 1074))
+    }
+}
@@ -0,0 +1,173 @@
+use std::cmp::Ordering;
+
+use crate::{BigUInt, RoundingMode};
+use super::BigFloat;
+
+impl BigFloat {
+    /// Add two BigFloats, rounding the exact sum to the larger of both precisions.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, RoundingMode};
+    /// let one = BigFloat::from_f64(1.0).expect("invalid f64").with_precision(4, RoundingMode::HalfEven);
+    /// let tiny = BigFloat::from_f64(1e-30).expect("invalid f64").with_precision(4, RoundingMode::HalfEven);
+    /// assert_eq!(one.add_rounded(&tiny, RoundingMode::HalfEven).to_f64(), 1.0);
+    /// assert_eq!(one.add_rounded(&tiny, RoundingMode::Ceiling).to_f64(), 1.125);
+    /// ```
+    pub fn add_rounded(&self, other: &Self, mode: RoundingMode) -> BigFloat {
+        self.add_signed(other, other.is_negative(), mode)
+    }
+
+    /// Subtract other from self, rounding the exact difference to the larger of both precisions.
+    pub fn sub_rounded(&self, other: &Self, mode: RoundingMode) -> BigFloat {
+        self.add_signed(other, !other.is_negative(), mode)
+    }
+
+    /// Multiply two BigFloats, rounding the exact product to the larger of both precisions.
+    pub fn mul_rounded(&self, other: &Self, mode: RoundingMode) -> BigFloat {
+        BigFloat::from_parts(
+            self.is_negative() ^ other.is_negative(),
+            self.mantissa.magnitude().mul_with(other.mantissa.magnitude()),
+            self.exponent + other.exponent,
+            usize::max(self.precision, other.precision),
+            mode)
+    }
+
+    /// Divide self by other, rounding the exact quotient to the larger of both precisions.
+    ///
+    /// # Panics
+    /// Panics if other is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, RoundingMode};
+    /// let one = BigFloat::from_f64(1.0).expect("invalid f64");
+    /// let three = BigFloat::from_f64(3.0).expect("invalid f64");
+    /// assert_eq!(one.div_rounded(&three, RoundingMode::HalfEven).to_f64(), 1.0 / 3.0);
+    /// ```
+    pub fn div_rounded(&self, other: &Self, mode: RoundingMode) -> BigFloat {
+        BigFloat::from_quotient(
+            self.is_negative() ^ other.is_negative(),
+            self.mantissa.magnitude(),
+            self.exponent - other.exponent,
+            other.mantissa.magnitude(),
+            usize::max(self.precision, other.precision),
+            mode)
+    }
+
+    /// Calculate the square root, rounding the exact result to the precision of self.
+    ///
+    /// # Returns
+    /// The square root or an error if self is negative
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigFloat, RoundingMode};
+    /// let two = BigFloat::from_f64(2.0).expect("invalid f64");
+    /// assert_eq!(two.sqrt_rounded(RoundingMode::HalfEven).unwrap().to_f64(), 2.0f64.sqrt());
+    /// assert!(BigFloat::from_f64(-2.0).unwrap().sqrt_rounded(RoundingMode::HalfEven).is_err());
+    /// ```
+    pub fn sqrt_rounded(&self, mode: RoundingMode) -> Result<BigFloat, String> {
+        if self.is_negative() {
+            return Err(format!("square root of negative number {}", self));
+        }
+        if self.is_zero() {
+            return Ok(BigFloat::new(self.precision));
+        }
+        // scale the mantissa so the root has at least precision + 2 bits and the exponent is even
+        let magnitude = self.mantissa.magnitude();
+        let mut shift = usize::try_from(2 * (self.precision as i64 + 2) - magnitude.length() as i64)
+            .unwrap_or(0);
+        if (self.exponent - shift as i64) & 1 == 1 {
+            shift += 1;
+        }
+        let scaled = magnitude.clone() << shift;
+        let root = scaled.sqrt();
+        let inexact = root.mul_with(&root) != scaled;
+        Ok(BigFloat::from_sticky(false, root, inexact, (self.exponent - shift as i64) / 2, self.precision, mode))
+    }
+
+    /// Add two BigFloats rounding half to even, see [BigFloat::add_rounded].
+    pub fn add_to(&self, other: &Self) -> BigFloat {
+        self.add_rounded(other, RoundingMode::HalfEven)
+    }
+
+    pub fn add_into(&mut self, other: &Self) {
+        *self = self.add_to(other);
+    }
+
+    /// Subtract other from self rounding half to even, see [BigFloat::sub_rounded].
+    pub fn sub_from(&self, other: &Self) -> BigFloat {
+        self.sub_rounded(other, RoundingMode::HalfEven)
+    }
+
+    pub fn sub_into(&mut self, other: &Self) {
+        *self = self.sub_from(other);
+    }
+
+    /// Multiply two BigFloats rounding half to even, see [BigFloat::mul_rounded].
+    pub fn mul_by(&self, other: &Self) -> BigFloat {
+        self.mul_rounded(other, RoundingMode::HalfEven)
+    }
+
+    pub fn mul_into(&mut self, other: &Self) {
+        *self = self.mul_by(other);
+    }
+
+    /// Divide self by other rounding half to even, see [BigFloat::div_rounded].
+    pub fn div_by(&self, other: &Self) -> BigFloat {
+        self.div_rounded(other, RoundingMode::HalfEven)
+    }
+
+    pub fn div_into(&mut self, other: &Self) {
+        *self = self.div_by(other);
+    }
+
+    /// Calculate the square root rounding half to even, see [BigFloat::sqrt_rounded].
+    pub fn sqrt(&self) -> Result<BigFloat, String> {
+        self.sqrt_rounded(RoundingMode::HalfEven)
+    }
+
+    /// Add self and other with the sign of other replaced by other_negative
+    fn add_signed(&self, other: &Self, other_negative: bool, mode: RoundingMode) -> BigFloat {
+        let precision = usize::max(self.precision, other.precision);
+        if other.is_zero() {
+            return self.with_precision(precision, mode);
+        }
+        if self.is_zero() {
+            return BigFloat::from_parts(other_negative, other.mantissa.magnitude().clone(), other.exponent,
+                                        precision, mode);
+        }
+
+        // a is the operand with the larger magnitude bound
+        let (a, a_negative, b, b_negative) = if self.top() >= other.top() {
+            (self, self.is_negative(), other, other_negative)
+        } else {
+            (other, other_negative, self, self.is_negative())
+        };
+        // a has at most precision bits, so if b lies below 2^(top(a) - precision - 3) it is smaller
+        // than a quarter of the distance between neighbouring rounding boundaries around a. Any
+        // value in that range rounds the same way, so b is replaced by a small one to avoid
+        // shifting a by the full exponent difference.
+        let min_top = a.top() - precision as i64 - 3;
+        let tiny = BigUInt::from(1u32);
+        let (b_magnitude, b_exponent) = if b.top() < min_top {
+            (&tiny, min_top - 1)
+        } else {
+            (b.mantissa.magnitude(), b.exponent)
+        };
+
+        let exponent = i64::min(a.exponent, b_exponent);
+        let a_magnitude = a.mantissa.magnitude().clone() << (a.exponent - exponent) as usize;
+        let b_magnitude = b_magnitude.clone() << (b_exponent - exponent) as usize;
+        let (negative, magnitude) = if a_negative == b_negative {
+            (a_negative, a_magnitude.add_to(&b_magnitude))
+        } else {
+            match a_magnitude.cmp(&b_magnitude) {
+                Ordering::Less => (b_negative, b_magnitude.sub_from(&a_magnitude)),
+                _ => (a_negative, a_magnitude.sub_from(&b_magnitude)),
+            }
+        };
+        BigFloat::from_parts(negative, magnitude, exponent, precision, mode)
+    }
+}
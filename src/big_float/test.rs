@@ -0,0 +1,135 @@
+use rand::Rng;
+use crate::{BigFloat, BigInt, Rational, RoundingMode};
+
+const MODES: [RoundingMode; 4] = [RoundingMode::HalfEven, RoundingMode::TowardZero, RoundingMode::Floor, RoundingMode::Ceiling];
+
+fn float(value: f64) -> BigFloat {
+    BigFloat::from_f64(value).expect("invalid f64")
+}
+
+fn random_f64(rng: &mut impl Rng) -> f64 {
+    let value = rng.gen::<f64>() * 2.0f64.powi(rng.gen_range(-60..60));
+    if rng.gen::<bool>() { -value } else { value }
+}
+
+#[test]
+fn test_f64_round_trip() {
+    let mut rng = rand::thread_rng();
+    for value in [0.0, 1.0, -1.5, 0.1, f64::MAX, f64::MIN_POSITIVE, -f64::MIN_POSITIVE / 1024.0, 5e-324] {
+        assert_eq!(float(value).to_f64(), value);
+    }
+    for _ in 0..1000 {
+        let value = random_f64(&mut rng);
+        assert_eq!(float(value).to_f64(), value);
+    }
+    assert!(BigFloat::from_f64(f64::INFINITY).is_err());
+    assert!(BigFloat::from_f64(f64::NAN).is_err());
+
+    // overflow and underflow
+    let max = float(f64::MAX);
+    assert_eq!(max.mul_by(&float(2.0)).to_f64(), f64::INFINITY);
+    let min = float(5e-324);
+    assert_eq!(min.div_by(&float(2.0)).to_f64(), 0.0);
+    assert_eq!(min.mul_by(&float(1.5)).to_f64(), 1e-323);
+    assert_eq!(float(f64::MIN_POSITIVE).div_by(&float(3.0)).to_f64(), f64::MIN_POSITIVE / 3.0);
+}
+
+#[test]
+fn test_ops_match_f64() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..500 {
+        let a = random_f64(&mut rng);
+        let b = random_f64(&mut rng);
+        let (fa, fb) = (float(a), float(b));
+        assert_eq!((&fa + &fb).to_f64(), a + b, "{} + {}", a, b);
+        assert_eq!((&fa - &fb).to_f64(), a - b, "{} - {}", a, b);
+        assert_eq!((&fa * &fb).to_f64(), a * b, "{} * {}", a, b);
+        assert_eq!((&fa / &fb).to_f64(), a / b, "{} / {}", a, b);
+        assert_eq!(fa.abs().sqrt().unwrap().to_f64(), a.abs().sqrt(), "sqrt({})", a);
+    }
+}
+
+#[test]
+fn test_directed_rounding() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let a = float(random_f64(&mut rng)).with_precision(20, RoundingMode::HalfEven);
+        let b = float(random_f64(&mut rng)).with_precision(20, RoundingMode::HalfEven);
+        let (ra, rb) = (a.to_rational(), b.to_rational());
+        let exact = [ra.add_to(&rb), ra.sub_from(&rb), ra.mul_by(&rb), ra.div_by(&rb)];
+        for mode in MODES {
+            let results = [a.add_rounded(&b, mode), a.sub_rounded(&b, mode), a.mul_rounded(&b, mode), a.div_rounded(&b, mode)];
+            for (result, exact) in results.iter().zip(exact.iter()) {
+                assert!(result.mantissa().magnitude().length() <= 20);
+                let result = result.to_rational();
+                match mode {
+                    RoundingMode::Floor => assert!(result <= *exact),
+                    RoundingMode::Ceiling => assert!(result >= *exact),
+                    RoundingMode::TowardZero => assert!(result.abs() <= exact.abs(), "{:?} {:?} {:?} {:?}", a, b, result, exact),
+                    _ => {}
+                }
+            }
+        }
+        let floor = a.div_rounded(&b, RoundingMode::Floor);
+        let ceiling = a.div_rounded(&b, RoundingMode::Ceiling);
+        assert!(floor < ceiling);
+        // neighbours differ in the last bit
+        let ulp = floor.abs().max(ceiling.abs()).sub_from(&floor.abs().min(ceiling.abs()));
+        assert_eq!(ulp.mantissa(), &BigInt::from(1));
+    }
+}
+
+#[test]
+fn test_add_far_apart() {
+    let one = float(1.0);
+    let tiny = float(2.0f64.powi(-200));
+    assert_eq!(one.add_rounded(&tiny, RoundingMode::HalfEven), one);
+    assert_eq!(one.add_rounded(&tiny, RoundingMode::Ceiling).to_f64(), 1.0 + f64::EPSILON);
+    assert_eq!(one.sub_rounded(&tiny, RoundingMode::HalfEven), one);
+    assert_eq!(one.sub_rounded(&tiny, RoundingMode::Floor).to_f64(), 1.0 - f64::EPSILON / 2.0);
+    assert_eq!(one.sub_rounded(&tiny, RoundingMode::TowardZero).to_f64(), 1.0 - f64::EPSILON / 2.0);
+    assert_eq!(tiny.sub_rounded(&one, RoundingMode::Ceiling).to_f64(), -1.0 + f64::EPSILON / 2.0);
+    // exact when the precision suffices
+    let wide = one.with_precision(256, RoundingMode::HalfEven);
+    assert_eq!(wide.add_to(&tiny).sub_from(&one), tiny);
+}
+
+#[test]
+fn test_sqrt_rounding() {
+    let two = float(2.0).with_precision(200, RoundingMode::HalfEven);
+    let floor = two.sqrt_rounded(RoundingMode::Floor).unwrap();
+    let ceiling = two.sqrt_rounded(RoundingMode::Ceiling).unwrap();
+    let (floor, ceiling) = (floor.to_rational(), ceiling.to_rational());
+    assert!(floor.mul_by(&floor) < Rational::from(2));
+    assert!(ceiling.mul_by(&ceiling) > Rational::from(2));
+    assert_eq!(float(0.25).sqrt().unwrap(), float(0.5));
+    assert_eq!(float(0.0).sqrt().unwrap(), float(0.0));
+    assert!(float(-1.0).sqrt().is_err());
+}
+
+#[test]
+fn test_rational_conversion() {
+    let value = Rational::from((-22, 7));
+    for mode in MODES {
+        let bf = BigFloat::from_rational(&value, 100, mode);
+        assert_eq!(bf.precision(), 100);
+        let diff = bf.to_rational().sub_from(&value).abs();
+        assert!(diff < Rational::from((1, 1)).div_by(&Rational::from(BigInt::from(2).pow(98))));
+    }
+    assert_eq!(BigFloat::from_rational(&Rational::from((3, 8)), 2, RoundingMode::HalfEven).to_rational(), Rational::from((3, 8)));
+    assert_eq!(BigFloat::from(BigInt::from(-96)).to_rational(), Rational::from(-96));
+    assert_eq!(BigFloat::from(BigInt::from(-96)).precision(), 7);
+}
+
+#[test]
+fn test_big_float_cmp_display() {
+    assert!(float(1.5) > float(1.25));
+    assert!(float(-1.5) < float(-1.25));
+    assert!(float(-1.5) < float(0.0));
+    assert!(float(1e-300) > float(0.0));
+    assert!(float(1e300) > float(3.0));
+    assert_eq!(float(0.5).with_precision(10, RoundingMode::HalfEven), float(0.5));
+    assert_eq!(format!("{}", float(0.1)), "1.0000000000000001e-1");
+    assert_eq!(format!("{:.3}", float(-2.0 / 3.0)), "-0.667");
+    assert_eq!(format!("{}", BigFloat::new(8)), "0.000e0");
+}
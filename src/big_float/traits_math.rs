@@ -0,0 +1,141 @@
+use std::ops::{ AddAssign, Add, SubAssign, Sub, MulAssign, Mul, DivAssign, Div};
+
+use super::BigFloat;
+
+impl Add for BigFloat {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add_to(&other)
+    }
+}
+
+impl<'a> Add<&'a Self> for BigFloat {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl<'a> Add<&'a BigFloat> for &BigFloat {
+    type Output = BigFloat;
+
+    fn add(self, other: &'a BigFloat) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl AddAssign for BigFloat {
+    fn add_assign(&mut self, other: Self) {
+        self.add_into(&other);
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for BigFloat {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.add_into(other);
+    }
+}
+
+impl Sub for BigFloat {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_from(&other)
+    }
+}
+
+impl<'a> Sub<&'a Self> for BigFloat {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl<'a> Sub<&'a BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    fn sub(self, other: &'a BigFloat) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl SubAssign for BigFloat {
+    fn sub_assign(&mut self, other: Self) {
+        self.sub_into(&other)
+    }
+}
+
+impl<'a> SubAssign<&'a Self> for BigFloat {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.sub_into(other)
+    }
+}
+
+impl Mul<Self> for BigFloat {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_by(&other)
+    }
+}
+
+impl<'a> Mul<&'a Self> for BigFloat {
+    type Output = Self;
+
+    fn mul(self, other: &'a Self) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl<'a> Mul<&'a BigFloat> for &BigFloat {
+    type Output = BigFloat;
+
+    fn mul(self, other: &'a BigFloat) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl MulAssign for BigFloat {
+    fn mul_assign(&mut self, other: Self) {
+        self.mul_into(&other)
+    }
+}
+
+impl<'a> MulAssign<&'a Self> for BigFloat {
+    fn mul_assign(&mut self, other: &'a Self) {
+        self.mul_into(other)
+    }
+}
+
+impl Div for BigFloat {
+    type Output = Self;
+    fn div(self, other: Self) -> BigFloat {
+        self.div_by(&other)
+    }
+}
+
+impl<'a> Div<&'a Self> for BigFloat {
+    type Output = Self;
+    fn div(self, other: &'a Self) -> BigFloat {
+        self.div_by(other)
+    }
+}
+
+impl<'a> Div<&'a BigFloat> for &BigFloat {
+    type Output = BigFloat;
+    fn div(self, other: &'a BigFloat) -> BigFloat {
+        self.div_by(other)
+    }
+}
+
+impl DivAssign for BigFloat {
+    fn div_assign(&mut self, other: Self) {
+        self.div_into(&other)
+    }
+}
+
+impl<'a> DivAssign<&'a Self> for BigFloat {
+    fn div_assign(&mut self, other: &'a Self) {
+        self.div_into(other)
+    }
+}
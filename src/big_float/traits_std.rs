@@ -0,0 +1,87 @@
+use crate::{BigFloat, BigInt, Rational, RoundingMode};
+use std::fmt::{Debug, Formatter, Display};
+use std::cmp::Ordering;
+
+impl PartialEq for BigFloat {
+    /// BigFloats are compared by value, the precision is ignored
+    fn eq(&self, other: &Self) -> bool {
+        self.mantissa == other.mantissa && (self.is_zero() || self.exponent == other.exponent)
+    }
+}
+
+impl Eq for BigFloat {}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigFloat {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let ord = match (self.is_zero(), other.is_zero()) {
+            (true, true) => return Ordering::Equal,
+            (true, false) => return if other.is_negative() { Ordering::Greater } else { Ordering::Less },
+            (false, true) => return if self.is_negative() { Ordering::Less } else { Ordering::Greater },
+            _ => if self.is_negative() != other.is_negative() {
+                return if self.is_negative() { Ordering::Less } else { Ordering::Greater };
+            } else if self.top() != other.top() {
+                self.top().cmp(&other.top())
+            } else {
+                // same magnitude bound so the exponents differ by less than the mantissa lengths
+                let exponent = i64::min(self.exponent, other.exponent);
+                let lhs = self.mantissa.magnitude().clone() << (self.exponent - exponent) as usize;
+                let rhs = other.mantissa.magnitude().clone() << (other.exponent - exponent) as usize;
+                lhs.cmp(&rhs)
+            }
+        };
+        if self.is_negative() { ord.reverse() } else { ord }
+    }
+}
+
+impl From<BigInt> for BigFloat {
+    /// Create an exact BigFloat, the precision is the bit length of the value
+    fn from(value: BigInt) -> Self {
+        let precision = usize::max(value.magnitude().length(), 1);
+        BigFloat::from_big_int(&value, precision, RoundingMode::HalfEven)
+    }
+}
+
+impl TryFrom<f64> for BigFloat {
+    type Error = String;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        BigFloat::from_f64(value)
+    }
+}
+
+impl From<BigFloat> for Rational {
+    fn from(value: BigFloat) -> Self {
+        value.to_rational()
+    }
+}
+
+impl From<&BigFloat> for f64 {
+    fn from(value: &BigFloat) -> Self {
+        value.to_f64()
+    }
+}
+
+impl Debug for BigFloat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({:?} * 2^{}, precision: {})", self.mantissa, self.exponent, self.precision)
+    }
+}
+
+impl Display for BigFloat {
+    /// Display the BigFloat in scientific notation with as many digits as its precision
+    /// requires or, if a precision is given as in `{:.3}`, as a decimal number rounded half to even.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(precision) = f.precision() {
+            f.write_str(self.to_decimal_string(precision, RoundingMode::HalfEven).as_str())
+        } else {
+            let digits = (self.precision as f64 * std::f64::consts::LOG10_2).ceil() as usize + 1;
+            f.write_str(self.to_scientific_string(digits, RoundingMode::HalfEven).as_str())
+        }
+    }
+}
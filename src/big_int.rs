@@ -113,16 +113,9 @@ impl BigInt {
     /// ```
     #[inline]
     pub fn from_i64(from: i64) -> BigInt {
-        if from as u64 == 0xFFFFFFFFFFFFFFFF {
-            BigInt {
-                signed: true,
-                uint: BigUInt::from_u64(1<<63)
-            }
-        } else {
-            BigInt {
-                signed: if from >= 0 { false } else { true },
-                uint: BigUInt::from_u64(i64::abs(from) as u64)
-            }
+        BigInt {
+            signed: from < 0,
+            uint: BigUInt::from_u64(from.unsigned_abs())
         }
     }
 
@@ -140,16 +133,9 @@ impl BigInt {
     /// ```
     #[inline]
     pub fn from_i128(from: i128) -> BigInt {
-        if from as u128 == 0xFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF {
-            BigInt {
-                signed: true,
-                uint: BigUInt::from_u128(1<<127)
-            }
-        } else {
-            BigInt {
-                signed: if from >= 0 { false } else { true },
-                uint: BigUInt::from_u128(i128::abs(from) as u128)
-            }
+        BigInt {
+            signed: from < 0,
+            uint: BigUInt::from_u128(from.unsigned_abs())
         }
     }
 
@@ -232,6 +218,11 @@ impl BigInt {
     }
 
 
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.uint.is_zero()
+    }
+
     #[inline]
     pub fn is_positive(&self) -> bool {
         !self.signed
@@ -270,6 +261,26 @@ impl BigInt {
         self.uint
     }
 
+    /// Create a BigInt from sign and magnitude, zero is never signed
+    #[inline]
+    pub(crate) fn from_sign_magnitude(signed: bool, uint: BigUInt) -> BigInt {
+        BigInt {
+            signed: signed && !uint.is_zero(),
+            uint
+        }
+    }
+
+    /// The value without a negative zero
+    #[inline]
+    pub(crate) fn normalized(self) -> BigInt {
+        BigInt::from_sign_magnitude(self.signed, self.uint)
+    }
+
+    #[inline]
+    pub(crate) fn magnitude(&self) -> &BigUInt {
+        &self.uint
+    }
+
 
 
 }
@@ -188,15 +188,81 @@ impl BigInt {
         self.uint = self.uint.div_by(&other.uint);
     }
 
+    /// Raise self to the given power, `x.pow(0)` is 1 for any x.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigInt;
+    /// assert_eq!(BigInt::from(-2).pow(3), BigInt::from(-8));
+    /// assert_eq!(BigInt::from(-2).pow(0), BigInt::from(1));
+    /// ```
     pub fn pow(&self, power: u32) -> BigInt {
+        let uint = self.uint.powi(power);
         BigInt {
-            signed: if self.signed {
-                (power & 0x1) == 0x1
-            } else {
-                false
-            },
-            uint: self.uint.powi(power)
+            signed: self.signed && (power & 0x1) == 0x1 && !uint.is_zero(),
+            uint
+        }
+    }
+
+    /// Raise self to a power given as BigUInt, failing if the magnitude of the result would
+    /// exceed max_bits bits. See [BigUInt::pow_big].
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigInt, BigUInt};
+    /// assert_eq!(BigInt::from(-3).pow_big(&BigUInt::from(3u32), 64).unwrap(), BigInt::from(-27));
+    /// ```
+    pub fn pow_big(&self, exponent: &BigUInt, max_bits: usize) -> Result<BigInt, String> {
+        let uint = self.uint.pow_big(exponent, max_bits)?;
+        let odd = exponent.iter().last().unwrap_or(false);
+        Ok(BigInt {
+            signed: self.signed && odd && !uint.is_zero(),
+            uint
+        })
+    }
+
+    /// Extended Euclidean algorithm.
+    ///
+    /// # Returns
+    /// A tuple (g, x, y) with g = gcd(self, other), which is never negative, and the Bézout
+    /// coefficients x, y with x * self + y * other = g
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigInt, BigUInt};
+    /// let (g, x, y) = BigInt::from(240).extended_gcd(&BigInt::from(-46));
+    /// assert_eq!(g, BigUInt::from(2u32));
+    /// assert_eq!((x.clone(), y.clone()), (BigInt::from(-9), BigInt::from(-47)));
+    /// assert_eq!(x * BigInt::from(240) + y * BigInt::from(-46), BigInt::from(2));
+    /// ```
+    pub fn extended_gcd(&self, other: &Self) -> (BigUInt, BigInt, BigInt) {
+        let [(gcd, x, y), _] = BigInt::euclid_rows(&self.uint, &other.uint, &BigUInt::new());
+        // the rows hold the coefficients for the magnitudes
+        (gcd,
+         BigInt::from_sign_magnitude(x.signed ^ self.signed, x.uint),
+         BigInt::from_sign_magnitude(y.signed ^ other.signed, y.uint))
+    }
+
+    /// Run the extended Euclidean algorithm on a and b until the remainder is no longer larger
+    /// than bound.
+    ///
+    /// Each row (r, s, t) satisfies s * a + t * b = r, the first row holds the last remainder
+    /// larger than bound, the second row the first remainder not larger than bound. With a bound
+    /// of zero the first row holds the gcd.
+    pub(crate) fn euclid_rows(a: &BigUInt, b: &BigUInt, bound: &BigUInt) -> [(BigUInt, BigInt, BigInt); 2] {
+        let mut row0 = (a.clone(), BigInt::from(1), BigInt::new());
+        let mut row1 = (b.clone(), BigInt::new(), BigInt::from(1));
+        if row0.0 <= *bound {
+            return [row1, row0];
+        }
+        while row1.0 > *bound {
+            let (quotient, remainder) = row0.0.div_mod(&row1.0);
+            let row2 = (remainder,
+                        row0.1.sub_from(&BigInt::from_sign_magnitude(row1.1.signed, quotient.mul_with(&row1.1.uint))),
+                        row0.2.sub_from(&BigInt::from_sign_magnitude(row1.2.signed, quotient.mul_with(&row1.2.uint))));
+            row0 = std::mem::replace(&mut row1, row2);
         }
+        [row0, row1]
     }
 
     pub fn to_f64(&self) -> Result<f64,String> {
@@ -161,4 +161,33 @@ fn test_from_i128() {
 
     let bi = BigInt::from_i128(0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF);
     assert_eq!(bi.to_i128(), Some(0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF));
+
+    assert_eq!(BigInt::from_i128(-1).to_dec_str(), "-1");
+    assert_eq!(BigInt::from_i64(-1).to_dec_str(), "-1");
+    assert_eq!(BigInt::from_i64(i64::MIN).to_dec_str(), "-9223372036854775808");
+}
+
+#[test]
+fn test_pow() {
+    assert_eq!(BigInt::from(-3).pow(0), BigInt::from(1));
+    assert_eq!(BigInt::from(-3).pow(3), BigInt::from(-27));
+    assert_eq!(BigInt::from(-3).pow(4), BigInt::from(81));
+    assert_eq!(BigInt::from(0).pow(0), BigInt::from(1));
+    assert!(BigInt::from(0).pow(5).is_zero());
+    assert_eq!(BigInt::from(-2).pow(125).to_i128(), Some(-(1i128 << 125)));
+
+    assert_eq!(BigInt::from(-2).pow_big(&crate::BigUInt::from(63u32), 64).unwrap().to_i128(), Some(i64::MIN as i128));
+    assert!(BigInt::from(-2).pow_big(&crate::BigUInt::from(64u32), 64).is_err());
+}
+
+#[test]
+fn test_extended_gcd() {
+    for (a, b, gcd) in [(240i64, 46i64, 2u32), (-240, 46, 2), (0, -7, 7), (-7, 0, 7), (0, 0, 0), (17, 5, 1),
+                        (-1234567890123, 987654321, 3)] {
+        let a = BigInt::from(a);
+        let b = BigInt::from(b);
+        let (g, x, y) = a.extended_gcd(&b);
+        assert_eq!(g, gcd.into());
+        assert_eq!(x.mul_with(&a).add_to(&y.mul_with(&b)), BigInt::from(gcd as i64));
+    }
 }
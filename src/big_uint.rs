@@ -1,5 +1,8 @@
 use super::macros::function;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 type Block = u64;
 
 const BLOCK_SIZE: usize = 64;
@@ -29,12 +32,21 @@ mod bits;
 
 pub use bits::*;
 
+#[cfg(feature = "zeroize")]
+mod secret;
+
+#[cfg(feature = "zeroize")]
+pub use secret::*;
+
 #[cfg(test)]
 mod test;
 
 /// An unsigned integer of indefinite size, limited only by memory constraints and rust maximum
 /// vector size.
-#[derive(Clone, PartialEq)]
+///
+/// With the `zeroize` feature the blocks are cleared when a value is dropped and before blocks
+/// are released by shrinking or growing a value.
+#[derive(Clone, PartialEq, Hash)]
 pub struct BigUInt {
     length: usize,
     bits: Vec<Block>,
@@ -92,6 +104,64 @@ impl BigUInt {
         Ok(res)
     }
 
+    /// Create a BigUInt from big endian bytes, leading zero bytes are allowed.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let bi = BigUInt::from_bytes_be(&[0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09]);
+    /// assert_eq!(bi.to_hex_string(), "10203040506070809");
+    /// ```
+    pub fn from_bytes_be(src: &[u8]) -> BigUInt {
+        let bits: Vec<Block> = src.rchunks(BLOCK_SIZE / 8)
+            .map(|chunk| chunk.iter().fold(0, |block, byte| (block << 8) | *byte as Block))
+            .collect();
+        let mut res = BigUInt {
+            length: bits.len() * BLOCK_SIZE,
+            bits,
+        };
+        res.trim();
+
+        #[cfg(feature = "debug_checks")]
+            res.check(function!());
+
+        res
+    }
+
+    /// Create a BigUInt from a string of decimal digits.
+    ///
+    /// # Arguments
+    ///
+    /// * src - the decimal digits, no sign or whitespace is accepted
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let bi = BigUInt::from_dec_str("12345678912345678912").expect("invalid decimal string");
+    /// assert_eq!(bi.to_hex_string(),"AB54A98F81652440");
+    /// ```
+    pub fn from_dec_str(src: &str) -> Result<BigUInt, String> {
+        if src.is_empty() {
+            return Err(String::from("empty decimal string"));
+        }
+        if let Some(chr) = src.chars().find(|chr| !chr.is_ascii_digit()) {
+            return Err(format!("invalid character encountered in decimal string: '{}'", chr));
+        }
+
+        // process chunks of 19 digits, 10^19 is the largest power of ten that fits into a block
+        let mut res = BigUInt::new();
+        for chunk in src.as_bytes().chunks(19) {
+            let value = chunk.iter().fold(0u64, |acc, digit| acc * 10 + (digit - b'0') as u64);
+            res.mul_into(&BigUInt::from_u64(10u64.pow(chunk.len() as u32)));
+            res.add_into(&BigUInt::from_u64(value));
+        }
+
+        #[cfg(feature = "debug_checks")]
+            res.check(function!());
+
+        Ok(res)
+    }
+
     /// Create a BigUInt from an u8 value.
     ///
     /// # Arguments
@@ -494,6 +564,56 @@ impl BigUInt {
         }
     }
 
+    /// Return the value as big endian bytes without leading zero bytes, zero has no bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let bi = BigUInt::from_u32(0x10203);
+    /// assert_eq!(bi.to_bytes_be(), vec![0x01, 0x02, 0x03]);
+    /// assert!(BigUInt::new().to_bytes_be().is_empty());
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let len = self.length.div_ceil(8);
+        let leading = self.bits.len() * size_of::<Block>() - len;
+        // written at its exact size, so no scratch copy of the value is left in released memory
+        let mut res = Vec::with_capacity(len);
+        res.extend(self.bits.iter().rev().flat_map(|block| block.to_be_bytes()).skip(leading));
+        res
+    }
+
+    /// Keep the lower len blocks, with the `zeroize` feature the dropped blocks are cleared
+    #[inline]
+    fn truncate_blocks(&mut self, len: usize) {
+        #[cfg(feature = "zeroize")]
+        if len < self.bits.len() {
+            self.bits[len..].zeroize();
+        }
+        self.bits.truncate(len);
+    }
+
+    /// Make room for len blocks. With the `zeroize` feature the blocks move to a new allocation
+    /// and the old one is cleared, instead of leaving a copy behind when the vector grows.
+    #[inline]
+    fn reserve_blocks(&mut self, len: usize) {
+        #[cfg(feature = "zeroize")]
+        if len > self.bits.capacity() {
+            let mut bits = Vec::with_capacity(len);
+            bits.extend_from_slice(&self.bits);
+            self.replace_blocks(bits);
+        }
+        #[cfg(not(feature = "zeroize"))]
+        self.bits.reserve(len.saturating_sub(self.bits.len()));
+    }
+
+    /// Replace the blocks, with the `zeroize` feature the old ones are cleared
+    #[inline]
+    fn replace_blocks(&mut self, bits: Vec<Block>) {
+        #[cfg(feature = "zeroize")]
+        self.bits.zeroize();
+        self.bits = bits;
+    }
+
     /// Trim any leading digits that contain no value (no bits set to 1)
     fn trim(&mut self) {
         if self.length > 0 {
@@ -508,10 +628,10 @@ impl BigUInt {
             }
             if blocks == 0 {
                 self.length = 0;
-                self.bits.clear();
+                self.truncate_blocks(0);
             } else {
                 // TODO: use length to make more efficient
-                self.bits.resize(blocks, 0);
+                self.truncate_blocks(blocks);
                 let mut length = 0usize;
                 for idx in (1..=64).rev() {
                     if high_block & 0x8000000000000000 == 0x8000000000000000 {
@@ -118,6 +118,7 @@ impl BigUInt {
         let mut empty = false;
         if index >= self.length {
             if bit {
+                self.reserve_blocks(index / BLOCK_SIZE + 1);
                 self.bits.resize(index / BLOCK_SIZE + 1, 0);
                 empty = true;
                 self.length = index + 1;
@@ -192,12 +193,14 @@ impl BigUInt {
             let new_length = self.length + rhs;
             let old_blocks = self.bits.len();
             if rhs as usize / BLOCK_SIZE > 0 {
-                let mut bits = vec![0; rhs / BLOCK_SIZE];
+                let mut bits = Vec::with_capacity(rhs / BLOCK_SIZE + old_blocks + 1);
+                bits.resize(rhs / BLOCK_SIZE, 0);
                 bits.extend(self.bits.iter());
-                self.bits = bits;
+                self.replace_blocks(bits);
             }
 
             if new_length > self.bits.len() * BLOCK_SIZE {
+                self.reserve_blocks(self.bits.len() + 1);
                 self.bits.push(0);
             }
 
@@ -237,7 +240,7 @@ impl BigUInt {
                 // some remaining right shift, drop blocks & shift
                 let l_shift = BLOCK_SIZE - r_shift;
                 // skip all blocks that are shifted out
-                let blocks = self.bits[rhs / BLOCK_SIZE..].to_vec();
+                let blocks = &self.bits[rhs / BLOCK_SIZE..];
                 debug_assert!(blocks.len() > 0, "no blocks left");
                 let mut new_block = blocks[0] >> r_shift;
                 let mut bits: Vec<u64> = blocks.iter().skip(1).map(|block| {
@@ -264,7 +267,7 @@ impl BigUInt {
         if rhs >= self.length {
             // everything shifted away
             self.length = 0;
-            self.bits = Vec::new();
+            self.truncate_blocks(0);
         } else if rhs == 0 {
             // nothing to do
         } else {
@@ -277,7 +280,7 @@ impl BigUInt {
                 // some remaining right shift, drop blocks & shift
                 let l_shift = BLOCK_SIZE - r_shift;
                 // skip all blocks that are shifted out
-                let blocks = self.bits[rhs / BLOCK_SIZE..].to_vec();
+                let blocks = &self.bits[rhs / BLOCK_SIZE..];
                 debug_assert!(blocks.len() > 0, "no blocks left");
                 let mut new_block = blocks[0] >> r_shift;
                 let mut bits: Vec<u64> = blocks.iter().skip(1).map(|block| {
@@ -290,7 +293,7 @@ impl BigUInt {
                 bits
             };
             self.length = self.length - rhs;
-            self.bits = bits;
+            self.replace_blocks(bits);
             self.trim();
             #[cfg(feature = "debug_checks")]
                 self.check(function!());
@@ -304,7 +307,7 @@ impl BigUInt {
         if num_bits == self.length {
             let res = (*self).clone();
             self.length = 0;
-            self.bits.clear();
+            self.truncate_blocks(0);
             res
         } else {
             let mut last_size = self.length % BLOCK_SIZE;
@@ -331,7 +334,7 @@ impl BigUInt {
                     // eprintln!("shift_out({},{}): Ordering::Equal", self.to_hex_string(), num_bits);
                     let res = BigUInt::from_u64(*self.bits.last().expect("Unexpected empty BigUInt"));
                     self.length -= num_bits;
-                    self.bits.resize(self.bits.len() - 1, 0);
+                    self.truncate_blocks(self.bits.len() - 1);
                     #[cfg(feature = "debug_checks")]
                         res.check(function!());
                     res
@@ -365,7 +368,7 @@ impl BigUInt {
                         self.bits[block_idx] &= !mask;
                     }
                     self.length -= num_bits;
-                    self.bits.resize(self.length / BLOCK_SIZE + if self.length % BLOCK_SIZE > 0 { 1 } else { 0 }, 0);
+                    self.truncate_blocks(self.length / BLOCK_SIZE + if self.length % BLOCK_SIZE > 0 { 1 } else { 0 });
                     self.trim();
                     #[cfg(feature = "debug_checks")]
                         res.check(function!());
@@ -376,6 +379,34 @@ impl BigUInt {
         }
     }
 
+    /// Keep the lowest num_bits bits, which is self mod 2^num_bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let bi = BigUInt::from_u32(0xF0F0F0F0);
+    /// assert_eq!(bi.low_bits(12).to_hex_string(), "F0");
+    /// ```
+    pub fn low_bits(&self, num_bits: usize) -> BigUInt {
+        if num_bits >= self.length {
+            return self.clone();
+        }
+        let blocks = num_bits.div_ceil(BLOCK_SIZE);
+        let mut bits = self.bits[..blocks].to_vec();
+        let rest = num_bits % BLOCK_SIZE;
+        if rest > 0 {
+            bits[blocks - 1] &= (1u64 << rest) - 1;
+        }
+        let mut res = BigUInt {
+            length: blocks * BLOCK_SIZE,
+            bits,
+        };
+        res.trim();
+        #[cfg(feature = "debug_checks")]
+            res.check(function!());
+        res
+    }
+
     pub fn iter(&self) -> BitIterator {
         BitIterator {
             bits: self,
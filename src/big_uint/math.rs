@@ -38,7 +38,7 @@ impl BigUInt {
             other.clone()
         } else {
             let mut overflow = false;
-            let mut bits = vec![];
+            let mut bits = Vec::with_capacity(usize::max(self.bits.len(), other.bits.len()) + 1);
             for (block1, block2) in self.bits.iter().zip(other.bits.iter()) {
                 let res = *block1 as u128 + *block2 as u128 + if overflow { 1 } else { 0 };
                 bits.push((res & BLOCK_MASK as u128) as Block);
@@ -121,8 +121,9 @@ impl BigUInt {
         // eprintln!("add_assign({},{})", self.to_hex_string(), other.to_hex_string());
         if self.is_zero() {
             self.length = other.length;
-            self.bits = other.bits.clone()
+            self.replace_blocks(other.bits.clone())
         } else if other.is_zero() {} else {
+            self.reserve_blocks(usize::max(self.bits.len(), other.bits.len()) + 1);
             let mut overflow = false;
             for (block1, block2) in self.bits.iter_mut().zip(other.bits.iter()) {
                 let res = *block1 as u128 + *block2 as u128 + if overflow { 1 } else { 0 };
@@ -196,7 +197,7 @@ impl BigUInt {
             Ordering::Less => panic!("integer underflow"),
             Ordering::Equal => BigUInt::new(),
             Ordering::Greater => {
-                let mut bits = vec![];
+                let mut bits = Vec::with_capacity(self.bits.len());
                 let mut overflow = false;
                 for (block1, block2) in self.bits.iter().zip(other.bits.iter()) {
                     let mut work = *block1 as u128;
@@ -220,8 +221,10 @@ impl BigUInt {
                     bits.push(work as u64);
                 }
 
+                let mut next = other.bits.len();
                 if overflow {
-                    for block in &self.bits[other.bits.len()..] {
+                    for block in &self.bits[next..] {
+                        next += 1;
                         if *block > 0 {
                             bits.push(*block - 1);
                             overflow = false;
@@ -231,9 +234,8 @@ impl BigUInt {
                         }
                     }
                     assert!(!overflow);
-                } else {
-                    bits.extend_from_slice(&self.bits[other.bits.len()..]);
                 }
+                bits.extend_from_slice(&self.bits[next..]);
 
                 let mut res = BigUInt {
                     length: bits.len() * BLOCK_SIZE,
@@ -273,7 +275,7 @@ impl BigUInt {
             Ordering::Less => panic!("integer underflow"),
             Ordering::Equal => {
                 self.length = 0;
-                self.bits.clear();
+                self.truncate_blocks(0);
             }
             Ordering::Greater => {
                 // the borrowed bit from the last block
@@ -368,14 +370,150 @@ impl BigUInt {
         }
     }
 
+    /// Raise self to the given power using square and multiply.
+    ///
+    /// `x.powi(0)` is 1 for any x, including 0.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// assert_eq!(BigUInt::from(3u32).powi(5), BigUInt::from(243u32));
+    /// assert_eq!(BigUInt::from(7u32).powi(0), BigUInt::from(1u32));
+    /// ```
     pub fn powi(&self, power: u32) -> BigUInt {
-        let mut res = self.clone();
-        for _ in 1..power {
-            res.mul_into(self);
+        let mut res = BigUInt::from(1u32);
+        for bit in (0..u32::BITS - power.leading_zeros()).rev() {
+            res = res.mul_with(&res);
+            if (power >> bit) & 1 == 1 {
+                res.mul_into(self);
+            }
         }
         res
     }
 
+    /// Raise self to a power given as BigUInt, failing if the result would exceed max_bits bits.
+    ///
+    /// The size of the result is estimated before any multiplication takes place, so absurdly
+    /// large exponents fail fast instead of exhausting memory.
+    ///
+    /// # Arguments
+    /// * exponent - the power to raise self to
+    /// * max_bits - the maximum bit length allowed for the result
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let two = BigUInt::from(2u32);
+    /// assert_eq!(two.pow_big(&BigUInt::from(100u32), 128).unwrap(), BigUInt::from(1u32) << 100);
+    /// assert!(two.pow_big(&BigUInt::from(200u32), 128).is_err());
+    /// ```
+    pub fn pow_big(&self, exponent: &BigUInt, max_bits: usize) -> Result<BigUInt, String> {
+        if exponent.is_zero() || self.length == 1 {
+            // x^0 and 1^e
+            return Ok(BigUInt::from(1u32));
+        }
+        if self.is_zero() {
+            return Ok(BigUInt::new());
+        }
+        // self >= 2^(length - 1) so the result has at least (length - 1) * exponent + 1 bits
+        let min_bits = BigUInt::from((self.length - 1) as u64).mul_with(exponent);
+        if min_bits >= BigUInt::from(max_bits as u64) {
+            return Err(format!("the result exceeds the limit of {} bits", max_bits));
+        }
+        let mut res = BigUInt::from(1u32);
+        for bit in exponent.iter() {
+            res = res.mul_with(&res);
+            if bit {
+                res.mul_into(self);
+            }
+            if res.length > max_bits {
+                return Err(format!("the result exceeds the limit of {} bits", max_bits));
+            }
+        }
+        Ok(res)
+    }
+
+    /// Calculate the integer square root, the largest value r with r * r <= self.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// assert_eq!(BigUInt::from(99u32).sqrt(), BigUInt::from(9u32));
+    /// assert_eq!(BigUInt::from(100u32).sqrt(), BigUInt::from(10u32));
+    /// ```
+    pub fn sqrt(&self) -> BigUInt {
+        self.nth_root(2)
+    }
+
+    /// Calculate the integer n-th root, the largest value r with r^n <= self.
+    ///
+    /// # Arguments
+    /// * n - the degree of the root, must not be zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// assert_eq!(BigUInt::from(1000u32).nth_root(3), BigUInt::from(10u32));
+    /// assert_eq!(BigUInt::from(999u32).nth_root(3), BigUInt::from(9u32));
+    /// ```
+    pub fn nth_root(&self, n: u32) -> BigUInt {
+        assert!(n > 0, "the zeroth root is undefined");
+        if n == 1 || self.is_zero() {
+            return self.clone();
+        }
+        // Newton's iteration x' = ((n - 1) * x + self / x^(n - 1)) / n, starting above the root,
+        // decreases monotonically until it reaches the integer root
+        let degree = BigUInt::from(n);
+        let degree_less_one = BigUInt::from(n - 1);
+        let mut x = BigUInt::from(1u32) << self.length.div_ceil(n as usize);
+        loop {
+            let next = x.mul_with(&degree_less_one)
+                .add_to(&self.div_by(&x.powi(n - 1)))
+                .div_by(&degree);
+            if next >= x {
+                return x;
+            }
+            x = next;
+        }
+    }
+
+    /// Find the inverse x of self modulo modulus, so that self * x = 1 (mod modulus).
+    ///
+    /// # Returns
+    /// The inverse in the range 0..modulus or None if self and modulus are not coprime
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// assert_eq!(BigUInt::from(3u32).mod_inverse(&BigUInt::from(11u32)), Some(BigUInt::from(4u32)));
+    /// assert_eq!(BigUInt::from(6u32).mod_inverse(&BigUInt::from(9u32)), None);
+    /// ```
+    pub fn mod_inverse(&self, modulus: &BigUInt) -> Option<BigUInt> {
+        assert!(!modulus.is_zero(), "Division by zero");
+        // extended Euclid, keeping t * self = r (mod modulus) with the coefficients t
+        // reduced modulo modulus
+        let mut r0 = modulus.clone();
+        let mut r1 = self.div_mod(modulus).1;
+        let mut t0 = BigUInt::new();
+        let mut t1 = BigUInt::from(1u32);
+        while !r1.is_zero() {
+            let (quotient, r2) = r0.div_mod(&r1);
+            let product = quotient.mul_with(&t1).div_mod(modulus).1;
+            let t2 = if t0 >= product {
+                t0.sub_from(&product)
+            } else {
+                t0.add_to(modulus).sub_from(&product)
+            };
+            r0 = std::mem::replace(&mut r1, r2);
+            t0 = std::mem::replace(&mut t1, t2);
+        }
+        if r0 == BigUInt::from(1u32) {
+            Some(t0.div_mod(modulus).1)
+        } else {
+            None
+        }
+    }
+
     pub fn gcd(&self, other: &Self) -> BigUInt {
         // Binary GCD algorithm, see https://en.wikipedia.org/wiki/Binary_GCD_algorithm
         // Base cases: gcd(n, 0) = gcd(0, n) = n
@@ -450,7 +588,7 @@ impl BigUInt {
     pub fn mul_into(&mut self, other: &Self) {
         if self.is_zero() {} else if other.is_zero() {
             self.length = 0;
-            self.bits.clear();
+            self.truncate_blocks(0);
         } else {
             let mut res_list = vec![];
             for (idx1, block1) in other.bits.iter().enumerate() {
@@ -464,8 +602,7 @@ impl BigUInt {
             for res in res_list {
                 sum += res;
             }
-            self.length = sum.length;
-            self.bits = sum.bits;
+            *self = sum;
             #[cfg(feature = "debug_checks")]
                 self.check(function!());
 
@@ -533,6 +670,40 @@ impl BigUInt {
         }
     }
 
+    /// Divide self by a u64 divisor, return the quotient and the remainder.
+    ///
+    /// Divides block by block, which is much faster than [BigUInt::div_mod] for small divisors.
+    ///
+    /// # Panics
+    /// Panics if divisor is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let bi = BigUInt::from_u128(0x1234567890ABCDEF1234567890ABCDEF);
+    /// let (quotient, remainder) = bi.div_mod_u64(1000);
+    /// assert_eq!(quotient, BigUInt::from_u128(0x1234567890ABCDEF1234567890ABCDEF / 1000));
+    /// assert_eq!(remainder, (0x1234567890ABCDEF1234567890ABCDEFu128 % 1000) as u64);
+    /// ```
+    pub fn div_mod_u64(&self, divisor: u64) -> (BigUInt, u64) {
+        assert!(divisor != 0, "Division by zero");
+        let mut bits = vec![0; self.bits.len()];
+        let mut remainder = 0u128;
+        for (idx, block) in self.bits.iter().enumerate().rev() {
+            let current = (remainder << BLOCK_SIZE) | *block as u128;
+            bits[idx] = (current / divisor as u128) as Block;
+            remainder = current % divisor as u128;
+        }
+        let mut res = BigUInt {
+            length: bits.len() * BLOCK_SIZE,
+            bits,
+        };
+        res.trim();
+        #[cfg(feature = "debug_checks")]
+            res.check(function!());
+        (res, remainder as u64)
+    }
+
     /// Divide self by a divisor, return the result.
     /// Due to BigUInt not being able to implement the Copy trait and the std::ops::Div trait
     /// consuming the right hand side operator the use of / can be inefficient, having to clone
@@ -583,12 +754,12 @@ impl BigUInt {
             Ordering::Less => {
                 let res = self.clone();
                 self.length = 0;
-                self.bits.clear();
+                self.truncate_blocks(0);
                 res
             }
             Ordering::Equal => {
                 self.length = 1;
-                self.bits.resize(1, 0);
+                self.truncate_blocks(1);
                 self.bits[0] = 1;
                 BigUInt::new()
             }
@@ -603,8 +774,7 @@ impl BigUInt {
                         res.set(idx, true);
                     }
                 }
-                self.length = res.length;
-                self.bits = res.bits;
+                *self = res;
                 modulo
             }
         }
@@ -0,0 +1,54 @@
+use std::fmt::{Debug, Formatter};
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use super::BigUInt;
+
+/// A BigUInt holding a secret like a private exponent.
+///
+/// The Debug output is redacted, so the value does not end up in logs or panic messages, and the
+/// blocks are cleared on drop. There is no Display, PartialEq or arithmetic, the value is only
+/// accessible through [SecretBigUInt::expose_secret].
+///
+/// # Examples
+/// ```
+/// use simple_big_int::{BigUInt, SecretBigUInt};
+/// let secret = SecretBigUInt::new(BigUInt::from(413u32));
+/// assert_eq!(format!("{:?}", secret), "SecretBigUInt(..)");
+/// assert_eq!(*secret.expose_secret(), BigUInt::from(413u32));
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretBigUInt(BigUInt);
+
+impl SecretBigUInt {
+    pub fn new(value: BigUInt) -> SecretBigUInt {
+        SecretBigUInt(value)
+    }
+
+    /// Access the secret value
+    #[inline]
+    pub fn expose_secret(&self) -> &BigUInt {
+        &self.0
+    }
+}
+
+impl From<BigUInt> for SecretBigUInt {
+    fn from(value: BigUInt) -> Self {
+        SecretBigUInt(value)
+    }
+}
+
+impl Zeroize for SecretBigUInt {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/// The Drop implementation of BigUInt clears the blocks
+impl ZeroizeOnDrop for SecretBigUInt {}
+
+impl Debug for SecretBigUInt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretBigUInt(..)")
+    }
+}
@@ -272,6 +272,10 @@ fn test_sub() {
     let bi = BigUInt::from_u128(0x8AC7230489E8000000);
     let res = bi - BigUInt::from_u32(0x5DEAD34);
     assert_eq!(res.to_hex_string(), format!("{:X}", 0x8AC7230489E8000000u128 - 0x5DEAD34u128));
+
+    // the borrow stops in a middle block, the blocks above it must be kept
+    let bi = BigUInt::from_u32(0x29454C) << 110;
+    assert_eq!(bi.sub_from(&BigUInt::from_u32(1)).to_hex_string(), "A5152FFFFFFFFFFFFFFFFFFFFFFFFFFFF");
 }
 
 #[test]
@@ -460,3 +464,146 @@ fn test_from_f64() {
         }
     }
 }
+
+#[test]
+fn test_from_dec_str() {
+    let bi = BigUInt::from_dec_str("0").expect("failed to convert from decimal");
+    assert!(bi.is_zero());
+
+    let bi = BigUInt::from_dec_str("12345678912345678912").expect("failed to convert from decimal");
+    assert_eq!(bi.to_u64(), Some(12345678912345678912));
+
+    let dec_str = "340282366920938463463374607431768211455";
+    let bi = BigUInt::from_dec_str(dec_str).expect("failed to convert from decimal");
+    assert_eq!(bi.to_u128(), Some(u128::MAX));
+    assert_eq!(bi.to_dec_string(), dec_str);
+
+    assert!(BigUInt::from_dec_str("").is_err());
+    assert!(BigUInt::from_dec_str("12a4").is_err());
+    assert!(BigUInt::from_dec_str("-1").is_err());
+}
+
+#[test]
+fn test_nth_root() {
+    assert!(BigUInt::new().sqrt().is_zero());
+    assert_eq!(BigUInt::from(1u32).sqrt(), 1u32.into());
+    assert_eq!(BigUInt::from(3u32).sqrt(), 1u32.into());
+    assert_eq!(BigUInt::from(4u32).sqrt(), 2u32.into());
+    assert_eq!(BigUInt::from(u128::MAX).sqrt(), u64::MAX.into());
+
+    let root = BigUInt::from_hex_str("113572E4620B646BD672F2DEDCF983AC855B8ABAD93F").expect("invalid hex string");
+    let square = root.mul_with(&root);
+    assert_eq!(square.sqrt(), root);
+    assert_eq!(square.sub_from(&1u32.into()).sqrt(), root.sub_from(&1u32.into()));
+
+    let cube = root.powi(3);
+    assert_eq!(cube.nth_root(3), root);
+    assert_eq!(cube.sub_from(&1u32.into()).nth_root(3), root.sub_from(&1u32.into()));
+    assert_eq!(cube.nth_root(1), cube);
+    assert_eq!(BigUInt::from(1u32 << 20).nth_root(20), 2u32.into());
+    assert_eq!(BigUInt::from((1u32 << 20) - 1).nth_root(20), 1u32.into());
+}
+
+#[test]
+fn test_powi() {
+    assert_eq!(BigUInt::new().powi(0), 1u32.into());
+    assert!(BigUInt::new().powi(3).is_zero());
+    assert_eq!(BigUInt::from(5u32).powi(0), 1u32.into());
+    assert_eq!(BigUInt::from(5u32).powi(1), 5u32.into());
+    assert_eq!(BigUInt::from(3u32).powi(40), 12157665459056928801u64.into());
+    assert_eq!(BigUInt::from(2u32).powi(200), BigUInt::from(1u32) << 200);
+
+    let base = BigUInt::from(0x123456789u64);
+    let mut expected = BigUInt::from(1u32);
+    for _ in 0..13 {
+        expected.mul_into(&base);
+    }
+    assert_eq!(base.powi(13), expected);
+}
+
+#[test]
+fn test_pow_big() {
+    let two = BigUInt::from(2u32);
+    assert_eq!(two.pow_big(&BigUInt::new(), 0).unwrap(), 1u32.into());
+    assert_eq!(two.pow_big(&127u32.into(), 128).unwrap(), BigUInt::from(1u32) << 127);
+    assert!(two.pow_big(&128u32.into(), 128).is_err());
+    assert_eq!(BigUInt::from(1u32).pow_big(&u128::MAX.into(), 1).unwrap(), 1u32.into());
+    assert!(BigUInt::new().pow_big(&u128::MAX.into(), 1).unwrap().is_zero());
+    // fails fast on the size estimate
+    assert!(BigUInt::from(3u32).pow_big(&u128::MAX.into(), 1 << 20).is_err());
+    // 3^81 has 129 bits but the estimate only rules out 82 and more
+    assert!(BigUInt::from(3u32).pow_big(&81u32.into(), 128).is_err());
+    assert_eq!(BigUInt::from(3u32).pow_big(&81u32.into(), 129).unwrap(), BigUInt::from(3u32).powi(81));
+}
+
+#[test]
+fn test_bytes_be() {
+    assert_eq!(BigUInt::from_bytes_be(&[]), BigUInt::new());
+    assert_eq!(BigUInt::from_bytes_be(&[0, 0, 0]), BigUInt::new());
+    let bytes: Vec<u8> = (1..=20).collect();
+    let bi = BigUInt::from_bytes_be(&bytes);
+    assert_eq!(bi.to_hex_string(), "102030405060708090A0B0C0D0E0F1011121314");
+    assert_eq!(bi.to_bytes_be(), bytes);
+    let bi = BigUInt::from_u128(0x80000000000000000000000000000000);
+    assert_eq!(BigUInt::from_bytes_be(&bi.to_bytes_be()), bi);
+    assert_eq!(bi.to_bytes_be().len(), 16);
+}
+
+#[test]
+fn test_div_mod_u64() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+        let value = BigUInt::from_u128(rng.gen::<u128>() >> rng.gen_range(0..128));
+        let divisor = rng.gen::<u64>() >> rng.gen_range(0..64);
+        if divisor == 0 {
+            continue;
+        }
+        let (quotient, remainder) = value.div_mod_u64(divisor);
+        assert_eq!((quotient, BigUInt::from_u64(remainder)), value.div_mod(&BigUInt::from_u64(divisor)));
+    }
+    assert_eq!(BigUInt::new().div_mod_u64(7), (BigUInt::new(), 0));
+    let big = BigUInt::from_u32(1) << 300;
+    let (quotient, remainder) = big.div_mod_u64(3);
+    assert_eq!(quotient.mul_with(&BigUInt::from_u32(3)).add_to(&BigUInt::from_u64(remainder)), big);
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn test_zeroize() {
+    use zeroize::Zeroize;
+    use super::SecretBigUInt;
+    let mut bi = BigUInt::from_hex_str("123456789ABCDEF0123456789ABCDEF0123456789").unwrap();
+    bi.zeroize();
+    assert!(bi.is_zero());
+    assert_eq!(bi, BigUInt::new());
+    bi.add_into(&BigUInt::from_u32(5));
+    assert_eq!(bi, BigUInt::from_u32(5));
+
+    // shrinking and growing in place keep the value intact
+    let mut bi = BigUInt::from_u128(u128::MAX);
+    bi.add_into(&BigUInt::from_u32(1));
+    assert_eq!(bi.to_hex_string(), "100000000000000000000000000000000");
+    bi.shift_left_into(130);
+    bi.shift_right_into(254);
+    assert_eq!(bi, BigUInt::from_u64(1 << 4));
+    bi.sub_into(&BigUInt::from_u32(16));
+    assert!(bi.is_zero());
+
+    // temporaries are allocated at their final size instead of growing
+    let bi = BigUInt::from_hex_str("123456789ABCDEF0123456789ABCDEF0123456789").unwrap();
+    let bytes = bi.to_bytes_be();
+    assert_eq!(bytes.capacity(), bytes.len());
+    let mut or = BigUInt::from_u32(1) | bi.clone();
+    assert_eq!(or.bits.capacity(), or.bits.len());
+    assert_eq!(or, bi.clone() | BigUInt::from_u32(1));
+    or = BigUInt::from_u32(0x100);
+    or |= bi.clone();
+    assert_eq!(or, BigUInt::from_hex_str("123456789ABCDEF0123456789ABCDEF0123456789").unwrap());
+    assert_eq!((bi.clone() & BigUInt::from_u32(0xFF)).bits.capacity(), 1);
+
+    let mut secret = SecretBigUInt::from(BigUInt::from_u32(413));
+    assert_eq!(format!("{:?}", secret), "SecretBigUInt(..)");
+    assert_eq!(*secret.clone().expose_secret(), BigUInt::from_u32(413));
+    secret.zeroize();
+    assert!(secret.expose_secret().is_zero());
+}
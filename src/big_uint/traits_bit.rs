@@ -44,7 +44,8 @@ impl BitAnd for BigUInt {
 
     // rhs is the "right-hand side" of the expression `a & b`
     fn bitand(self, rhs: Self) -> Self {
-        let mut bits = vec![];
+        // allocated at its final size, growing would leave copies of the blocks behind
+        let mut bits = Vec::with_capacity(usize::min(self.bits.len(), rhs.bits.len()));
         for (block1, block2) in self.bits.iter().zip(rhs.bits.iter()) {
             bits.push(*block1 & *block2);
         }
@@ -75,14 +76,14 @@ impl BitOr for BigUInt {
 
     // rhs is the "right-hand side" of the expression `a &= b`
     fn bitor(self, rhs: Self) -> Self {
-        let mut bits = vec![];
+        // allocated at its final size, growing would leave copies of the blocks behind
+        let mut bits = Vec::with_capacity(usize::max(self.bits.len(), rhs.bits.len()));
         for (block1, block2) in self.bits.iter().zip(rhs.bits.iter()) {
             bits.push(*block1 | *block2);
         }
 
-        if self.bits.len() < rhs.bits.len() {
-            bits.extend_from_slice(&rhs.bits[self.bits.len()..]);
-        }
+        let longer = if self.bits.len() < rhs.bits.len() { &rhs.bits } else { &self.bits };
+        bits.extend_from_slice(&longer[bits.len()..]);
 
         let mut res = BigUInt {
             length: usize::max(self.length, rhs.length),
@@ -102,6 +103,7 @@ impl BitOrAssign for BigUInt {
         }
 
         if self.bits.len() < rhs.bits.len() {
+            self.reserve_blocks(rhs.bits.len());
             self.bits.extend_from_slice(&rhs.bits[self.bits.len()..]);
         }
         self.length = usize::max(self.length, rhs.length);
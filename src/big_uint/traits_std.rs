@@ -7,12 +7,31 @@ use crate::BigInt;
 
 use super::BigUInt;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 impl Default for BigUInt {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl Drop for BigUInt {
+    fn drop(&mut self) {
+        self.bits.zeroize();
+    }
+}
+
+/// Clear the blocks, leaving the value zero
+#[cfg(feature = "zeroize")]
+impl Zeroize for BigUInt {
+    fn zeroize(&mut self) {
+        self.bits.zeroize();
+        self.length = 0;
+    }
+}
+
 impl Eq for BigUInt {}
 
 impl PartialOrd for BigUInt {
@@ -0,0 +1,298 @@
+//! Counting functions returning exact BigUInt values.
+//!
+//! Factorials, binomial, multinomial and Catalan numbers are assembled from their prime
+//! factorizations, so they need no big divisions. Stirling numbers, Bell numbers and partition
+//! numbers are calculated from their recurrences with big additions and small multiplications.
+//!
+//! Permutations and subsets are mapped to and from their rank with the factorial and the
+//! combinatorial number system.
+
+use crate::BigUInt;
+use crate::number_theory::small_primes;
+
+#[cfg(test)]
+mod test;
+
+mod ranking;
+pub use ranking::*;
+
+/// The largest argument of the functions built from prime factorizations. They sieve the primes
+/// up to their argument, which takes one byte per number, and the results have billions of digits
+/// long before this bound.
+pub const MAX_ARGUMENT: u64 = u32::MAX as u64;
+
+/// Calculate n! with the prime swing algorithm, n! = ((n / 2)!)^2 * swing(n).
+///
+/// # Panics
+/// Panics if n is larger than [MAX_ARGUMENT].
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::factorial;
+/// assert_eq!(factorial(0), BigUInt::from(1u32));
+/// assert_eq!(factorial(20), BigUInt::from(2432902008176640000u64));
+/// assert_eq!(factorial(1000).to_dec_string().len(), 2568);
+/// ```
+pub fn factorial(n: u64) -> BigUInt {
+    let primes = primes_up_to(n);
+    swing_factorial(n, &primes)
+}
+
+/// Calculate the binomial coefficient n over k, the number of k element subsets of an n element
+/// set, which is 0 for k > n.
+///
+/// With j = min(k, n - k) the result is the product of (n - j + i) / i for i = 1..=j if j^2 <= n
+/// or n is larger than [MAX_ARGUMENT], otherwise it is assembled from its prime factorization.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::binomial;
+/// assert_eq!(binomial(10, 3), BigUInt::from(120u32));
+/// assert_eq!(binomial(3, 10), BigUInt::new());
+/// assert_eq!(binomial(100, 50).to_dec_string(), "100891344545564193334812497256");
+/// assert_eq!(binomial(1 << 33, 2), BigUInt::from(1u64 << 32).mul_with(&BigUInt::from((1u64 << 33) - 1)));
+/// ```
+pub fn binomial(n: u64, k: u64) -> BigUInt {
+    if k > n {
+        return BigUInt::new();
+    }
+    let j = u64::min(k, n - k);
+    if j.saturating_mul(j) <= n || n > MAX_ARGUMENT {
+        // j steps whatever the size of n, every partial product is a binomial coefficient, so the
+        // divisions are exact
+        return (1..=j).fold(BigUInt::from(1u32), |acc, i| acc.mul_with(&BigUInt::from(n - j + i)).div_mod_u64(i).0);
+    }
+    prime_power_product(&primes_up_to(n),
+                        |p| legendre_exponent(n, p) - legendre_exponent(k, p) - legendre_exponent(n - k, p))
+}
+
+/// Calculate the multinomial coefficient (k1 + k2 + ...)! / (k1! * k2! * ...), the number of ways
+/// to split a set into subsets of the given sizes.
+///
+/// # Panics
+/// Panics if the sum of the sizes is larger than [MAX_ARGUMENT].
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::multinomial;
+/// // the permutations of MISSISSIPPI
+/// assert_eq!(multinomial(&[1, 4, 4, 2]), BigUInt::from(34650u32));
+/// assert_eq!(multinomial(&[]), BigUInt::from(1u32));
+/// ```
+pub fn multinomial(sizes: &[u64]) -> BigUInt {
+    let n = sizes.iter().fold(0u64, |acc, size| acc.saturating_add(*size));
+    prime_power_product(&primes_up_to(n), |p| {
+        legendre_exponent(n, p) - sizes.iter().map(|size| legendre_exponent(*size, p)).sum::<u64>()
+    })
+}
+
+/// Calculate the n-th Catalan number (2n over n) / (n + 1), the number of binary trees with n
+/// inner nodes.
+///
+/// # Panics
+/// Panics if 2n is larger than [MAX_ARGUMENT].
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::catalan;
+/// let values: Vec<BigUInt> = (0..8).map(catalan).collect();
+/// assert_eq!(values, [1u32, 1, 2, 5, 14, 42, 132, 429].map(BigUInt::from));
+/// ```
+pub fn catalan(n: u64) -> BigUInt {
+    let m = n.saturating_mul(2);
+    prime_power_product(&primes_up_to(m), |p| {
+        let mut exponent = legendre_exponent(m, p) - 2 * legendre_exponent(n, p);
+        let mut rest = n + 1;
+        while rest.is_multiple_of(p) {
+            rest /= p;
+            exponent -= 1;
+        }
+        exponent
+    })
+}
+
+/// Calculate the unsigned Stirling number of the first kind [n, k], the number of permutations
+/// of n elements with k cycles. The signed Stirling number is (-1)^(n - k) * [n, k].
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::stirling_first;
+/// assert_eq!(stirling_first(4, 2), BigUInt::from(11u32));
+/// assert_eq!(stirling_first(10, 3), BigUInt::from(1172700u32));
+/// assert_eq!(stirling_first(0, 0), BigUInt::from(1u32));
+/// ```
+pub fn stirling_first(n: u64, k: u64) -> BigUInt {
+    // [m, j] = (m - 1) * [m - 1, j] + [m - 1, j - 1]
+    stirling(n, k, |m, _| m - 1)
+}
+
+/// Calculate the Stirling number of the second kind {n, k}, the number of partitions of an n
+/// element set into k non empty subsets.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::stirling_second;
+/// assert_eq!(stirling_second(4, 2), BigUInt::from(7u32));
+/// assert_eq!(stirling_second(10, 3), BigUInt::from(9330u32));
+/// assert_eq!(stirling_second(3, 4), BigUInt::new());
+/// ```
+pub fn stirling_second(n: u64, k: u64) -> BigUInt {
+    // {m, j} = j * {m - 1, j} + {m - 1, j - 1}
+    stirling(n, k, |_, j| j)
+}
+
+/// Calculate the n-th Bell number, the number of partitions of an n element set, with the Bell
+/// triangle.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::bell;
+/// let values: Vec<BigUInt> = (0..8).map(bell).collect();
+/// assert_eq!(values, [1u32, 1, 2, 5, 15, 52, 203, 877].map(BigUInt::from));
+/// ```
+pub fn bell(n: u64) -> BigUInt {
+    // every row starts with the last value of the previous row, which is the Bell number
+    let mut row = vec![BigUInt::from(1u32)];
+    for _ in 0..n {
+        let mut next = Vec::with_capacity(row.len() + 1);
+        next.push(row.last().expect("Unexpected empty row").clone());
+        for value in row.iter() {
+            let sum = next.last().expect("Unexpected empty row").add_to(value);
+            next.push(sum);
+        }
+        row = next;
+    }
+    row.swap_remove(0)
+}
+
+/// Calculate the number of partitions p(n) of n into positive integers with Euler's pentagonal
+/// number recurrence, which needs all values p(0) to p(n).
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::partitions;
+/// assert_eq!(partitions(5), BigUInt::from(7u32));
+/// assert_eq!(partitions(100), BigUInt::from(190569292u32));
+/// ```
+pub fn partitions(n: u64) -> BigUInt {
+    let n = n as usize;
+    let mut values: Vec<BigUInt> = Vec::with_capacity(n + 1);
+    values.push(BigUInt::from(1u32));
+    for m in 1..=n {
+        // p(m) = sum (-1)^(k + 1) * (p(m - k(3k - 1) / 2) + p(m - k(3k + 1) / 2)) for k >= 1
+        let mut positive = BigUInt::new();
+        let mut negative = BigUInt::new();
+        for k in 1.. {
+            let pentagonal = k * (3 * k - 1) / 2;
+            if pentagonal > m {
+                break;
+            }
+            let sum = if k % 2 == 1 { &mut positive } else { &mut negative };
+            sum.add_into(&values[m - pentagonal]);
+            if pentagonal + k <= m {
+                sum.add_into(&values[m - pentagonal - k]);
+            }
+        }
+        values.push(positive.sub_from(&negative));
+    }
+    values.swap_remove(n)
+}
+
+/// The primes up to n
+///
+/// # Panics
+/// Panics if n is larger than [MAX_ARGUMENT].
+fn primes_up_to(n: u64) -> Vec<u64> {
+    assert!(n <= MAX_ARGUMENT, "argument {} larger than {}", n, MAX_ARGUMENT);
+    small_primes(n + 1)
+}
+
+/// The exponent of the prime p in n! by Legendre's formula, sum floor(n / p^i)
+fn legendre_exponent(mut n: u64, p: u64) -> u64 {
+    let mut exponent = 0;
+    while n >= p {
+        n /= p;
+        exponent += n;
+    }
+    exponent
+}
+
+/// The product of p^exponent(p) for all given primes, collecting factors in u64 values before
+/// multiplying them by binary splitting
+fn prime_power_product<F: Fn(u64) -> u64>(primes: &[u64], exponent: F) -> BigUInt {
+    let mut factors = vec![];
+    let mut acc = 1u64;
+    for p in primes {
+        for _ in 0..exponent(*p) {
+            acc = match acc.checked_mul(*p) {
+                Some(product) => product,
+                None => {
+                    factors.push(BigUInt::from(acc));
+                    *p
+                }
+            };
+        }
+    }
+    factors.push(BigUInt::from(acc));
+    product(&factors)
+}
+
+/// Multiply all factors by binary splitting, so the operands of each multiplication have similar
+/// sizes
+fn product(factors: &[BigUInt]) -> BigUInt {
+    match factors {
+        [] => BigUInt::from(1u32),
+        [factor] => factor.clone(),
+        _ => {
+            let (low, high) = factors.split_at(factors.len() / 2);
+            product(low).mul_with(&product(high))
+        }
+    }
+}
+
+/// n! = ((n / 2)!)^2 * swing(n) with the swing factorial n! / ((n / 2)!)^2, whose prime
+/// factorization has the exponents sum floor(n / p^i) mod 2
+fn swing_factorial(n: u64, primes: &[u64]) -> BigUInt {
+    if n < 2 {
+        return BigUInt::from(1u32);
+    }
+    let half = swing_factorial(n / 2, primes);
+    let bound = primes.partition_point(|p| *p <= n);
+    let swing = prime_power_product(&primes[..bound], |p| {
+        let mut exponent = 0;
+        let mut rest = n;
+        while rest >= p {
+            rest /= p;
+            exponent += rest & 1;
+        }
+        exponent
+    });
+    half.mul_with(&half).mul_with(&swing)
+}
+
+/// The Stirling numbers with the recurrence s(m, j) = factor(m, j) * s(m - 1, j) + s(m - 1, j - 1),
+/// calculated row by row
+fn stirling<F: Fn(u64, u64) -> u64>(n: u64, k: u64, factor: F) -> BigUInt {
+    if k > n {
+        return BigUInt::new();
+    }
+    // row[j] = s(m, j) for j <= k, starting with s(0, 0) = 1
+    let mut row = vec![BigUInt::new(); k as usize + 1];
+    row[0] = BigUInt::from(1u32);
+    for m in 1..=n {
+        for j in (1..=usize::min(m as usize, k as usize)).rev() {
+            let value = row[j].mul_with(&BigUInt::from(factor(m, j as u64))).add_to(&row[j - 1]);
+            row[j] = value;
+        }
+        row[0] = BigUInt::new();
+    }
+    row.swap_remove(k as usize)
+}
@@ -0,0 +1,208 @@
+use crate::BigUInt;
+use super::binomial;
+
+impl BigUInt {
+    /// Convert to digits in a mixed radix system, value = d0 + r0 * (d1 + r1 * (d2 + ...)) with
+    /// 0 <= di < ri.
+    ///
+    /// # Arguments
+    /// * radices - the radix of each digit, least significant first
+    ///
+    /// # Returns
+    /// The digits, least significant first, or an error if a radix is zero or the value does not
+    /// fit into the digits
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// // 1 day, 2 hours, 3 minutes and 4 seconds
+    /// let seconds = BigUInt::from(93784u32);
+    /// assert_eq!(seconds.to_mixed_radix(&[60, 60, 24, 365]), Ok(vec![4, 3, 2, 1]));
+    /// assert_eq!(BigUInt::from_mixed_radix(&[4, 3, 2, 1], &[60, 60, 24, 365]), Ok(seconds));
+    /// assert!(BigUInt::from(3600u32).to_mixed_radix(&[60, 60]).is_err());
+    /// ```
+    pub fn to_mixed_radix(&self, radices: &[u64]) -> Result<Vec<u64>, String> {
+        let mut value = self.clone();
+        let mut digits = Vec::with_capacity(radices.len());
+        for radix in radices {
+            if *radix == 0 {
+                return Err("radix must not be zero".to_string());
+            }
+            let (quotient, digit) = value.div_mod_u64(*radix);
+            digits.push(digit);
+            value = quotient;
+        }
+        if value.is_zero() {
+            Ok(digits)
+        } else {
+            Err(format!("{} does not fit into {} digits", self, radices.len()))
+        }
+    }
+
+    /// Create a BigUInt from digits in a mixed radix system, the inverse of
+    /// [BigUInt::to_mixed_radix].
+    ///
+    /// # Returns
+    /// The value or an error if the number of digits and radices differ or a digit is not less
+    /// than its radix
+    pub fn from_mixed_radix(digits: &[u64], radices: &[u64]) -> Result<BigUInt, String> {
+        if digits.len() != radices.len() {
+            return Err(format!("{} digits for {} radices", digits.len(), radices.len()));
+        }
+        let mut value = BigUInt::new();
+        for (digit, radix) in digits.iter().zip(radices.iter()).rev() {
+            if digit >= radix {
+                return Err(format!("digit {} is out of range for radix {}", digit, radix));
+            }
+            value = value.mul_with(&BigUInt::from(*radix)).add_to(&BigUInt::from(*digit));
+        }
+        Ok(value)
+    }
+
+    /// Convert to the factorial number system, value = sum di * i! with 0 <= di <= i.
+    ///
+    /// # Returns
+    /// The digits, least significant first, without leading zeros except for d0, which is always 0
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// // 463 = 3 * 5! + 4 * 4! + 1 * 3! + 0 * 2! + 1 * 1!
+    /// assert_eq!(BigUInt::from(463u32).to_factoradic(), vec![0, 1, 0, 1, 4, 3]);
+    /// assert_eq!(BigUInt::from_factoradic(&[0, 1, 0, 1, 4, 3]), Ok(BigUInt::from(463u32)));
+    /// assert_eq!(BigUInt::new().to_factoradic(), vec![0]);
+    /// ```
+    pub fn to_factoradic(&self) -> Vec<u64> {
+        let mut digits = vec![0];
+        let mut value = self.clone();
+        let mut radix = 2;
+        while !value.is_zero() {
+            let (quotient, digit) = value.div_mod_u64(radix);
+            digits.push(digit);
+            value = quotient;
+            radix += 1;
+        }
+        digits
+    }
+
+    /// Create a BigUInt from digits in the factorial number system, least significant first.
+    ///
+    /// # Returns
+    /// The value or an error if a digit di is larger than i
+    pub fn from_factoradic(digits: &[u64]) -> Result<BigUInt, String> {
+        let radices: Vec<u64> = (1..=digits.len() as u64).collect();
+        BigUInt::from_mixed_radix(digits, &radices)
+    }
+}
+
+/// Find the rank of a permutation of 0..n among all permutations in lexicographic order.
+///
+/// The rank is the Lehmer code, the number of smaller elements right of each element, read as a
+/// number in the factorial number system.
+///
+/// # Returns
+/// The rank in 0..n! or an error if the values are not a permutation of 0..n
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::{rank_permutation, unrank_permutation};
+/// assert_eq!(rank_permutation(&[0, 1, 2]), Ok(BigUInt::new()));
+/// assert_eq!(rank_permutation(&[1, 0, 2]), Ok(BigUInt::from(2u32)));
+/// assert_eq!(rank_permutation(&[2, 1, 0]), Ok(BigUInt::from(5u32)));
+/// assert_eq!(unrank_permutation(&BigUInt::from(2u32), 3), Ok(vec![1, 0, 2]));
+/// assert!(rank_permutation(&[0, 2]).is_err());
+/// ```
+pub fn rank_permutation(permutation: &[usize]) -> Result<BigUInt, String> {
+    let n = permutation.len();
+    // a Fenwick tree counting the elements seen so far, scanning from the right
+    let mut tree = vec![0usize; n + 1];
+    let mut seen = vec![false; n];
+    let mut lehmer = vec![0u64; n];
+    for (position, element) in permutation.iter().enumerate().rev() {
+        if *element >= n || seen[*element] {
+            return Err(format!("{:?} is not a permutation of 0..{}", permutation, n));
+        }
+        seen[*element] = true;
+        let mut index = *element;
+        let mut smaller = 0;
+        while index > 0 {
+            smaller += tree[index];
+            index &= index - 1;
+        }
+        lehmer[n - 1 - position] = smaller as u64;
+        let mut index = *element + 1;
+        while index <= n {
+            tree[index] += 1;
+            index += index & index.wrapping_neg();
+        }
+    }
+    BigUInt::from_factoradic(&lehmer)
+}
+
+/// Find the permutation of 0..n with the given rank in lexicographic order, the inverse of
+/// [rank_permutation].
+///
+/// # Returns
+/// The permutation or an error if rank is not less than n!
+pub fn unrank_permutation(rank: &BigUInt, n: usize) -> Result<Vec<usize>, String> {
+    let radices: Vec<u64> = (1..=n as u64).collect();
+    let lehmer = rank.to_mixed_radix(&radices)
+        .map_err(|_| format!("rank {} is out of range for permutations of {} elements", rank, n))?;
+    let mut remaining: Vec<usize> = (0..n).collect();
+    Ok(lehmer.iter().rev().map(|smaller| remaining.remove(*smaller as usize)).collect())
+}
+
+/// Find the rank of a k element subset of 0..n in colexicographic order, which is its value in
+/// the combinatorial number system, sum (ci over i + 1) for the elements c0 < c1 < ... < c(k-1).
+///
+/// # Returns
+/// The rank in 0..(n over k) or an error if the elements are not strictly increasing and less
+/// than n
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::combinatorics::{rank_combination, unrank_combination};
+/// // the 2 element subsets of 0..4 are 01, 02, 12, 03, 13, 23
+/// assert_eq!(rank_combination(&[1, 3], 4), Ok(BigUInt::from(4u32)));
+/// assert_eq!(unrank_combination(&BigUInt::from(4u32), 4, 2), Ok(vec![1, 3]));
+/// assert!(rank_combination(&[3, 1], 4).is_err());
+/// ```
+pub fn rank_combination(subset: &[u64], n: u64) -> Result<BigUInt, String> {
+    if subset.windows(2).any(|pair| pair[0] >= pair[1]) || subset.last().is_some_and(|last| *last >= n) {
+        return Err(format!("{:?} is not a strictly increasing subset of 0..{}", subset, n));
+    }
+    Ok(subset.iter().enumerate()
+        .fold(BigUInt::new(), |acc, (index, element)| acc.add_to(&binomial(*element, index as u64 + 1))))
+}
+
+/// Find the k element subset of 0..n with the given rank in colexicographic order, the inverse of
+/// [rank_combination].
+///
+/// # Returns
+/// The elements in increasing order or an error if k > n or rank is not less than (n over k)
+pub fn unrank_combination(rank: &BigUInt, n: u64, k: u64) -> Result<Vec<u64>, String> {
+    if k > n || *rank >= binomial(n, k) {
+        return Err(format!("rank {} is out of range for {} element subsets of 0..{}", rank, k, n));
+    }
+    let mut rest = rank.clone();
+    let mut upper = n;
+    let mut subset = vec![0; k as usize];
+    for index in (1..=k).rev() {
+        // the largest element c < upper with (c over index) <= rest, by binary search
+        let (mut low, mut high) = (index - 1, upper - 1);
+        while low < high {
+            let middle = high - (high - low) / 2;
+            if binomial(middle, index) <= rest {
+                low = middle;
+            } else {
+                high = middle - 1;
+            }
+        }
+        rest.sub_into(&binomial(low, index));
+        subset[index as usize - 1] = low;
+        upper = low;
+    }
+    Ok(subset)
+}
@@ -0,0 +1,180 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use crate::BigUInt;
+use super::{bell, binomial, catalan, factorial, multinomial, partitions, rank_combination, rank_permutation,
+            stirling_first, stirling_second, unrank_combination, unrank_permutation};
+
+#[test]
+fn test_factorial() {
+    let mut expected = BigUInt::from(1u32);
+    for n in 0..300u64 {
+        if n > 0 {
+            expected.mul_into(&BigUInt::from(n));
+        }
+        assert_eq!(factorial(n), expected, "{}", n);
+    }
+    let large = factorial(5000);
+    assert_eq!(large, factorial(4999).mul_with(&BigUInt::from(5000u32)));
+    // the number of trailing zeros of 5000! in base 2 is 5000 minus the number of ones of 5000
+    assert_eq!(large.trailing_zeros(), 5000 - 5000u32.count_ones());
+}
+
+#[test]
+#[should_panic(expected = "larger than")]
+fn test_factorial_too_large() {
+    factorial(u64::MAX);
+}
+
+#[test]
+fn test_binomial_multinomial_catalan() {
+    // Pascal's triangle
+    let mut row = vec![BigUInt::from(1u32)];
+    for n in 0..120u64 {
+        for (k, value) in row.iter().enumerate() {
+            assert_eq!(binomial(n, k as u64), *value, "{} {}", n, k);
+        }
+        assert!(binomial(n, n + 1).is_zero());
+        let mut next = vec![BigUInt::from(1u32)];
+        next.extend(row.windows(2).map(|pair| pair[0].add_to(&pair[1])));
+        next.push(BigUInt::from(1u32));
+        row = next;
+    }
+    for n in 0..60u64 {
+        assert_eq!(catalan(n).mul_with(&BigUInt::from(n + 1)), binomial(2 * n, n), "{}", n);
+    }
+    assert_eq!(multinomial(&[7]), BigUInt::from(1u32));
+    assert_eq!(multinomial(&[0, 3, 0]), BigUInt::from(1u32));
+    assert_eq!(multinomial(&[30, 40]), binomial(70, 30));
+    assert_eq!(multinomial(&[10, 20, 30]), binomial(60, 10).mul_with(&binomial(50, 20)));
+
+    // both ways to calculate a binomial coefficient agree
+    for (n, k) in [(1000u64, 31), (1000, 32), (1000, 969), (5000, 2500)] {
+        assert_eq!(binomial(n, k), multinomial(&[k, n - k]), "{} {}", n, k);
+    }
+    // n above u32::MAX needs no sieve
+    let n = 1u64 << 40;
+    assert_eq!(binomial(n, 1), BigUInt::from(n));
+    assert_eq!(binomial(n, n - 2), BigUInt::from(n / 2).mul_with(&BigUInt::from(n - 1)));
+    assert_eq!(binomial(n, 3).mul_with(&BigUInt::from(6u32)),
+               BigUInt::from(n).mul_with(&BigUInt::from(n - 1)).mul_with(&BigUInt::from(n - 2)));
+}
+
+#[test]
+fn test_stirling_bell() {
+    assert_eq!(stirling_first(0, 0), BigUInt::from(1u32));
+    assert!(stirling_first(5, 0).is_zero());
+    assert_eq!(stirling_first(5, 5), BigUInt::from(1u32));
+    assert!(stirling_second(5, 0).is_zero());
+    for n in 0..40u64 {
+        // the permutations by number of cycles, the partitions by number of blocks
+        let cycles = (0..=n).fold(BigUInt::new(), |acc, k| acc.add_to(&stirling_first(n, k)));
+        assert_eq!(cycles, factorial(n), "{}", n);
+        let blocks = (0..=n).fold(BigUInt::new(), |acc, k| acc.add_to(&stirling_second(n, k)));
+        assert_eq!(blocks, bell(n), "{}", n);
+        if n > 0 {
+            assert_eq!(stirling_first(n, 1), factorial(n - 1));
+            assert_eq!(stirling_second(n, 2), (BigUInt::from(1u32) << (n as usize - 1)).sub_from(&1u32.into()));
+            assert_eq!(stirling_first(n, n - 1), binomial(n, 2));
+        }
+    }
+    assert_eq!(bell(20), BigUInt::from(51724158235372u64));
+}
+
+#[test]
+fn test_partitions() {
+    // brute force with partitions into parts of at most the given size
+    let n = 60;
+    let mut counts = vec![0u64; n + 1];
+    counts[0] = 1;
+    for part in 1..=n {
+        for m in part..=n {
+            counts[m] += counts[m - part];
+        }
+    }
+    for (m, count) in counts.iter().enumerate() {
+        assert_eq!(partitions(m as u64), BigUInt::from(*count), "{}", m);
+    }
+    assert_eq!(partitions(1000).to_dec_string(), "24061467864032622473692149727991");
+}
+
+#[test]
+fn test_factoradic_mixed_radix() {
+    let mut rng = StdRng::seed_from_u64(46);
+    for _ in 0..50 {
+        let value = BigUInt::from_u128(rng.gen::<u128>() >> rng.gen_range(0..128));
+        let digits = value.to_factoradic();
+        assert!(digits.iter().enumerate().all(|(index, digit)| *digit <= index as u64));
+        assert_eq!(BigUInt::from_factoradic(&digits), Ok(value.clone()));
+        let radices: Vec<u64> = (0..20).map(|_| rng.gen_range(1..1000)).collect();
+        if let Ok(digits) = value.to_mixed_radix(&radices) {
+            assert_eq!(BigUInt::from_mixed_radix(&digits, &radices), Ok(value));
+        }
+    }
+    // n! - 1 has the largest digits
+    assert_eq!(factorial(6).sub_from(&1u32.into()).to_factoradic(), vec![0, 1, 2, 3, 4, 5]);
+    assert!(BigUInt::from_factoradic(&[0, 2]).is_err());
+    assert!(BigUInt::from_factoradic(&[1]).is_err());
+    assert!(BigUInt::from(5u32).to_mixed_radix(&[0]).is_err());
+    assert!(BigUInt::from_mixed_radix(&[1, 2], &[3]).is_err());
+    assert_eq!(BigUInt::new().to_mixed_radix(&[]), Ok(vec![]));
+}
+
+#[test]
+fn test_rank_permutation() {
+    // all permutations of 0..5 in lexicographic order
+    let mut permutation: Vec<usize> = (0..5).collect();
+    let mut rank = 0u32;
+    loop {
+        assert_eq!(rank_permutation(&permutation), Ok(BigUInt::from(rank)));
+        assert_eq!(unrank_permutation(&BigUInt::from(rank), 5), Ok(permutation.clone()));
+        rank += 1;
+        // the next permutation in lexicographic order
+        let Some(pivot) = (0..4).rev().find(|idx| permutation[*idx] < permutation[*idx + 1]) else {
+            break;
+        };
+        let swap = (pivot + 1..5).rev().find(|idx| permutation[*idx] > permutation[pivot]).unwrap();
+        permutation.swap(pivot, swap);
+        permutation[pivot + 1..].reverse();
+    }
+    assert_eq!(rank, 120);
+    assert!(unrank_permutation(&BigUInt::from(120u32), 5).is_err());
+    assert_eq!(rank_permutation(&[]), Ok(BigUInt::new()));
+    assert!(rank_permutation(&[1, 1]).is_err());
+
+    let mut rng = StdRng::seed_from_u64(46);
+    let mut permutation: Vec<usize> = (0..300).collect();
+    permutation.shuffle(&mut rng);
+    let rank = rank_permutation(&permutation).unwrap();
+    assert!(rank < factorial(300));
+    assert_eq!(unrank_permutation(&rank, 300), Ok(permutation));
+    let last: Vec<usize> = (0..300).rev().collect();
+    assert_eq!(unrank_permutation(&factorial(300).sub_from(&1u32.into()), 300), Ok(last));
+}
+
+#[test]
+fn test_rank_combination() {
+    // all 3 element subsets of 0..7 in colexicographic order
+    let mut rank = 0u32;
+    for c in 2..7 {
+        for b in 1..c {
+            for a in 0..b {
+                assert_eq!(rank_combination(&[a, b, c], 7), Ok(BigUInt::from(rank)));
+                assert_eq!(unrank_combination(&BigUInt::from(rank), 7, 3), Ok(vec![a, b, c]));
+                rank += 1;
+            }
+        }
+    }
+    assert_eq!(BigUInt::from(rank), binomial(7, 3));
+    assert!(unrank_combination(&BigUInt::from(rank), 7, 3).is_err());
+    assert!(unrank_combination(&BigUInt::new(), 2, 3).is_err());
+    assert_eq!(unrank_combination(&BigUInt::new(), 5, 0), Ok(vec![]));
+    assert!(rank_combination(&[1, 7], 7).is_err());
+    assert!(rank_combination(&[2, 2], 7).is_err());
+
+    // subsets of a set too large for a sieve
+    let n = 1u64 << 40;
+    let subset = [5, 1 << 20, 1 << 39, n - 1];
+    let rank = rank_combination(&subset, n).unwrap();
+    assert_eq!(unrank_combination(&rank, n, 4), Ok(subset.to_vec()));
+}
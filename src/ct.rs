@@ -0,0 +1,219 @@
+//! Fixed width integers for secret values, with arithmetic that does not branch or index
+//! memory depending on the values.
+//!
+//! [BigUInt] trims leading zeros, compares before subtracting and divides bit by bit, so the
+//! time it takes depends on the values. [SecretUInt] always works on all of its limbs and
+//! replaces branches by masks, conditions are returned as [Choice] instead of bool.
+//!
+//! Conversions from and to [BigUInt], [Choice::to_bool] and the setup of a [SecretModulus] are
+//! not constant time, the modulus itself is treated as public.
+
+use std::hint::black_box;
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::BigUInt;
+
+#[cfg(test)]
+mod test;
+
+mod math;
+
+mod modulus;
+pub use modulus::*;
+
+/// A condition without a branch, stored as a mask of all zero or all one bits
+#[derive(Clone, Copy, Debug)]
+pub struct Choice(u64);
+
+impl Choice {
+    pub const FALSE: Choice = Choice(0);
+    pub const TRUE: Choice = Choice(u64::MAX);
+
+    pub fn from_bool(value: bool) -> Choice {
+        Choice::from_bit(value as u64)
+    }
+
+    /// Convert to a bool, any branch on the result is no longer constant time
+    pub fn to_bool(self) -> bool {
+        self.0 != 0
+    }
+
+    /// Create a choice from a bit 0 or 1, black_box keeps the optimizer from turning the mask back
+    /// into a branch
+    #[inline]
+    fn from_bit(bit: u64) -> Choice {
+        Choice(0u64.wrapping_sub(black_box(bit)))
+    }
+
+    #[inline]
+    fn mask(self) -> u64 {
+        self.0
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Choice {
+        Choice(!self.0)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Choice {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Choice) -> Choice {
+        Choice(self.0 | rhs.0)
+    }
+}
+
+/// An unsigned integer of LIMBS 64 bit limbs for secret values, all operations run in a time
+/// that only depends on LIMBS.
+///
+/// Arithmetic wraps around at 2^(64 * LIMBS) and reports carries as [Choice]. There is no
+/// PartialEq or Debug output of the value, use [SecretUInt::ct_eq] and
+/// [SecretUInt::to_biguint].
+#[derive(Clone, Copy)]
+pub struct SecretUInt<const LIMBS: usize> {
+    /// the limbs, least significant first
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> SecretUInt<LIMBS> {
+    pub const ZERO: SecretUInt<LIMBS> = SecretUInt { limbs: [0; LIMBS] };
+
+    /// The number of bits
+    pub const BITS: usize = 64 * LIMBS;
+
+    pub fn from_u64(value: u64) -> SecretUInt<LIMBS> {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = value;
+        SecretUInt { limbs }
+    }
+
+    /// Create a value from its limbs, least significant first
+    pub fn from_limbs(limbs: [u64; LIMBS]) -> SecretUInt<LIMBS> {
+        SecretUInt { limbs }
+    }
+
+    /// The limbs, least significant first
+    pub fn to_limbs(&self) -> [u64; LIMBS] {
+        self.limbs
+    }
+
+    /// Convert a BigUInt.
+    ///
+    /// # Returns
+    /// The value or an error if it has more than 64 * LIMBS bits
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, SecretUInt};
+    /// let value = BigUInt::from_hex_str("123456789ABCDEF0123").unwrap();
+    /// let secret = SecretUInt::<2>::from_biguint(&value).unwrap();
+    /// assert_eq!(secret.to_limbs(), [0x456789ABCDEF0123, 0x123]);
+    /// assert_eq!(secret.to_biguint(), value);
+    /// assert!(SecretUInt::<1>::from_biguint(&value).is_err());
+    /// ```
+    pub fn from_biguint(value: &BigUInt) -> Result<SecretUInt<LIMBS>, String> {
+        if value.length() > Self::BITS {
+            return Err(format!("value of {} bits does not fit into {} bits", value.length(), Self::BITS));
+        }
+        let mut limbs = [0; LIMBS];
+        let bytes = value.to_bytes_be();
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks(8)) {
+            *limb = chunk.iter().fold(0, |limb, byte| (limb << 8) | *byte as u64);
+        }
+        Ok(SecretUInt { limbs })
+    }
+
+    pub fn to_biguint(&self) -> BigUInt {
+        let bytes: Vec<u8> = self.limbs.iter().rev().flat_map(|limb| limb.to_be_bytes()).collect();
+        BigUInt::from_bytes_be(&bytes)
+    }
+
+    /// The bit at the given index, the index itself is not secret.
+    ///
+    /// # Panics
+    /// Panics if index is not less than 64 * LIMBS.
+    pub fn bit(&self, index: usize) -> Choice {
+        Choice::from_bit((self.limbs[index / 64] >> (index % 64)) & 1)
+    }
+
+    pub fn ct_is_zero(&self) -> Choice {
+        let acc = self.limbs.iter().fold(0, |acc, limb| acc | limb);
+        // the top bit of acc | -acc is set for any acc != 0
+        !Choice::from_bit((acc | acc.wrapping_neg()) >> 63)
+    }
+
+    pub fn ct_eq(&self, other: &Self) -> Choice {
+        let mut diff = Self::ZERO;
+        for (res, (lhs, rhs)) in diff.limbs.iter_mut().zip(self.limbs.iter().zip(other.limbs.iter())) {
+            *res = lhs ^ rhs;
+        }
+        diff.ct_is_zero()
+    }
+
+    /// Check if self < other, which is the borrow of self - other
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::SecretUInt;
+    /// let a = SecretUInt::<2>::from_limbs([5, 1]);
+    /// let b = SecretUInt::<2>::from_limbs([7, 0]);
+    /// assert!(b.ct_lt(&a).to_bool());
+    /// assert!(!a.ct_lt(&b).to_bool());
+    /// assert!(!a.ct_lt(&a).to_bool());
+    /// ```
+    pub fn ct_lt(&self, other: &Self) -> Choice {
+        self.overflowing_sub(other).1
+    }
+
+    /// Select b if choice is true, else a
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::SecretUInt;
+    /// use simple_big_int::ct::Choice;
+    /// let a = SecretUInt::<1>::from_u64(1);
+    /// let b = SecretUInt::<1>::from_u64(2);
+    /// assert_eq!(SecretUInt::select(&a, &b, Choice::TRUE).to_limbs(), [2]);
+    /// assert_eq!(SecretUInt::select(&a, &b, Choice::FALSE).to_limbs(), [1]);
+    /// ```
+    pub fn select(a: &Self, b: &Self, choice: Choice) -> SecretUInt<LIMBS> {
+        let mut res = *a;
+        for (res, b) in res.limbs.iter_mut().zip(b.limbs.iter()) {
+            *res ^= choice.mask() & (*res ^ b);
+        }
+        res
+    }
+
+    /// Swap a and b if choice is true
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        for (a, b) in a.limbs.iter_mut().zip(b.limbs.iter_mut()) {
+            let diff = choice.mask() & (*a ^ *b);
+            *a ^= diff;
+            *b ^= diff;
+        }
+    }
+}
+
+impl<const LIMBS: usize> Default for SecretUInt<LIMBS> {
+    fn default() -> Self {
+        SecretUInt::ZERO
+    }
+}
+
+impl<const LIMBS: usize> std::fmt::Debug for SecretUInt<LIMBS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SecretUInt<{}>(..)", LIMBS)
+    }
+}
@@ -0,0 +1,93 @@
+use super::{Choice, SecretUInt};
+
+/// a + b + carry, returning the sum and the carry
+#[inline]
+pub(super) fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let sum = a as u128 + b as u128 + carry as u128;
+    (sum as u64, (sum >> 64) as u64)
+}
+
+/// a - b - borrow, returning the difference and the borrow
+#[inline]
+pub(super) fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let diff = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (diff as u64, (diff >> 127) as u64)
+}
+
+/// a + b * c + carry, returning the low and the high limb, this never overflows
+#[inline]
+pub(super) fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let res = a as u128 + b as u128 * c as u128 + carry as u128;
+    (res as u64, (res >> 64) as u64)
+}
+
+impl<const LIMBS: usize> SecretUInt<LIMBS> {
+    /// Add other to self modulo 2^(64 * LIMBS), returning the sum and the carry.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::SecretUInt;
+    /// let (sum, carry) = SecretUInt::<2>::from_limbs([u64::MAX, 1]).overflowing_add(&SecretUInt::from_u64(1));
+    /// assert_eq!(sum.to_limbs(), [0, 2]);
+    /// assert!(!carry.to_bool());
+    /// let (sum, carry) = SecretUInt::<1>::from_u64(u64::MAX).overflowing_add(&SecretUInt::from_u64(2));
+    /// assert_eq!(sum.to_limbs(), [1]);
+    /// assert!(carry.to_bool());
+    /// ```
+    pub fn overflowing_add(&self, other: &Self) -> (SecretUInt<LIMBS>, Choice) {
+        let mut res = SecretUInt::ZERO;
+        let mut carry = 0;
+        for idx in 0..LIMBS {
+            (res.limbs[idx], carry) = adc(self.limbs[idx], other.limbs[idx], carry);
+        }
+        (res, Choice::from_bit(carry))
+    }
+
+    /// Subtract other from self modulo 2^(64 * LIMBS), returning the difference and the borrow,
+    /// which is set if other > self
+    pub fn overflowing_sub(&self, other: &Self) -> (SecretUInt<LIMBS>, Choice) {
+        let mut res = SecretUInt::ZERO;
+        let mut borrow = 0;
+        for idx in 0..LIMBS {
+            (res.limbs[idx], borrow) = sbb(self.limbs[idx], other.limbs[idx], borrow);
+        }
+        (res, Choice::from_bit(borrow))
+    }
+
+    pub fn wrapping_add(&self, other: &Self) -> SecretUInt<LIMBS> {
+        self.overflowing_add(other).0
+    }
+
+    pub fn wrapping_sub(&self, other: &Self) -> SecretUInt<LIMBS> {
+        self.overflowing_sub(other).0
+    }
+
+    /// Multiply self with other, returning the low and the high half of the full product.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::SecretUInt;
+    /// let value = SecretUInt::<2>::from_limbs([u64::MAX, u64::MAX]);
+    /// let (low, high) = value.widening_mul(&value);
+    /// assert_eq!(low.to_limbs(), [1, 0]);
+    /// assert_eq!(high.to_limbs(), [u64::MAX - 1, u64::MAX]);
+    /// ```
+    pub fn widening_mul(&self, other: &Self) -> (SecretUInt<LIMBS>, SecretUInt<LIMBS>) {
+        let mut low = SecretUInt::ZERO;
+        let mut high = SecretUInt::ZERO;
+        for idx in 0..LIMBS {
+            let mut carry = 0;
+            for jdx in 0..LIMBS {
+                let pos = idx + jdx;
+                let limb = if pos < LIMBS { &mut low.limbs[pos] } else { &mut high.limbs[pos - LIMBS] };
+                (*limb, carry) = mac(*limb, self.limbs[jdx], other.limbs[idx], carry);
+            }
+            high.limbs[idx] = carry;
+        }
+        (low, high)
+    }
+
+    pub fn wrapping_mul(&self, other: &Self) -> SecretUInt<LIMBS> {
+        self.widening_mul(other).0
+    }
+}
@@ -0,0 +1,129 @@
+use crate::BigUInt;
+use super::{Choice, SecretUInt};
+use super::math::{adc, mac};
+
+/// An odd public modulus for constant time modular arithmetic on [SecretUInt] values with
+/// Montgomery multiplication, R = 2^(64 * LIMBS).
+#[derive(Clone)]
+pub struct SecretModulus<const LIMBS: usize> {
+    modulus: SecretUInt<LIMBS>,
+    /// -m^-1 mod 2^64
+    m_prime: u64,
+    /// R mod m, the Montgomery form of one
+    r: SecretUInt<LIMBS>,
+    /// R^2 mod m, used to convert into Montgomery form
+    r_squared: SecretUInt<LIMBS>,
+}
+
+impl<const LIMBS: usize> SecretModulus<LIMBS> {
+    /// Create a modulus context, precomputing the Montgomery constants. This is not constant
+    /// time, the modulus is public.
+    ///
+    /// # Panics
+    /// Panics if modulus is even or less than 3.
+    pub fn new(modulus: SecretUInt<LIMBS>) -> SecretModulus<LIMBS> {
+        let value = modulus.to_biguint();
+        assert!(value.is_odd() && value > BigUInt::from(1u32), "modulus must be odd and at least 3");
+        // m^-1 mod 2^64 by Newton iteration, every step doubles the number of correct bits
+        let m0 = modulus.limbs[0];
+        let mut inverse = 1u64;
+        for _ in 0..6 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(m0.wrapping_mul(inverse)));
+        }
+        let r = BigUInt::from(1u32) << SecretUInt::<LIMBS>::BITS;
+        SecretModulus {
+            modulus,
+            m_prime: inverse.wrapping_neg(),
+            r: SecretUInt::from_biguint(&r.div_mod(&value).1).expect("Unexpected oversize residue"),
+            r_squared: SecretUInt::from_biguint(&r.mul_with(&r).div_mod(&value).1)
+                .expect("Unexpected oversize residue"),
+        }
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &SecretUInt<LIMBS> {
+        &self.modulus
+    }
+
+    /// Montgomery product a * b * R^-1 mod m, for a * b < m * R
+    fn mont_mul(&self, a: &SecretUInt<LIMBS>, b: &SecretUInt<LIMBS>) -> SecretUInt<LIMBS> {
+        // coarsely integrated operand scanning, the intermediate t < 2m needs one extra limb
+        let m = &self.modulus.limbs;
+        let mut t = SecretUInt::<LIMBS>::ZERO;
+        let mut t_high = 0;
+        for idx in 0..LIMBS {
+            let mut carry = 0;
+            for jdx in 0..LIMBS {
+                (t.limbs[jdx], carry) = mac(t.limbs[jdx], a.limbs[jdx], b.limbs[idx], carry);
+            }
+            let (sum, overflow) = adc(t_high, carry, 0);
+            // t + u * m is divisible by 2^64, shift it down by one limb
+            let u = t.limbs[0].wrapping_mul(self.m_prime);
+            let (_, mut carry) = mac(t.limbs[0], u, m[0], 0);
+            for (jdx, m_limb) in m.iter().enumerate().skip(1) {
+                (t.limbs[jdx - 1], carry) = mac(t.limbs[jdx], u, *m_limb, carry);
+            }
+            let (sum, carry) = adc(sum, carry, 0);
+            t.limbs[LIMBS - 1] = sum;
+            t_high = overflow + carry;
+        }
+        let (reduced, borrow) = t.overflowing_sub(&self.modulus);
+        // keep t only if it is below m, which needs t_high = 0
+        SecretUInt::select(&reduced, &t, borrow & !Choice::from_bit(t_high))
+    }
+}
+
+impl<const LIMBS: usize> SecretUInt<LIMBS> {
+    /// Calculate self + other mod m for self, other < m
+    pub fn add_mod(&self, other: &Self, modulus: &SecretModulus<LIMBS>) -> SecretUInt<LIMBS> {
+        let (sum, carry) = self.overflowing_add(other);
+        let (reduced, borrow) = sum.overflowing_sub(&modulus.modulus);
+        SecretUInt::select(&reduced, &sum, borrow & !carry)
+    }
+
+    /// Calculate self - other mod m for self, other < m
+    pub fn sub_mod(&self, other: &Self, modulus: &SecretModulus<LIMBS>) -> SecretUInt<LIMBS> {
+        let (diff, borrow) = self.overflowing_sub(other);
+        let corrected = diff.wrapping_add(&modulus.modulus);
+        SecretUInt::select(&diff, &corrected, borrow)
+    }
+
+    /// Calculate self * other mod m for self < 2^(64 * LIMBS) and other < m
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{SecretModulus, SecretUInt};
+    /// let modulus = SecretModulus::new(SecretUInt::<1>::from_u64(97));
+    /// let product = SecretUInt::from_u64(50).mul_mod(&SecretUInt::from_u64(60), &modulus);
+    /// assert_eq!(product.to_limbs(), [90]);
+    /// ```
+    pub fn mul_mod(&self, other: &Self, modulus: &SecretModulus<LIMBS>) -> SecretUInt<LIMBS> {
+        // (a * b * R^-1) * R^2 * R^-1 = a * b
+        modulus.mont_mul(&modulus.mont_mul(self, other), &modulus.r_squared)
+    }
+
+    /// Calculate self^exponent mod m for self < 2^(64 * LIMBS) with a Montgomery ladder, which
+    /// squares and multiplies once for every bit of the exponent, whatever its value.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, SecretModulus, SecretUInt};
+    /// let modulus = SecretModulus::new(SecretUInt::<2>::from_u64(497));
+    /// let res = SecretUInt::from_u64(4).modpow(&SecretUInt::<1>::from_u64(13), &modulus);
+    /// assert_eq!(res.to_biguint(), BigUInt::from(445u32));
+    /// ```
+    pub fn modpow<const EXP_LIMBS: usize>(&self, exponent: &SecretUInt<EXP_LIMBS>,
+                                          modulus: &SecretModulus<LIMBS>) -> SecretUInt<LIMBS> {
+        let mut low = modulus.r;
+        let mut high = modulus.mont_mul(self, &modulus.r_squared);
+        // invariant high = low * self
+        for index in (0..SecretUInt::<EXP_LIMBS>::BITS).rev() {
+            let bit = exponent.bit(index);
+            SecretUInt::conditional_swap(&mut low, &mut high, bit);
+            high = modulus.mont_mul(&low, &high);
+            low = modulus.mont_mul(&low, &low);
+            SecretUInt::conditional_swap(&mut low, &mut high, bit);
+        }
+        modulus.mont_mul(&low, &SecretUInt::from_u64(1))
+    }
+}
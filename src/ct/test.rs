@@ -0,0 +1,117 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use crate::BigUInt;
+use super::{Choice, SecretModulus, SecretUInt};
+
+fn random<const LIMBS: usize>(rng: &mut StdRng) -> SecretUInt<LIMBS> {
+    // mix in edge values, all zero and all one limbs
+    let mut limbs = [0; LIMBS];
+    for limb in limbs.iter_mut() {
+        *limb = match rng.gen_range(0..4) {
+            0 => 0,
+            1 => u64::MAX,
+            _ => rng.gen(),
+        };
+    }
+    SecretUInt::from_limbs(limbs)
+}
+
+/// base^exponent mod m by square and multiply, independent of the mod_int feature
+fn modpow(base: &BigUInt, exponent: &BigUInt, m: &BigUInt) -> BigUInt {
+    exponent.iter().fold(BigUInt::from(1u32), |acc, bit| {
+        let square = acc.mul_with(&acc).div_mod(m).1;
+        if bit { square.mul_with(base).div_mod(m).1 } else { square }
+    })
+}
+
+fn check_arithmetic<const LIMBS: usize>(rng: &mut StdRng) {
+    let bound = BigUInt::from(1u32) << SecretUInt::<LIMBS>::BITS;
+    for _ in 0..200 {
+        let a = random::<LIMBS>(rng);
+        let b = random::<LIMBS>(rng);
+        let (big_a, big_b) = (a.to_biguint(), b.to_biguint());
+        assert_eq!(SecretUInt::<LIMBS>::from_biguint(&big_a).unwrap().to_limbs(), a.to_limbs());
+
+        let (sum, carry) = a.overflowing_add(&b);
+        let big_sum = big_a.add_to(&big_b);
+        assert_eq!(carry.to_bool(), big_sum >= bound);
+        assert_eq!(sum.to_biguint(), big_sum.div_mod(&bound).1);
+
+        let (diff, borrow) = a.overflowing_sub(&b);
+        assert_eq!(borrow.to_bool(), big_a < big_b);
+        assert_eq!(diff.to_biguint(), big_a.add_to(&bound).sub_from(&big_b).div_mod(&bound).1);
+
+        let (low, high) = a.widening_mul(&b);
+        let (big_high, big_low) = big_a.mul_with(&big_b).div_mod(&bound);
+        assert_eq!((low.to_biguint(), high.to_biguint()), (big_low, big_high));
+
+        assert_eq!(a.ct_lt(&b).to_bool(), big_a < big_b);
+        assert_eq!(a.ct_eq(&b).to_bool(), big_a == big_b);
+        assert!(a.ct_eq(&a).to_bool());
+        assert_eq!(a.ct_is_zero().to_bool(), big_a.is_zero());
+        for index in [0, SecretUInt::<LIMBS>::BITS - 1, rng.gen_range(0..SecretUInt::<LIMBS>::BITS)] {
+            assert_eq!(a.bit(index).to_bool(), big_a.shift_right(index).is_odd());
+        }
+
+        let (mut x, mut y) = (a, b);
+        SecretUInt::conditional_swap(&mut x, &mut y, Choice::FALSE);
+        assert_eq!((x.to_limbs(), y.to_limbs()), (a.to_limbs(), b.to_limbs()));
+        SecretUInt::conditional_swap(&mut x, &mut y, Choice::TRUE);
+        assert_eq!((x.to_limbs(), y.to_limbs()), (b.to_limbs(), a.to_limbs()));
+    }
+}
+
+fn check_modular<const LIMBS: usize>(rng: &mut StdRng) {
+    for _ in 0..20 {
+        let mut limbs = random::<LIMBS>(rng).to_limbs();
+        limbs[0] |= 1;
+        limbs[LIMBS - 1] |= 1 << 40;
+        let modulus = SecretModulus::new(SecretUInt::from_limbs(limbs));
+        let big_m = modulus.modulus().to_biguint();
+        let reduce = |value: SecretUInt<LIMBS>| {
+            SecretUInt::<LIMBS>::from_biguint(&value.to_biguint().div_mod(&big_m).1).unwrap()
+        };
+        for _ in 0..10 {
+            let a = reduce(random(rng));
+            let b = reduce(random(rng));
+            let (big_a, big_b) = (a.to_biguint(), b.to_biguint());
+            assert_eq!(a.add_mod(&b, &modulus).to_biguint(), big_a.add_to(&big_b).div_mod(&big_m).1);
+            assert_eq!(a.sub_mod(&b, &modulus).to_biguint(),
+                       big_a.add_to(&big_m).sub_from(&big_b).div_mod(&big_m).1);
+            assert_eq!(a.mul_mod(&b, &modulus).to_biguint(), big_a.mul_with(&big_b).div_mod(&big_m).1);
+            // unreduced bases are allowed
+            let base = random::<LIMBS>(rng);
+            let exponent = random::<2>(rng);
+            assert_eq!(base.modpow(&exponent, &modulus).to_biguint(),
+                       modpow(&base.to_biguint(), &exponent.to_biguint(), &big_m));
+        }
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut rng = StdRng::seed_from_u64(42);
+    check_arithmetic::<1>(&mut rng);
+    check_arithmetic::<2>(&mut rng);
+    check_arithmetic::<5>(&mut rng);
+}
+
+#[test]
+fn test_modular() {
+    let mut rng = StdRng::seed_from_u64(42);
+    check_modular::<1>(&mut rng);
+    check_modular::<2>(&mut rng);
+    check_modular::<4>(&mut rng);
+    // the largest modulus and exponent zero
+    let modulus = SecretModulus::new(SecretUInt::<2>::from_limbs([u64::MAX, u64::MAX]));
+    let base = SecretUInt::from_limbs([u64::MAX - 1, u64::MAX]);
+    assert!(base.modpow(&SecretUInt::<1>::ZERO, &modulus).ct_eq(&SecretUInt::from_u64(1)).to_bool());
+    assert_eq!(base.modpow(&SecretUInt::<1>::from_u64(3), &modulus).to_biguint(),
+               modpow(&base.to_biguint(), &3u32.into(), &modulus.modulus().to_biguint()));
+}
+
+#[test]
+#[should_panic(expected = "modulus must be odd")]
+fn test_even_modulus() {
+    SecretModulus::new(SecretUInt::<2>::from_u64(96));
+}
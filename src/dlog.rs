@@ -0,0 +1,249 @@
+//! Discrete logarithms modulo a prime p, finding x with g^x = h (mod p).
+//!
+//! [bsgs] suits small orders, [pollard_rho] larger prime orders and [pohlig_hellman] reduces
+//! the problem to the prime factors of a group order whose factorization is known.
+
+use std::collections::HashMap;
+
+use crate::{BigUInt, ModInt, Modulus};
+use crate::number_theory::crt;
+
+#[cfg(test)]
+mod test;
+
+/// Prime orders up to this number of bits are solved with baby-step giant-step by [pohlig_hellman]
+const BSGS_MAX_BITS: usize = 32;
+
+/// The number of starting points tried by [pollard_rho]
+const RHO_ATTEMPTS: u64 = 16;
+
+/// Collisions leaving more than 2^RHO_MAX_CANDIDATE_BITS candidates are skipped by [pollard_rho]
+const RHO_MAX_CANDIDATE_BITS: usize = 16;
+
+/// Find the discrete logarithm with the baby-step giant-step algorithm.
+///
+/// Uses time and memory in the order of sqrt(order).
+///
+/// # Arguments
+/// * g - the base, coprime to p
+/// * h - the value to find the logarithm of
+/// * p - the prime modulus
+/// * order - the order of g or a multiple of it, like p - 1
+///
+/// # Returns
+/// The smallest x with g^x = h (mod p) or None if there is no such x
+///
+/// # Panics
+/// Panics if p is less than 2, order is zero or sqrt(order) does not fit into an usize.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::dlog::bsgs;
+/// let p = BigUInt::from(1019u32);
+/// assert_eq!(bsgs(&2u32.into(), &5u32.into(), &p, &1018u32.into()), Some(BigUInt::from(10u32)));
+/// // 4 only generates the quadratic residues
+/// assert_eq!(bsgs(&4u32.into(), &2u32.into(), &p, &1018u32.into()), None);
+/// ```
+pub fn bsgs(g: &BigUInt, h: &BigUInt, p: &BigUInt, order: &BigUInt) -> Option<BigUInt> {
+    let modulus = Modulus::new(p.clone());
+    bsgs_in(&modulus.element(g), &modulus.element(h), order)
+}
+
+/// Find the discrete logarithm with Pollard's rho algorithm.
+///
+/// Uses time in the order of sqrt(order) and constant memory. The random walk is restarted from
+/// a few different points if it ends in a useless collision.
+///
+/// # Arguments
+/// * g - the base, coprime to p
+/// * h - the value to find the logarithm of
+/// * p - the prime modulus
+/// * order - the order of g, which should be prime
+///
+/// # Returns
+/// The logarithm x < order with g^x = h (mod p) or None if h is not in the subgroup of the given
+/// order. For a composite order None is also returned if all walks fail.
+///
+/// # Panics
+/// Panics if p is less than 2 or order is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::dlog::pollard_rho;
+/// // 4 has the prime order 509 modulo 1019
+/// let p = BigUInt::from(1019u32);
+/// let order = BigUInt::from(509u32);
+/// let h = BigUInt::from(4u32).modpow(&BigUInt::from(321u32), &p);
+/// assert_eq!(pollard_rho(&4u32.into(), &h, &p, &order), Some(BigUInt::from(321u32)));
+/// assert_eq!(pollard_rho(&4u32.into(), &2u32.into(), &p, &order), None);
+/// ```
+pub fn pollard_rho(g: &BigUInt, h: &BigUInt, p: &BigUInt, order: &BigUInt) -> Option<BigUInt> {
+    let modulus = Modulus::new(p.clone());
+    rho_in(&modulus.element(g), &modulus.element(h), order)
+}
+
+/// Find the discrete logarithm with the Pohlig–Hellman algorithm.
+///
+/// The logarithm is calculated modulo each prime power dividing the order of g, solving one
+/// base q digit at a time in the subgroup of prime order q, and combined with the Chinese
+/// Remainder Theorem. Digits are found with [bsgs] for small and [pollard_rho] for large q.
+///
+/// # Arguments
+/// * g - the base, coprime to p
+/// * h - the value to find the logarithm of
+/// * p - the prime modulus
+/// * factors - the prime factorization of the order of g or a multiple of it, like p - 1,
+///   as pairs of prime and exponent
+///
+/// # Returns
+/// The smallest x with g^x = h (mod p) or None if there is no such x
+///
+/// # Panics
+/// Panics if p is less than 2 or the factorization does not describe a multiple of the order of g.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::dlog::pohlig_hellman;
+/// // p - 1 = 2^3 * 3^2 * 5 * 7
+/// let p = BigUInt::from(2521u32);
+/// let factors = [(2u32, 3u32), (3, 2), (5, 1), (7, 1)].map(|(q, e)| (BigUInt::from(q), e));
+/// let h = BigUInt::from(11u32).modpow(&BigUInt::from(1234u32), &p);
+/// let x = pohlig_hellman(&11u32.into(), &h, &p, &factors).unwrap();
+/// assert_eq!(BigUInt::from(11u32).modpow(&x, &p), h);
+/// ```
+pub fn pohlig_hellman(g: &BigUInt, h: &BigUInt, p: &BigUInt, factors: &[(BigUInt, u32)]) -> Option<BigUInt> {
+    let modulus = Modulus::new(p.clone());
+    let g = modulus.element(g);
+    let h = modulus.element(h);
+    let one = modulus.one();
+    let order = factors.iter().fold(BigUInt::from(1u32), |acc, (q, e)| acc.mul_with(&q.powi(*e)));
+    assert!(g.pow(&order) == one, "the factorization is not a multiple of the order of g");
+
+    let mut congruences = vec![];
+    for (q, e) in factors {
+        let cofactor = order.div_by(&q.powi(*e));
+        let g0 = g.pow(&cofactor);
+        let h0 = h.pow(&cofactor);
+        // g0 has the order q^f for some f <= e
+        let mut f = 0;
+        let mut gamma = g0.clone();
+        let mut previous = one.clone();
+        while gamma != one {
+            previous = gamma.clone();
+            gamma = gamma.pow(q);
+            f += 1;
+        }
+        if h0.pow(&q.powi(f)) != one {
+            return None;
+        }
+        // previous = g0^(q^(f - 1)) has the order q, every digit is a logarithm to that base
+        let mut x = BigUInt::new();
+        let mut digit_value = BigUInt::from(1u32);
+        for k in 0..f {
+            let target = g0.pow(&x).inverse().ok()?.mul_with(&h0).pow(&q.powi(f - 1 - k));
+            let digit = if q.length() <= BSGS_MAX_BITS {
+                bsgs_in(&previous, &target, q)?
+            } else {
+                rho_in(&previous, &target, q)?
+            };
+            x.add_into(&digit.mul_with(&digit_value));
+            digit_value.mul_into(q);
+        }
+        congruences.push((x, digit_value));
+    }
+    let (x, _) = crt(&congruences)?;
+    if g.pow(&x) == h {
+        Some(x)
+    } else {
+        None
+    }
+}
+
+/// Baby-step giant-step within a Modulus context
+fn bsgs_in(g: &ModInt<'_>, h: &ModInt<'_>, order: &BigUInt) -> Option<BigUInt> {
+    assert!(!order.is_zero(), "order must not be zero");
+    let mut m = order.sqrt();
+    if m.mul_with(&m) < *order {
+        m.add_into(&1u32.into());
+    }
+    let steps = m.to_u64().and_then(|steps| usize::try_from(steps).ok())
+        .expect("order too large for baby-step giant-step");
+
+    // baby steps g^j for 0 <= j < m, keeping the smallest j for each value
+    let mut table = HashMap::with_capacity(steps);
+    let mut value = g.modulus().one();
+    for j in 0..steps {
+        table.entry(value.clone()).or_insert(j);
+        value.mul_into(g);
+    }
+    // giant steps h * g^(-i * m)
+    let factor = value.inverse().ok()?;
+    let mut gamma = h.clone();
+    for i in 0..steps {
+        if let Some(j) = table.get(&gamma) {
+            return Some(BigUInt::from(i as u64).mul_with(&m).add_to(&(*j as u64).into()));
+        }
+        gamma.mul_into(&factor);
+    }
+    None
+}
+
+/// Pollard's rho within a Modulus context
+fn rho_in<'a>(g: &ModInt<'a>, h: &ModInt<'a>, order: &BigUInt) -> Option<BigUInt> {
+    assert!(!order.is_zero(), "order must not be zero");
+    if h.pow(order) != g.modulus().one() {
+        return None;
+    }
+    if *order == BigUInt::from(1u32) {
+        return Some(BigUInt::new());
+    }
+    // exponents are kept reduced by a conditional subtraction, division is slow
+    let reduce = |value: BigUInt| if value >= *order { value.sub_from(order) } else { value };
+    // a step of the walk x = g^a * h^b, partitioned by the low bits of x
+    let step = |(x, a, b): (ModInt<'a>, BigUInt, BigUInt)| {
+        match x.to_biguint().low_bits(8).to_u64().unwrap_or(0) % 3 {
+            0 => (x.mul_with(&x), reduce(a.add_to(&a)), reduce(b.add_to(&b))),
+            1 => (x.mul_with(g), reduce(a.add_to(&1u32.into())), b),
+            _ => (x.mul_with(h), a, reduce(b.add_to(&1u32.into()))),
+        }
+    };
+    for attempt in 0..RHO_ATTEMPTS {
+        let a = BigUInt::from(attempt).div_mod(order).1;
+        let b = BigUInt::from(1u32);
+        let mut tortoise = (g.pow(&a).mul_with(h), a, b);
+        let mut hare = step(tortoise.clone());
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        // g^a1 * h^b1 = g^a2 * h^b2, so (b1 - b2) * x = a2 - a1 (mod order)
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        let r = b1.add_to(order).sub_from(&b2).div_mod(order).1;
+        let s = a2.add_to(order).sub_from(&a1).div_mod(order).1;
+        if r.is_zero() {
+            continue;
+        }
+        let d = r.gcd(order);
+        let (s, remainder) = s.div_mod(&d);
+        if !remainder.is_zero() || d.length() > RHO_MAX_CANDIDATE_BITS {
+            continue;
+        }
+        let reduced = order.div_by(&d);
+        let inverse = r.div_by(&d).mod_inverse(&reduced).expect("Unexpected missing inverse of coprime values");
+        let mut x = s.mul_with(&inverse).div_mod(&reduced).1;
+        // one of the d solutions modulo order is the logarithm
+        let mut candidates = d.to_u64().expect("Unexpected large number of candidates");
+        while candidates > 0 {
+            if g.pow(&x) == *h {
+                return Some(x);
+            }
+            x.add_into(&reduced);
+            candidates -= 1;
+        }
+    }
+    None
+}
@@ -0,0 +1,63 @@
+use rand::Rng;
+use crate::BigUInt;
+use super::{bsgs, pohlig_hellman, pollard_rho};
+
+fn num(src: &str) -> BigUInt {
+    BigUInt::from_dec_str(src).expect("invalid decimal string")
+}
+
+#[test]
+fn test_bsgs() {
+    let p = 1019u64;
+    for g in [2u64, 4, 1018] {
+        // the smallest logarithm of every power of g
+        let mut logs = vec![None; p as usize];
+        let mut power = 1u64;
+        for x in 0..p - 1 {
+            logs[power as usize].get_or_insert(x);
+            power = power * g % p;
+        }
+        for h in 1..p {
+            assert_eq!(bsgs(&g.into(), &h.into(), &p.into(), &(p - 1).into()), logs[h as usize].map(BigUInt::from),
+                       "log_{}({})", g, h);
+        }
+    }
+    assert_eq!(bsgs(&5u32.into(), &1u32.into(), &7u32.into(), &1u32.into()), Some(BigUInt::new()));
+}
+
+#[test]
+fn test_pollard_rho() {
+    // 4 has the prime order q in the group of the safe prime 2q + 1
+    let q = BigUInt::from(16777259u64);
+    let p = BigUInt::from(33554519u64);
+    let g = BigUInt::from(4u32);
+    let mut rng = rand::thread_rng();
+    for _ in 0..5 {
+        let x = BigUInt::from(rng.gen_range(0..16777259u64));
+        let h = g.modpow(&x, &p);
+        assert_eq!(pollard_rho(&g, &h, &p, &q), Some(x));
+    }
+    // -1 is not a quadratic residue, so it is no power of 4
+    assert_eq!(pollard_rho(&g, &p.sub_from(&1u32.into()), &p, &q), None);
+    assert_eq!(pollard_rho(&g, &1u32.into(), &p, &q), Some(BigUInt::new()));
+}
+
+#[test]
+fn test_pohlig_hellman() {
+    // p - 1 = 2^5 * 3^4 * 5^3 * 7 * 11^2 * 1000003 * 268435639, 23 is a generator
+    let p = num("73666476538258618476001");
+    let factors: Vec<(BigUInt, u32)> = [(2u64, 5u32), (3, 4), (5, 3), (7, 1), (11, 2), (1000003, 1), (268435639, 1)]
+        .iter().map(|(q, e)| (BigUInt::from(*q), *e)).collect();
+    let g = BigUInt::from(23u32);
+    let h = num("34281067984220048804006");
+    assert_eq!(pohlig_hellman(&g, &h, &p, &factors), Some(num("123456789012345678")));
+
+    // 23^(2 * 3 * 11) has a smaller order than p - 1, the result is the smallest logarithm
+    let g = g.modpow(&66u32.into(), &p);
+    let x = num("98765432109876");
+    let h = g.modpow(&x, &p);
+    let order = p.sub_from(&1u32.into()).div_by(&66u32.into());
+    assert_eq!(pohlig_hellman(&g, &h, &p, &factors), Some(x.div_mod(&order).1));
+    // 23 is not in the subgroup generated by g
+    assert_eq!(pohlig_hellman(&g, &23u32.into(), &p, &factors), None);
+}
@@ -0,0 +1,287 @@
+//! Elliptic curves y^2 = x^3 + ax + b in short Weierstrass form over prime fields.
+//!
+//! A [Curve] holds the field modulus, the coefficients and a generator of prime order. Points
+//! are exchanged as [AffinePoint] values, while [JacobianPoint] values avoid an inversion per
+//! operation in longer calculations. None of the operations run in constant time.
+
+use crate::{BigUInt, ModInt, Modulus};
+
+#[cfg(test)]
+mod test;
+
+mod math;
+
+mod sec1;
+
+/// A point in affine coordinates or the point at infinity
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AffinePoint {
+    coordinates: Option<(BigUInt, BigUInt)>,
+}
+
+/// A point in Jacobian coordinates (X, Y, Z) for the affine point (X / Z^2, Y / Z^3), the point at
+/// infinity has Z = 0.
+///
+/// Equality compares the represented points.
+#[derive(Clone, Debug)]
+pub struct JacobianPoint<'a> {
+    curve: &'a Curve,
+    x: ModInt<'a>,
+    y: ModInt<'a>,
+    z: ModInt<'a>,
+}
+
+/// An elliptic curve y^2 = x^3 + ax + b over the prime field of order p with a generator.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Curve {
+    field: Modulus,
+    a: BigUInt,
+    b: BigUInt,
+    generator: AffinePoint,
+    order: BigUInt,
+}
+
+impl AffinePoint {
+    pub fn new(x: BigUInt, y: BigUInt) -> AffinePoint {
+        AffinePoint { coordinates: Some((x, y)) }
+    }
+
+    pub fn infinity() -> AffinePoint {
+        AffinePoint { coordinates: None }
+    }
+
+    #[inline]
+    pub fn is_infinity(&self) -> bool {
+        self.coordinates.is_none()
+    }
+
+    /// The x coordinate, None for the point at infinity
+    #[inline]
+    pub fn x(&self) -> Option<&BigUInt> {
+        self.coordinates.as_ref().map(|(x, _)| x)
+    }
+
+    /// The y coordinate, None for the point at infinity
+    #[inline]
+    pub fn y(&self) -> Option<&BigUInt> {
+        self.coordinates.as_ref().map(|(_, y)| y)
+    }
+}
+
+impl Curve {
+    /// Create a curve from its parameters.
+    ///
+    /// # Arguments
+    /// * p - the field modulus, an odd prime, primality is not checked
+    /// * a, b - the coefficients, less than p
+    /// * generator - a point on the curve
+    /// * order - the prime order of the generator
+    ///
+    /// # Returns
+    /// The curve or an error if the curve is singular, the generator is not on the curve or
+    /// order times the generator is not the point at infinity
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::ec::{AffinePoint, Curve};
+    /// // y^2 = x^3 + 2x + 3 over F_97, (3, 6) has order 5
+    /// let curve = Curve::new(97u32.into(), 2u32.into(), 3u32.into(),
+    ///                        AffinePoint::new(3u32.into(), 6u32.into()), 5u32.into()).unwrap();
+    /// assert_eq!(curve.double(curve.generator()), AffinePoint::new(80u32.into(), 10u32.into()));
+    /// ```
+    pub fn new(p: BigUInt, a: BigUInt, b: BigUInt, generator: AffinePoint, order: BigUInt) -> Result<Curve, String> {
+        if p.is_even() || p < BigUInt::from(5u32) {
+            return Err(format!("the field modulus must be an odd prime larger than 3, got {}", p));
+        }
+        if a >= p || b >= p {
+            return Err("the coefficients must be less than the field modulus".to_string());
+        }
+        let curve = Curve::from_parameters(p, a, b, generator, order);
+        // the discriminant 4a^3 + 27b^2 must not vanish
+        let a = curve.field.element(&curve.a);
+        let b = curve.field.element(&curve.b);
+        let discriminant = curve.field.element(&4u32.into()).mul_with(&a.mul_with(&a).mul_with(&a))
+            .add_to(&curve.field.element(&27u32.into()).mul_with(&b.mul_with(&b)));
+        if discriminant.is_zero() {
+            return Err("the curve is singular".to_string());
+        }
+        curve.validate(&curve.generator).map_err(|err| format!("invalid generator: {}", err))?;
+        Ok(curve)
+    }
+
+    /// Create a curve from its parameters without any checks
+    fn from_parameters(p: BigUInt, a: BigUInt, b: BigUInt, generator: AffinePoint, order: BigUInt) -> Curve {
+        Curve {
+            field: Modulus::new(p),
+            a,
+            b,
+            generator,
+            order,
+        }
+    }
+
+    /// The curve secp256k1 from SEC 2, y^2 = x^3 + 7
+    pub fn secp256k1() -> Curve {
+        // the published parameters are validated once in the tests, not on every call
+        Curve::from_parameters(
+            hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F"),
+            BigUInt::new(),
+            7u32.into(),
+            AffinePoint::new(hex("79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798"),
+                             hex("483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8")),
+            hex("FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141"),
+        )
+    }
+
+    /// The NIST curve P-256 (secp256r1), y^2 = x^3 - 3x + b
+    pub fn p256() -> Curve {
+        Curve::from_parameters(
+            hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF"),
+            hex("FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC"),
+            hex("5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B"),
+            AffinePoint::new(hex("6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296"),
+                             hex("4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5")),
+            hex("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551"),
+        )
+    }
+
+    /// The field modulus
+    #[inline]
+    pub fn p(&self) -> &BigUInt {
+        self.field.modulus()
+    }
+
+    #[inline]
+    pub fn a(&self) -> &BigUInt {
+        &self.a
+    }
+
+    #[inline]
+    pub fn b(&self) -> &BigUInt {
+        &self.b
+    }
+
+    #[inline]
+    pub fn generator(&self) -> &AffinePoint {
+        &self.generator
+    }
+
+    /// The order of the generator
+    #[inline]
+    pub fn order(&self) -> &BigUInt {
+        &self.order
+    }
+
+    /// Check if a point satisfies the curve equation, the point at infinity always does
+    pub fn is_on_curve(&self, point: &AffinePoint) -> bool {
+        match &point.coordinates {
+            None => true,
+            Some((x, y)) => {
+                let y = self.field.element(y);
+                y.mul_with(&y) == self.rhs(&self.field.element(x))
+            }
+        }
+    }
+
+    /// Validate a point received from elsewhere, as required before using it in a protocol.
+    ///
+    /// # Returns
+    /// An error if the point is the point at infinity, has coordinates not less than p, is not on
+    /// the curve or is not in the subgroup generated by the generator
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::ec::{AffinePoint, Curve};
+    /// let curve = Curve::secp256k1();
+    /// assert!(curve.validate(curve.generator()).is_ok());
+    /// assert!(curve.validate(&AffinePoint::new(1u32.into(), 1u32.into())).is_err());
+    /// ```
+    pub fn validate(&self, point: &AffinePoint) -> Result<(), String> {
+        let (x, y) = point.coordinates.as_ref().ok_or_else(|| "the point at infinity".to_string())?;
+        if x >= self.p() || y >= self.p() {
+            return Err("coordinates not less than the field modulus".to_string());
+        }
+        if !self.is_on_curve(point) {
+            return Err(format!("the point ({}, {}) is not on the curve", x, y));
+        }
+        if !self.to_jacobian(point).mul(&self.order).is_infinity() {
+            return Err(format!("the point ({}, {}) is not in the subgroup of the generator", x, y));
+        }
+        Ok(())
+    }
+
+    /// Add two points.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::ec::Curve;
+    /// let curve = Curve::p256();
+    /// let g = curve.generator();
+    /// assert_eq!(curve.add(&curve.double(g), g), curve.mul(g, &3u32.into()));
+    /// assert!(curve.add(g, &curve.negate(g)).is_infinity());
+    /// ```
+    pub fn add(&self, lhs: &AffinePoint, rhs: &AffinePoint) -> AffinePoint {
+        self.to_jacobian(lhs).add(&self.to_jacobian(rhs)).to_affine()
+    }
+
+    pub fn double(&self, point: &AffinePoint) -> AffinePoint {
+        self.to_jacobian(point).double().to_affine()
+    }
+
+    pub fn negate(&self, point: &AffinePoint) -> AffinePoint {
+        match &point.coordinates {
+            None => AffinePoint::infinity(),
+            Some((x, y)) => AffinePoint::new(x.clone(), self.field.element(y).negate().to_biguint()),
+        }
+    }
+
+    /// Multiply a point by a scalar with a Montgomery ladder, see [JacobianPoint::mul].
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::ec::Curve;
+    /// let curve = Curve::secp256k1();
+    /// let point = curve.mul(curve.generator(), &2u32.into());
+    /// assert_eq!(point.x().unwrap().to_hex_string(),
+    ///            "C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5");
+    /// assert!(curve.mul(curve.generator(), curve.order()).is_infinity());
+    /// ```
+    pub fn mul(&self, point: &AffinePoint, scalar: &BigUInt) -> AffinePoint {
+        self.to_jacobian(point).mul(scalar).to_affine()
+    }
+
+    /// Multiply the generator by a scalar
+    pub fn mul_generator(&self, scalar: &BigUInt) -> AffinePoint {
+        self.mul(&self.generator, scalar)
+    }
+
+    /// Convert a point to Jacobian coordinates with Z = 1
+    pub fn to_jacobian(&self, point: &AffinePoint) -> JacobianPoint<'_> {
+        match &point.coordinates {
+            None => JacobianPoint {
+                curve: self,
+                x: self.field.one(),
+                y: self.field.one(),
+                z: self.field.zero(),
+            },
+            Some((x, y)) => JacobianPoint {
+                curve: self,
+                x: self.field.element(x),
+                y: self.field.element(y),
+                z: self.field.one(),
+            },
+        }
+    }
+
+    /// Calculate x^3 + ax + b
+    fn rhs<'a>(&'a self, x: &ModInt<'a>) -> ModInt<'a> {
+        x.mul_with(x).add_to(&self.field.element(&self.a)).mul_with(x).add_to(&self.field.element(&self.b))
+    }
+}
+
+fn hex(src: &str) -> BigUInt {
+    BigUInt::from_hex_str(src).expect("invalid hex string")
+}
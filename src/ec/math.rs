@@ -0,0 +1,129 @@
+use crate::BigUInt;
+use super::{AffinePoint, JacobianPoint};
+
+impl<'a> JacobianPoint<'a> {
+    #[inline]
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// Convert to affine coordinates, this needs one inversion
+    pub fn to_affine(&self) -> AffinePoint {
+        match self.z.inverse() {
+            Err(_) => AffinePoint::infinity(),
+            Ok(z_inv) => {
+                let z_inv2 = z_inv.mul_with(&z_inv);
+                AffinePoint::new(self.x.mul_with(&z_inv2).to_biguint(),
+                                 self.y.mul_with(&z_inv2).mul_with(&z_inv).to_biguint())
+            }
+        }
+    }
+
+    pub fn negate(&self) -> JacobianPoint<'a> {
+        JacobianPoint {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: self.y.negate(),
+            z: self.z.clone(),
+        }
+    }
+
+    /// Double the point
+    pub fn double(&self) -> JacobianPoint<'a> {
+        if self.is_infinity() || self.y.is_zero() {
+            return self.curve.to_jacobian(&AffinePoint::infinity());
+        }
+        let field = &self.curve.field;
+        let xx = self.x.mul_with(&self.x);
+        let yy = self.y.mul_with(&self.y);
+        let zz = self.z.mul_with(&self.z);
+        // S = 4 * X * Y^2, M = 3 * X^2 + a * Z^4
+        let s = self.x.mul_with(&yy).mul_with(&field.element(&4u32.into()));
+        let m = xx.mul_with(&field.element(&3u32.into()))
+            .add_to(&field.element(&self.curve.a).mul_with(&zz.mul_with(&zz)));
+        let x = m.mul_with(&m).sub_from(&s.add_to(&s));
+        let y = m.mul_with(&s.sub_from(&x)).sub_from(&yy.mul_with(&yy).mul_with(&field.element(&8u32.into())));
+        let z = self.y.mul_with(&self.z).mul_with(&field.element(&2u32.into()));
+        JacobianPoint { curve: self.curve, x, y, z }
+    }
+
+    /// Add two points, handling equal and opposite points
+    ///
+    /// # Panics
+    /// Panics if the points are on different curves.
+    pub fn add(&self, other: &Self) -> JacobianPoint<'a> {
+        assert!(std::ptr::eq(self.curve, other.curve) || self.curve == other.curve,
+                "points on different curves can not be added");
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+        // U1 = X1 * Z2^2, U2 = X2 * Z1^2, S1 = Y1 * Z2^3, S2 = Y2 * Z1^3
+        let z1z1 = self.z.mul_with(&self.z);
+        let z2z2 = other.z.mul_with(&other.z);
+        let u1 = self.x.mul_with(&z2z2);
+        let u2 = other.x.mul_with(&z1z1);
+        let s1 = self.y.mul_with(&z2z2).mul_with(&other.z);
+        let s2 = other.y.mul_with(&z1z1).mul_with(&self.z);
+        if u1 == u2 {
+            return if s1 == s2 {
+                self.double()
+            } else {
+                self.curve.to_jacobian(&AffinePoint::infinity())
+            };
+        }
+        let h = u2.sub_from(&u1);
+        let r = s2.sub_from(&s1);
+        let hh = h.mul_with(&h);
+        let hhh = hh.mul_with(&h);
+        let v = u1.mul_with(&hh);
+        let x = r.mul_with(&r).sub_from(&hhh).sub_from(&v.add_to(&v));
+        let y = r.mul_with(&v.sub_from(&x)).sub_from(&s1.mul_with(&hhh));
+        let z = h.mul_with(&self.z).mul_with(&other.z);
+        JacobianPoint { curve: self.curve, x, y, z }
+    }
+
+    /// Multiply the point by a scalar with a Montgomery ladder.
+    ///
+    /// Every bit of the scalar costs one addition and one doubling, but the underlying integer
+    /// arithmetic does not run in constant time.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::ec::Curve;
+    /// let curve = Curve::p256();
+    /// let g = curve.to_jacobian(curve.generator());
+    /// assert_eq!(g.mul(&5u32.into()), g.double().double().add(&g));
+    /// ```
+    pub fn mul(&self, scalar: &BigUInt) -> JacobianPoint<'a> {
+        let mut r0 = self.curve.to_jacobian(&AffinePoint::infinity());
+        let mut r1 = self.clone();
+        // r1 = r0 + self at the start of every step
+        for bit in scalar.iter() {
+            if bit {
+                r0 = r0.add(&r1);
+                r1 = r1.double();
+            } else {
+                r1 = r0.add(&r1);
+                r0 = r0.double();
+            }
+        }
+        r0
+    }
+}
+
+impl PartialEq for JacobianPoint<'_> {
+    /// Points are equal if they represent the same affine point
+    fn eq(&self, other: &Self) -> bool {
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+        let z1z1 = self.z.mul_with(&self.z);
+        let z2z2 = other.z.mul_with(&other.z);
+        self.x.mul_with(&z2z2) == other.x.mul_with(&z1z1)
+            && self.y.mul_with(&z2z2).mul_with(&other.z) == other.y.mul_with(&z1z1).mul_with(&self.z)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::BigUInt;
+use crate::number_theory::sqrt_mod_prime;
+use super::{AffinePoint, Curve};
+
+impl Curve {
+    /// The number of bytes of an encoded field element
+    pub fn field_bytes(&self) -> usize {
+        self.p().length().div_ceil(8)
+    }
+
+    /// Encode a point in compressed SEC1 format, `02` or `03` for an even or odd y followed by x.
+    /// The point at infinity is encoded as `00`.
+    ///
+    /// # Panics
+    /// Panics if a coordinate is not less than the field modulus, see [Curve::validate].
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::ec::Curve;
+    /// let curve = Curve::secp256k1();
+    /// let encoded = curve.encode_compressed(curve.generator());
+    /// assert_eq!(encoded.len(), 33);
+    /// assert_eq!(curve.decode_point(&encoded).unwrap(), *curve.generator());
+    /// ```
+    pub fn encode_compressed(&self, point: &AffinePoint) -> Vec<u8> {
+        match &point.coordinates {
+            None => vec![0],
+            Some((x, y)) => {
+                // the parity of y is only meaningful for a reduced coordinate
+                assert!(y < self.p(), "point coordinate not less than the field modulus");
+                let mut res = vec![if y.is_odd() { 3 } else { 2 }];
+                res.extend(self.encode_field_element(x));
+                res
+            }
+        }
+    }
+
+    /// Encode a point in uncompressed SEC1 format, `04` followed by x and y.
+    /// The point at infinity is encoded as `00`.
+    ///
+    /// # Panics
+    /// Panics if a coordinate is not less than the field modulus, see [Curve::validate].
+    pub fn encode_uncompressed(&self, point: &AffinePoint) -> Vec<u8> {
+        match &point.coordinates {
+            None => vec![0],
+            Some((x, y)) => {
+                let mut res = vec![4];
+                res.extend(self.encode_field_element(x));
+                res.extend(self.encode_field_element(y));
+                res
+            }
+        }
+    }
+
+    /// Decode a point in compressed or uncompressed SEC1 format.
+    ///
+    /// The point is checked to be on the curve, use [Curve::validate] to also check the subgroup.
+    ///
+    /// # Returns
+    /// The point or an error if the encoding is invalid or the point is not on the curve
+    pub fn decode_point(&self, src: &[u8]) -> Result<AffinePoint, String> {
+        let length = self.field_bytes();
+        match (src.first(), src.len()) {
+            (Some(0), 1) => Ok(AffinePoint::infinity()),
+            (Some(tag @ (2 | 3)), len) if len == length + 1 => {
+                let x = self.decode_field_element(&src[1..])?;
+                let rhs = self.rhs(&self.field.element(&x)).to_biguint();
+                let y = sqrt_mod_prime(&rhs, self.p())
+                    .ok_or_else(|| format!("no point with x = {} on the curve", x))?;
+                let odd = *tag == 3;
+                if y.is_odd() == odd {
+                    Ok(AffinePoint::new(x, y))
+                } else if y.is_zero() {
+                    Err(format!("no point with x = {} and odd y on the curve", x))
+                } else {
+                    Ok(AffinePoint::new(x, self.p().sub_from(&y)))
+                }
+            }
+            (Some(4), len) if len == 2 * length + 1 => {
+                let point = AffinePoint::new(self.decode_field_element(&src[1..=length])?,
+                                             self.decode_field_element(&src[length + 1..])?);
+                if self.is_on_curve(&point) {
+                    Ok(point)
+                } else {
+                    Err("the point is not on the curve".to_string())
+                }
+            }
+            _ => Err(format!("invalid SEC1 point encoding of {} bytes", src.len())),
+        }
+    }
+
+    fn encode_field_element(&self, value: &BigUInt) -> Vec<u8> {
+        assert!(value < self.p(), "point coordinate not less than the field modulus");
+        let bytes = value.to_bytes_be();
+        let mut res = vec![0; self.field_bytes() - bytes.len()];
+        res.extend(bytes);
+        res
+    }
+
+    fn decode_field_element(&self, src: &[u8]) -> Result<BigUInt, String> {
+        let value = BigUInt::from_bytes_be(src);
+        if value >= *self.p() {
+            Err("field element not less than the field modulus".to_string())
+        } else {
+            Ok(value)
+        }
+    }
+}
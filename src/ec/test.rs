@@ -0,0 +1,139 @@
+use rand::Rng;
+use crate::BigUInt;
+use super::{AffinePoint, Curve};
+
+fn hex(src: &str) -> BigUInt {
+    BigUInt::from_hex_str(src).expect("invalid hex string")
+}
+
+fn point(x: &str, y: &str) -> AffinePoint {
+    AffinePoint::new(hex(x), hex(y))
+}
+
+fn random_scalar(rng: &mut impl Rng) -> BigUInt {
+    (0..4).fold(BigUInt::new(), |acc, _| (acc << 64).add_to(&rng.gen::<u64>().into()))
+}
+
+#[test]
+fn test_known_answers() {
+    let scalar = hex("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
+    let curve = Curve::secp256k1();
+    assert_eq!(curve.mul_generator(&2u32.into()),
+               point("C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+                     "1AE168FEA63DC339A3C58419466CEAEEF7F632653266D0E1236431A950CFE52A"));
+    assert_eq!(curve.mul_generator(&3u32.into()),
+               point("F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                     "388F7B0F632DE8140FE337E62A37F3566500A99934C2231B6CB9FD7584B8E672"));
+    assert_eq!(curve.mul_generator(&scalar),
+               point("34F9460F0E4F08393D192B3C5133A6BA099AA0AD9FD54EBCCFACDFA239FF49C6",
+                     "0B71EA9BD730FD8923F6D25A7A91E7DD7728A960686CB5A901BB419E0F2CA232"));
+
+    let curve = Curve::p256();
+    assert_eq!(curve.mul_generator(&2u32.into()),
+               point("7CF27B188D034F7E8A52380304B51AC3C08969E277F21B35A60B48FC47669978",
+                     "07775510DB8ED040293D9AC69F7430DBBA7DADE63CE982299E04B79D227873D1"));
+    assert_eq!(curve.mul_generator(&3u32.into()),
+               point("5ECBE4D1A6330A44C8F7EF951D4BF165E6C6B721EFADA985FB41661BC6E7FD6C",
+                     "8734640C4998FF7E374B06CE1A64A2ECD82AB036384FB83D9A79B127A27D5032"));
+    assert_eq!(curve.mul_generator(&scalar),
+               point("4EAE4A96650738771F08F8C5EAA7736E52B3B8D1F8A2CEF531895107530FB43E",
+                     "7C4EDB022D516DE84075008626D6F19BDCCCD6CDEAA7239C6BA5671D026808BE"));
+    // (n - 1) * G = -G
+    assert_eq!(curve.mul_generator(&curve.order().sub_from(&1u32.into())), curve.negate(curve.generator()));
+    assert!(curve.mul_generator(&BigUInt::new()).is_infinity());
+}
+
+#[test]
+fn test_group_laws() {
+    let mut rng = rand::thread_rng();
+    for curve in [Curve::secp256k1(), Curve::p256()] {
+        let a = random_scalar(&mut rng);
+        let b = random_scalar(&mut rng);
+        let pa = curve.mul_generator(&a);
+        let pb = curve.mul_generator(&b);
+        assert_eq!(curve.add(&pa, &pb), curve.mul_generator(&a.add_to(&b)));
+        assert_eq!(curve.add(&pa, &pa), curve.double(&pa));
+        assert_eq!(curve.mul(&pa, &b), curve.mul(&pb, &a));
+        assert!(curve.validate(&pa).is_ok());
+        assert!(curve.add(&pa, &curve.negate(&pa)).is_infinity());
+        assert_eq!(curve.add(&pa, &AffinePoint::infinity()), pa);
+        let jacobian = curve.to_jacobian(&pa).double().add(&curve.to_jacobian(&pb));
+        assert_eq!(jacobian.to_affine(), curve.add(&curve.double(&pa), &pb));
+    }
+}
+
+#[test]
+fn test_small_curve() {
+    // y^2 = x^3 + 2x + 3 over F_97 has 100 points including infinity, (3, 6) generates a subgroup of order 5
+    let curve = Curve::new(97u32.into(), 2u32.into(), 3u32.into(),
+                           AffinePoint::new(3u32.into(), 6u32.into()), 5u32.into()).unwrap();
+    let points: Vec<AffinePoint> = (0..97u32).flat_map(|x| (0..97u32).map(move |y| (x, y)))
+        .filter(|(x, y)| (y * y) % 97 == (x * x * x + 2 * x + 3) % 97)
+        .map(|(x, y)| AffinePoint::new(x.into(), y.into()))
+        .collect();
+    assert_eq!(points.len(), 99);
+    for point in points.iter() {
+        assert!(curve.is_on_curve(point));
+        // the group of order 100 is annihilated by 100
+        assert!(curve.mul(point, &100u32.into()).is_infinity());
+        let encoded = curve.encode_compressed(point);
+        assert_eq!(encoded.len(), 2);
+        assert_eq!(curve.decode_point(&encoded).as_ref(), Ok(point));
+        assert_eq!(curve.decode_point(&curve.encode_uncompressed(point)).as_ref(), Ok(point));
+    }
+    let mut multiple = AffinePoint::infinity();
+    for _ in 0..5 {
+        multiple = curve.add(&multiple, curve.generator());
+        assert_eq!(curve.validate(&multiple).is_ok(), !multiple.is_infinity());
+    }
+    assert!(multiple.is_infinity());
+    // points outside the subgroup of order 5 are rejected
+    assert!(points.iter().any(|point| curve.validate(point).is_err()));
+    assert!(Curve::new(97u32.into(), 0u32.into(), 0u32.into(),
+                       AffinePoint::new(0u32.into(), 0u32.into()), 1u32.into()).is_err());
+}
+
+#[test]
+fn test_builtin_curves() {
+    for curve in [Curve::secp256k1(), Curve::p256()] {
+        let checked = Curve::new(curve.p().clone(), curve.a().clone(), curve.b().clone(), curve.generator().clone(),
+                                 curve.order().clone());
+        assert_eq!(checked, Ok(curve));
+    }
+}
+
+#[test]
+fn test_sec1() {
+    let curve = Curve::secp256k1();
+    let encoded = curve.encode_compressed(&curve.mul_generator(&2u32.into()));
+    assert_eq!(BigUInt::from_bytes_be(&encoded).to_hex_string(),
+               "2C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5");
+    let curve = Curve::p256();
+    let two_g = curve.mul_generator(&2u32.into());
+    let encoded = curve.encode_compressed(&two_g);
+    assert_eq!(encoded[0], 3);
+    assert_eq!(curve.decode_point(&encoded), Ok(two_g.clone()));
+    let encoded = curve.encode_uncompressed(&two_g);
+    assert_eq!(encoded.len(), 65);
+    assert_eq!(curve.decode_point(&encoded), Ok(two_g));
+    assert_eq!(curve.encode_compressed(&AffinePoint::infinity()), vec![0]);
+    assert_eq!(curve.decode_point(&[0]), Ok(AffinePoint::infinity()));
+
+    assert!(curve.decode_point(&[]).is_err());
+    assert!(curve.decode_point(&[2; 32]).is_err());
+    let mut invalid = curve.encode_uncompressed(curve.generator());
+    invalid[64] ^= 1;
+    assert!(curve.decode_point(&invalid).is_err());
+    // x = p is out of range
+    let mut out_of_range = vec![2];
+    out_of_range.extend(curve.p().to_bytes_be());
+    assert!(curve.decode_point(&out_of_range).is_err());
+}
+
+#[test]
+#[should_panic(expected = "point coordinate not less than the field modulus")]
+fn test_sec1_unreduced() {
+    let curve = Curve::p256();
+    let x = curve.generator().x().unwrap().add_to(curve.p());
+    curve.encode_uncompressed(&AffinePoint::new(x, curve.generator().y().unwrap().clone()));
+}
@@ -0,0 +1,231 @@
+use crate::Rational;
+
+#[cfg(test)]
+mod test;
+
+mod traits_std;
+
+mod traits_math;
+
+/// A closed interval [lower, upper] with Rational endpoints.
+///
+/// All operations return intervals that are guaranteed to enclose every result of the operation
+/// applied to values taken from the operand intervals.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Interval {
+    lower: Rational,
+    upper: Rational,
+}
+
+impl Interval {
+    /// Create the interval [lower, upper].
+    ///
+    /// # Panics
+    /// Panics if lower is greater than upper.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Interval, Rational};
+    /// let interval = Interval::new(Rational::from(1), Rational::from(2));
+    /// assert!(interval.contains(&Rational::from((3, 2))));
+    /// ```
+    pub fn new(lower: Rational, upper: Rational) -> Interval {
+        assert!(lower <= upper, "lower bound must not be greater than upper bound");
+        Interval { lower, upper }
+    }
+
+    /// Create the interval [value, value] containing a single point.
+    pub fn point(value: Rational) -> Interval {
+        Interval { lower: value.clone(), upper: value }
+    }
+
+    #[inline]
+    pub fn lower(&self) -> &Rational {
+        &self.lower
+    }
+
+    #[inline]
+    pub fn upper(&self) -> &Rational {
+        &self.upper
+    }
+
+    /// The width upper - lower of the interval
+    pub fn width(&self) -> Rational {
+        self.upper.sub_from(&self.lower)
+    }
+
+    /// The midpoint (lower + upper) / 2 of the interval
+    pub fn midpoint(&self) -> Rational {
+        self.lower.add_to(&self.upper).div_by(&Rational::from(2))
+    }
+
+    #[inline]
+    pub fn is_point(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Check if value lies within the interval
+    pub fn contains(&self, value: &Rational) -> bool {
+        self.lower <= *value && *value <= self.upper
+    }
+
+    /// Check if other lies completely within the interval
+    pub fn encloses(&self, other: &Interval) -> bool {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    /// The intersection of two intervals or None if they are disjoint.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Interval, Rational};
+    /// let a = Interval::new(Rational::from(0), Rational::from(2));
+    /// let b = Interval::new(Rational::from(1), Rational::from(3));
+    /// assert_eq!(a.intersection(&b), Some(Interval::new(Rational::from(1), Rational::from(2))));
+    /// assert_eq!(a.intersection(&Interval::point(Rational::from(5))), None);
+    /// ```
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let lower = Ord::max(&self.lower, &other.lower);
+        let upper = Ord::min(&self.upper, &other.upper);
+        if lower <= upper {
+            Some(Interval { lower: lower.clone(), upper: upper.clone() })
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing both intervals
+    pub fn hull(&self, other: &Interval) -> Interval {
+        Interval {
+            lower: Ord::min(&self.lower, &other.lower).clone(),
+            upper: Ord::max(&self.upper, &other.upper).clone(),
+        }
+    }
+
+    pub fn add_to(&self, other: &Self) -> Interval {
+        Interval {
+            lower: self.lower.add_to(&other.lower),
+            upper: self.upper.add_to(&other.upper),
+        }
+    }
+
+    pub fn add_into(&mut self, other: &Self) {
+        *self = self.add_to(other);
+    }
+
+    pub fn sub_from(&self, other: &Self) -> Interval {
+        Interval {
+            lower: self.lower.sub_from(&other.upper),
+            upper: self.upper.sub_from(&other.lower),
+        }
+    }
+
+    pub fn sub_into(&mut self, other: &Self) {
+        *self = self.sub_from(other);
+    }
+
+    pub fn mul_by(&self, other: &Self) -> Interval {
+        Interval::from_candidates(vec![
+            self.lower.mul_by(&other.lower),
+            self.lower.mul_by(&other.upper),
+            self.upper.mul_by(&other.lower),
+            self.upper.mul_by(&other.upper),
+        ])
+    }
+
+    pub fn mul_into(&mut self, other: &Self) {
+        *self = self.mul_by(other);
+    }
+
+    /// Divide self by other.
+    ///
+    /// # Returns
+    /// The enclosure of the quotient or an error if other contains zero, in which case the
+    /// quotient is not bounded.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Interval, Rational};
+    /// let a = Interval::new(Rational::from(1), Rational::from(2));
+    /// let b = Interval::new(Rational::from(-4), Rational::from(-2));
+    /// assert_eq!(a.div_by(&b), Ok(Interval::new(Rational::from(-1), Rational::from((-1, 4)))));
+    /// assert!(b.div_by(&Interval::new(Rational::from(-1), Rational::from(1))).is_err());
+    /// ```
+    pub fn div_by(&self, other: &Self) -> Result<Interval, String> {
+        if other.contains(&Rational::new()) {
+            Err(format!("division by an interval containing zero: {}", other))
+        } else {
+            Ok(self.mul_by(&Interval {
+                lower: other.upper.invert(),
+                upper: other.lower.invert(),
+            }))
+        }
+    }
+
+    pub fn div_into(&mut self, other: &Self) -> Result<(), String> {
+        *self = self.div_by(other)?;
+        Ok(())
+    }
+
+    /// Raise the interval to the given power.
+    ///
+    /// Even powers of intervals containing zero have zero as lower bound, `x.powi(0)` is [1, 1].
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Interval, Rational};
+    /// let interval = Interval::new(Rational::from(-2), Rational::from(1));
+    /// assert_eq!(interval.powi(2), Interval::new(Rational::from(0), Rational::from(4)));
+    /// assert_eq!(interval.powi(3), Interval::new(Rational::from(-8), Rational::from(1)));
+    /// ```
+    pub fn powi(&self, power: u32) -> Interval {
+        let lower = self.lower.powi(power);
+        let upper = self.upper.powi(power);
+        if power & 1 == 1 {
+            Interval { lower, upper }
+        } else if power > 0 && self.contains(&Rational::new()) {
+            Interval { lower: Rational::new(), upper: Ord::max(lower, upper) }
+        } else {
+            Interval::from_candidates(vec![lower, upper])
+        }
+    }
+
+    /// Calculate an enclosure of the square root.
+    ///
+    /// The bounds are widened by tolerance, so the result encloses the exact square roots of all
+    /// values in the interval.
+    ///
+    /// # Arguments
+    /// * tolerance - the precision of the bounds, must be greater than zero
+    ///
+    /// # Returns
+    /// The enclosure or an error if the interval contains negative numbers or tolerance is not
+    /// positive
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Interval, Rational};
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let root = Interval::new(Rational::from(2), Rational::from(3)).sqrt(&tolerance).expect("sqrt failed");
+    /// assert!(root.contains(&Rational::from((14142136, 10000000))));
+    /// assert!(root.contains(&Rational::from((17320508, 10000000))));
+    /// ```
+    pub fn sqrt(&self, tolerance: &Rational) -> Result<Interval, String> {
+        if self.lower.is_negative() {
+            return Err(format!("square root of an interval with negative numbers: {}", self));
+        }
+        let lower = self.lower.sqrt_with_precision(tolerance)?.sub_from(tolerance);
+        let upper = self.upper.sqrt_with_precision(tolerance)?.add_to(tolerance);
+        Ok(Interval {
+            lower: if lower.is_negative() { Rational::new() } else { lower },
+            upper,
+        })
+    }
+
+    /// Build the smallest interval containing all candidates
+    fn from_candidates(candidates: Vec<Rational>) -> Interval {
+        let lower = candidates.iter().min().expect("Unexpected empty candidates").clone();
+        let upper = candidates.iter().max().expect("Unexpected empty candidates").clone();
+        Interval { lower, upper }
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Interval, Rational};
+
+fn interval(lower: (i32, i32), upper: (i32, i32)) -> Interval {
+    Interval::new(Rational::from(lower), Rational::from(upper))
+}
+
+#[test]
+fn test_interval_new() {
+    let int = interval((1, 2), (3, 2));
+    assert_eq!(int.lower(), &Rational::from((1, 2)));
+    assert_eq!(int.upper(), &Rational::from((3, 2)));
+    assert_eq!(int.width(), Rational::from(1));
+    assert_eq!(int.midpoint(), Rational::from(1));
+    assert!(!int.is_point());
+    assert!(Interval::from(Rational::from(3)).is_point());
+    assert_eq!(format!("{}", int), "[1/2, 3/2]");
+    assert_eq!(format!("{:.2}", int), "[0.50, 1.50]");
+}
+
+#[test]
+#[should_panic]
+fn test_interval_new_invalid() {
+    interval((2, 1), (1, 1));
+}
+
+#[test]
+fn test_interval_add_sub() {
+    let a = interval((1, 1), (2, 1));
+    let b = interval((-3, 1), (1, 2));
+    assert_eq!(&a + &b, interval((-2, 1), (5, 2)));
+    assert_eq!(&a - &b, interval((1, 2), (5, 1)));
+    assert_eq!(&b - &a, interval((-5, 1), (-1, 2)));
+
+    let mut c = a.clone();
+    c += &b;
+    c -= b;
+    // x - y is not the inverse of x + y in interval arithmetic
+    assert!(c.encloses(&a));
+    assert_eq!(c, interval((-5, 2), (11, 2)));
+}
+
+#[test]
+fn test_interval_mul() {
+    let a = interval((-1, 1), (2, 1));
+    let b = interval((-3, 1), (4, 1));
+    assert_eq!(&a * &b, interval((-6, 1), (8, 1)));
+    let c = interval((-3, 1), (-2, 1));
+    assert_eq!(&a * &c, interval((-6, 1), (3, 1)));
+    assert_eq!(&c * &c, interval((4, 1), (9, 1)));
+    let mut d = c.clone();
+    d *= interval((1, 2), (1, 2));
+    assert_eq!(d, interval((-3, 2), (-1, 1)));
+}
+
+#[test]
+fn test_interval_div() {
+    let a = interval((1, 1), (3, 1));
+    let b = interval((2, 1), (4, 1));
+    assert_eq!(a.div_by(&b), Ok(interval((1, 4), (3, 2))));
+    assert_eq!(&a / &interval((-4, 1), (-2, 1)), interval((-3, 2), (-1, 4)));
+    assert_eq!(interval((-1, 1), (1, 1)).div_by(&b), Ok(interval((-1, 2), (1, 2))));
+    assert!(a.div_by(&interval((-1, 1), (1, 1))).is_err());
+    assert!(a.div_by(&interval((0, 1), (1, 1))).is_err());
+    assert!(a.clone().div_into(&interval((-1, 1), (0, 1))).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_interval_div_zero() {
+    let _ = interval((1, 1), (3, 1)) / interval((-1, 1), (1, 1));
+}
+
+#[test]
+fn test_interval_powi() {
+    let a = interval((-3, 1), (2, 1));
+    assert_eq!(a.powi(0), interval((1, 1), (1, 1)));
+    assert_eq!(a.powi(1), a);
+    assert_eq!(a.powi(2), interval((0, 1), (9, 1)));
+    assert_eq!(a.powi(3), interval((-27, 1), (8, 1)));
+    assert_eq!(interval((-3, 1), (-2, 1)).powi(2), interval((4, 1), (9, 1)));
+    assert_eq!(interval((1, 2), (2, 1)).powi(2), interval((1, 4), (4, 1)));
+}
+
+#[test]
+fn test_interval_sqrt() {
+    let tolerance = Rational::from((1, 1000000000));
+    let root = interval((2, 1), (9, 1)).sqrt(&tolerance).expect("sqrt failed");
+    assert!(root.contains(&Rational::from(3)));
+    assert!(root.powi(2).encloses(&interval((2, 1), (9, 1))));
+    assert!(root.width() < Rational::from(2));
+
+    let root = interval((0, 1), (1, 4)).sqrt(&tolerance).expect("sqrt failed");
+    assert_eq!(root.lower(), &Rational::new());
+    assert!(root.contains(&Rational::from((1, 2))));
+
+    assert!(interval((-1, 1), (1, 1)).sqrt(&tolerance).is_err());
+    assert!(interval((1, 1), (2, 1)).sqrt(&Rational::new()).is_err());
+}
+
+#[test]
+fn test_interval_set_operations() {
+    let a = interval((0, 1), (2, 1));
+    let b = interval((1, 1), (3, 1));
+    assert!(a.contains(&Rational::new()));
+    assert!(a.contains(&Rational::from(2)));
+    assert!(!a.contains(&Rational::from((-1, 3))));
+    assert!(a.encloses(&interval((1, 2), (1, 1))));
+    assert!(!a.encloses(&b));
+    assert_eq!(a.intersection(&b), Some(interval((1, 1), (2, 1))));
+    assert_eq!(a.intersection(&interval((2, 1), (5, 1))), Some(interval((2, 1), (2, 1))));
+    assert_eq!(a.intersection(&interval((3, 1), (5, 1))), None);
+    assert_eq!(a.hull(&interval((3, 1), (5, 1))), interval((0, 1), (5, 1)));
+}
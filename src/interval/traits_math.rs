@@ -0,0 +1,141 @@
+use std::ops::{ AddAssign, Add, SubAssign, Sub, MulAssign, Mul, DivAssign, Div};
+
+use super::Interval;
+
+impl Add for Interval {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add_to(&other)
+    }
+}
+
+impl<'a> Add<&'a Self> for Interval {
+    type Output = Self;
+
+    fn add(self, other: &'a Self) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl<'a> Add<&'a Interval> for &Interval {
+    type Output = Interval;
+
+    fn add(self, other: &'a Interval) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl AddAssign for Interval {
+    fn add_assign(&mut self, other: Self) {
+        self.add_into(&other);
+    }
+}
+
+impl<'a> AddAssign<&'a Self> for Interval {
+    fn add_assign(&mut self, other: &'a Self) {
+        self.add_into(other);
+    }
+}
+
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_from(&other)
+    }
+}
+
+impl<'a> Sub<&'a Self> for Interval {
+    type Output = Self;
+    fn sub(self, other: &'a Self) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl<'a> Sub<&'a Interval> for &Interval {
+    type Output = Interval;
+    fn sub(self, other: &'a Interval) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl SubAssign for Interval {
+    fn sub_assign(&mut self, other: Self) {
+        self.sub_into(&other)
+    }
+}
+
+impl<'a> SubAssign<&'a Self> for Interval {
+    fn sub_assign(&mut self, other: &'a Self) {
+        self.sub_into(other)
+    }
+}
+
+impl Mul<Self> for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_by(&other)
+    }
+}
+
+impl<'a> Mul<&'a Self> for Interval {
+    type Output = Self;
+
+    fn mul(self, other: &'a Self) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl<'a> Mul<&'a Interval> for &Interval {
+    type Output = Interval;
+
+    fn mul(self, other: &'a Interval) -> Self::Output {
+        self.mul_by(other)
+    }
+}
+
+impl MulAssign for Interval {
+    fn mul_assign(&mut self, other: Self) {
+        self.mul_into(&other)
+    }
+}
+
+impl<'a> MulAssign<&'a Self> for Interval {
+    fn mul_assign(&mut self, other: &'a Self) {
+        self.mul_into(other)
+    }
+}
+
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, other: Self) -> Interval {
+        self.div_by(&other).expect("division by an interval containing zero")
+    }
+}
+
+impl<'a> Div<&'a Self> for Interval {
+    type Output = Self;
+    fn div(self, other: &'a Self) -> Interval {
+        self.div_by(other).expect("division by an interval containing zero")
+    }
+}
+
+impl<'a> Div<&'a Interval> for &Interval {
+    type Output = Interval;
+    fn div(self, other: &'a Interval) -> Interval {
+        self.div_by(other).expect("division by an interval containing zero")
+    }
+}
+
+impl DivAssign for Interval {
+    fn div_assign(&mut self, other: Self) {
+        self.div_into(&other).expect("division by an interval containing zero")
+    }
+}
+
+impl<'a> DivAssign<&'a Self> for Interval {
+    fn div_assign(&mut self, other: &'a Self) {
+        self.div_into(other).expect("division by an interval containing zero")
+    }
+}
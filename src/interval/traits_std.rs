@@ -0,0 +1,31 @@
+use crate::{Interval, Rational};
+use std::fmt::{Debug, Formatter, Display};
+
+impl From<Rational> for Interval {
+    fn from(value: Rational) -> Self {
+        Interval::point(value)
+    }
+}
+
+impl From<(Rational, Rational)> for Interval {
+    fn from(src: (Rational, Rational)) -> Self {
+        Interval::new(src.0, src.1)
+    }
+}
+
+impl Debug for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{:?}, {:?}]", self.lower, self.upper)
+    }
+}
+
+impl Display for Interval {
+    /// Display the Interval as `[lower, upper]`, a precision as in `{:.3}` is applied to both bounds
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(precision) = f.precision() {
+            write!(f, "[{:.*}, {:.*}]", precision, self.lower, precision, self.upper)
+        } else {
+            write!(f, "[{}, {}]", self.lower, self.upper)
+        }
+    }
+}
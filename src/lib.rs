@@ -9,6 +9,15 @@ pub mod big_uint;
 #[cfg(feature = "big_uint")]
 pub use big_uint::BigUInt;
 
+#[cfg(feature = "zeroize")]
+pub use big_uint::SecretBigUInt;
+
+#[cfg(feature = "big_uint")]
+pub mod rounding;
+
+#[cfg(feature = "big_uint")]
+pub use rounding::RoundingMode;
+
 #[cfg(feature = "big_int")]
 pub mod big_int;
 
@@ -21,6 +30,57 @@ pub mod rational;
 #[cfg(feature = "rational")]
 pub use rational::Rational;
 
+#[cfg(feature = "interval")]
+pub mod interval;
+
+#[cfg(feature = "interval")]
+pub use interval::Interval;
+
+#[cfg(feature = "big_float")]
+pub mod big_float;
+
+#[cfg(feature = "big_float")]
+pub use big_float::BigFloat;
+
+#[cfg(feature = "big_decimal")]
+pub mod big_decimal;
+
+#[cfg(feature = "big_decimal")]
+pub use big_decimal::BigDecimal;
+
+#[cfg(feature = "mod_int")]
+pub mod mod_int;
+
+#[cfg(feature = "mod_int")]
+pub use mod_int::{ModInt, Modulus};
+
+#[cfg(feature = "number_theory")]
+pub mod number_theory;
+
+#[cfg(feature = "dlog")]
+pub mod dlog;
+
+#[cfg(feature = "ec")]
+pub mod ec;
+
+#[cfg(feature = "rsa")]
+pub mod rsa;
+
+#[cfg(feature = "ct")]
+pub mod ct;
+
+#[cfg(feature = "ct")]
+pub use ct::{SecretModulus, SecretUInt};
+
+#[cfg(feature = "rand")]
+pub mod random;
+
+#[cfg(feature = "combinatorics")]
+pub mod combinatorics;
+
+#[cfg(feature = "sequences")]
+pub mod sequences;
+
 
 
 
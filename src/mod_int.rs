@@ -0,0 +1,331 @@
+use crate::BigUInt;
+
+#[cfg(test)]
+mod test;
+
+mod traits_std;
+
+mod traits_math;
+
+/// The reduction used for products modulo a Modulus
+#[derive(Clone, Debug, PartialEq)]
+enum Reduction {
+    /// Montgomery multiplication for odd moduli with R = 2^bits > m, values are kept in
+    /// Montgomery form x * R mod m
+    Montgomery {
+        bits: usize,
+        /// -m^-1 mod R
+        m_prime: BigUInt,
+        /// R^2 mod m, used to convert into Montgomery form
+        r_squared: BigUInt,
+    },
+    /// Barrett reduction for even moduli with mu = floor(2^(2 * bits) / m) and 2^(bits - 1) <= m < 2^bits
+    Barrett {
+        bits: usize,
+        mu: BigUInt,
+    },
+}
+
+/// A modulus with precomputed reduction constants, shared by all [ModInt] values modulo it.
+///
+/// Odd moduli use Montgomery multiplication, even moduli Barrett reduction, so products of
+/// residues are reduced with multiplications and shifts instead of a division.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Modulus {
+    modulus: BigUInt,
+    reduction: Reduction,
+}
+
+impl Modulus {
+    /// Create a modulus context, precomputing the reduction constants.
+    ///
+    /// # Panics
+    /// Panics if modulus is less than 2.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, Modulus};
+    /// let modulus = Modulus::new(BigUInt::from(97u32));
+    /// let a = modulus.element(&BigUInt::from(50u32));
+    /// let b = modulus.element(&BigUInt::from(60u32));
+    /// assert_eq!((&a + &b).to_biguint(), BigUInt::from(13u32));
+    /// assert_eq!((&a * &b).to_biguint(), BigUInt::from(90u32));
+    /// ```
+    pub fn new(modulus: BigUInt) -> Modulus {
+        assert!(modulus > BigUInt::from(1u32), "modulus must be at least 2");
+        let bits = modulus.length();
+        let reduction = if modulus.is_odd() {
+            // m^-1 mod 2^bits by Hensel lifting, every step doubles the number of correct bits
+            let mut inverse = BigUInt::from(1u32);
+            let mut precision = 1;
+            while precision < bits {
+                precision = usize::min(2 * precision, bits);
+                let product = modulus.low_bits(precision).mul_with(&inverse).low_bits(precision);
+                let factor = (BigUInt::from(1u32) << precision).add_to(&2u32.into()).sub_from(&product)
+                    .low_bits(precision);
+                inverse = inverse.mul_with(&factor).low_bits(precision);
+            }
+            Reduction::Montgomery {
+                bits,
+                m_prime: (BigUInt::from(1u32) << bits).sub_from(&inverse),
+                r_squared: (BigUInt::from(1u32) << (2 * bits)).div_mod(&modulus).1,
+            }
+        } else {
+            Reduction::Barrett {
+                bits,
+                mu: (BigUInt::from(1u32) << (2 * bits)).div_by(&modulus),
+            }
+        };
+        Modulus { modulus, reduction }
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &BigUInt {
+        &self.modulus
+    }
+
+    /// Check if values are kept in Montgomery form, which is the case for odd moduli
+    #[inline]
+    pub fn is_montgomery(&self) -> bool {
+        matches!(self.reduction, Reduction::Montgomery { .. })
+    }
+
+    /// Create the element value mod m, value may be of any size
+    pub fn element(&self, value: &BigUInt) -> ModInt<'_> {
+        ModInt::new(value, self)
+    }
+
+    pub fn zero(&self) -> ModInt<'_> {
+        ModInt {
+            value: BigUInt::new(),
+            modulus: self,
+        }
+    }
+
+    pub fn one(&self) -> ModInt<'_> {
+        self.element(&BigUInt::from(1u32))
+    }
+
+    /// Convert a residue 0 <= residue < m to the internal representation
+    fn to_internal(&self, residue: BigUInt) -> BigUInt {
+        match &self.reduction {
+            Reduction::Montgomery { r_squared, .. } => self.redc(residue.mul_with(r_squared)),
+            Reduction::Barrett { .. } => residue,
+        }
+    }
+
+    /// Convert the internal representation back to the residue
+    fn to_residue(&self, value: &BigUInt) -> BigUInt {
+        match &self.reduction {
+            Reduction::Montgomery { .. } => self.redc(value.clone()),
+            Reduction::Barrett { .. } => value.clone(),
+        }
+    }
+
+    /// Multiply two values in internal representation
+    fn mul_internal(&self, lhs: &BigUInt, rhs: &BigUInt) -> BigUInt {
+        let product = lhs.mul_with(rhs);
+        match &self.reduction {
+            Reduction::Montgomery { .. } => self.redc(product),
+            Reduction::Barrett { bits, mu } => {
+                // for x < m^2 the estimate q is at most 2 below floor(x / m)
+                let quotient = product.shift_right(bits - 1).mul_with(mu).shift_right(bits + 1);
+                let mut res = product.sub_from(&quotient.mul_with(&self.modulus));
+                while res >= self.modulus {
+                    res.sub_into(&self.modulus);
+                }
+                res
+            }
+        }
+    }
+
+    /// Montgomery reduction t * R^-1 mod m for t < m * R
+    fn redc(&self, value: BigUInt) -> BigUInt {
+        if let Reduction::Montgomery { bits, m_prime, .. } = &self.reduction {
+            let factor = value.low_bits(*bits).mul_with(m_prime).low_bits(*bits);
+            let mut res = value.add_to(&factor.mul_with(&self.modulus)).shift_right(*bits);
+            if res >= self.modulus {
+                res.sub_into(&self.modulus);
+            }
+            res
+        } else {
+            unreachable!("Montgomery reduction with a Barrett modulus")
+        }
+    }
+}
+
+/// An integer modulo a [Modulus].
+///
+/// All arithmetic keeps the value reduced. Combining values of different moduli panics.
+#[derive(Clone)]
+pub struct ModInt<'a> {
+    /// the value in the internal representation of the modulus
+    value: BigUInt,
+    modulus: &'a Modulus,
+}
+
+impl<'a> ModInt<'a> {
+    /// Create the element value mod m, value may be of any size
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, ModInt, Modulus};
+    /// let modulus = Modulus::new(BigUInt::from(10u32));
+    /// let value = ModInt::new(&BigUInt::from(1234u32), &modulus);
+    /// assert_eq!(value.to_biguint(), BigUInt::from(4u32));
+    /// ```
+    pub fn new(value: &BigUInt, modulus: &'a Modulus) -> ModInt<'a> {
+        let residue = if *value >= modulus.modulus {
+            value.div_mod(&modulus.modulus).1
+        } else {
+            value.clone()
+        };
+        ModInt {
+            value: modulus.to_internal(residue),
+            modulus,
+        }
+    }
+
+    /// The residue in the range 0..m
+    pub fn to_biguint(&self) -> BigUInt {
+        self.modulus.to_residue(&self.value)
+    }
+
+    #[inline]
+    pub fn modulus(&self) -> &'a Modulus {
+        self.modulus
+    }
+
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    pub fn add_to(&self, other: &Self) -> ModInt<'a> {
+        self.check_modulus(other);
+        let mut value = self.value.add_to(&other.value);
+        if value >= self.modulus.modulus {
+            value.sub_into(&self.modulus.modulus);
+        }
+        ModInt { value, modulus: self.modulus }
+    }
+
+    pub fn add_into(&mut self, other: &Self) {
+        *self = self.add_to(other);
+    }
+
+    pub fn sub_from(&self, other: &Self) -> ModInt<'a> {
+        self.check_modulus(other);
+        let value = if self.value >= other.value {
+            self.value.sub_from(&other.value)
+        } else {
+            self.value.add_to(&self.modulus.modulus).sub_from(&other.value)
+        };
+        ModInt { value, modulus: self.modulus }
+    }
+
+    pub fn sub_into(&mut self, other: &Self) {
+        *self = self.sub_from(other);
+    }
+
+    pub fn mul_with(&self, other: &Self) -> ModInt<'a> {
+        self.check_modulus(other);
+        ModInt {
+            value: self.modulus.mul_internal(&self.value, &other.value),
+            modulus: self.modulus,
+        }
+    }
+
+    pub fn mul_into(&mut self, other: &Self) {
+        *self = self.mul_with(other);
+    }
+
+    /// Divide self by other, which is multiplying with the inverse of other.
+    ///
+    /// # Returns
+    /// The quotient or an error if other has no inverse
+    pub fn div_by(&self, other: &Self) -> Result<ModInt<'a>, String> {
+        Ok(self.mul_with(&other.inverse()?))
+    }
+
+    pub fn div_into(&mut self, other: &Self) -> Result<(), String> {
+        *self = self.div_by(other)?;
+        Ok(())
+    }
+
+    /// The additive inverse m - self
+    pub fn negate(&self) -> ModInt<'a> {
+        self.modulus.zero().sub_from(self)
+    }
+
+    /// Find the multiplicative inverse.
+    ///
+    /// # Returns
+    /// The inverse or an error if self and the modulus are not coprime
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, Modulus};
+    /// let modulus = Modulus::new(BigUInt::from(26u32));
+    /// assert_eq!(modulus.element(&BigUInt::from(7u32)).inverse().map(|x| x.to_biguint()), Ok(BigUInt::from(15u32)));
+    /// assert!(modulus.element(&BigUInt::from(13u32)).inverse().is_err());
+    /// ```
+    pub fn inverse(&self) -> Result<ModInt<'a>, String> {
+        let residue = self.to_biguint();
+        match residue.mod_inverse(&self.modulus.modulus) {
+            Some(inverse) => Ok(ModInt {
+                value: self.modulus.to_internal(inverse),
+                modulus: self.modulus,
+            }),
+            None => Err(format!("{} has no inverse modulo {}", residue, self.modulus.modulus)),
+        }
+    }
+
+    /// Raise self to the given power by square and multiply.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, Modulus};
+    /// let modulus = Modulus::new(BigUInt::from(497u32));
+    /// let base = modulus.element(&BigUInt::from(4u32));
+    /// assert_eq!(base.pow(&BigUInt::from(13u32)).to_biguint(), BigUInt::from(445u32));
+    /// ```
+    pub fn pow(&self, exponent: &BigUInt) -> ModInt<'a> {
+        let mut res = self.modulus.one();
+        for bit in exponent.iter() {
+            res.value = self.modulus.mul_internal(&res.value, &res.value);
+            if bit {
+                res.value = self.modulus.mul_internal(&res.value, &self.value);
+            }
+        }
+        res
+    }
+
+    fn check_modulus(&self, other: &Self) {
+        assert!(std::ptr::eq(self.modulus, other.modulus) || self.modulus.modulus == other.modulus.modulus,
+                "values of different moduli can not be combined");
+    }
+}
+
+impl BigUInt {
+    /// Calculate self^exponent mod modulus.
+    ///
+    /// For repeated calculations with the same modulus create a [Modulus] once and use
+    /// [ModInt::pow] instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// let res = BigUInt::from(4u32).modpow(&BigUInt::from(13u32), &BigUInt::from(497u32));
+    /// assert_eq!(res, BigUInt::from(445u32));
+    /// ```
+    pub fn modpow(&self, exponent: &BigUInt, modulus: &BigUInt) -> BigUInt {
+        assert!(!modulus.is_zero(), "Division by zero");
+        if *modulus == BigUInt::from(1u32) {
+            BigUInt::new()
+        } else {
+            let modulus = Modulus::new(modulus.clone());
+            modulus.element(self).pow(exponent).to_biguint()
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use rand::Rng;
+use crate::{BigUInt, ModInt, Modulus};
+
+fn num(src: &str) -> BigUInt {
+    BigUInt::from_dec_str(src).expect("invalid decimal string")
+}
+
+fn random_biguint(rng: &mut impl Rng, words: usize) -> BigUInt {
+    (0..words).fold(BigUInt::new(), |acc, _| (acc << 64).add_to(&rng.gen::<u64>().into()))
+}
+
+#[test]
+fn test_ops_against_div_mod() {
+    let mut rng = rand::thread_rng();
+    for words in [1, 2, 3, 5] {
+        for odd in [true, false] {
+            let mut m = random_biguint(&mut rng, words).add_to(&2u32.into());
+            if m.is_odd() != odd {
+                m.add_into(&1u32.into());
+            }
+            let modulus = Modulus::new(m.clone());
+            assert_eq!(modulus.is_montgomery(), odd);
+            for _ in 0..20 {
+                let a = random_biguint(&mut rng, words + 1);
+                let b = random_biguint(&mut rng, words);
+                let x = modulus.element(&a);
+                let y = ModInt::new(&b, &modulus);
+                let a = a.div_mod(&m).1;
+                let b = b.div_mod(&m).1;
+                assert_eq!(x.to_biguint(), a);
+                assert_eq!((&x + &y).to_biguint(), a.add_to(&b).div_mod(&m).1);
+                assert_eq!((&x - &y).to_biguint(), a.add_to(&m).sub_from(&b).div_mod(&m).1);
+                assert_eq!((&x * &y).to_biguint(), a.mul_with(&b).div_mod(&m).1);
+                assert_eq!((&x + &(-&x)).to_biguint(), BigUInt::new());
+                if let Ok(quotient) = x.div_by(&y) {
+                    assert_eq!(quotient * &y, x);
+                } else {
+                    assert_ne!(b.gcd(&m), 1u32.into());
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_pow() {
+    // Fermat's little theorem with the Mersenne prime 2^127 - 1
+    let p = (BigUInt::from(1u32) << 127).sub_from(&1u32.into());
+    let modulus = Modulus::new(p.clone());
+    let exponent = p.sub_from(&1u32.into());
+    for base in [2u32, 3, 12345, 0xFFFFFFFF] {
+        assert_eq!(modulus.element(&base.into()).pow(&exponent), modulus.one());
+    }
+    assert_eq!(modulus.zero().pow(&BigUInt::new()), modulus.one());
+
+    let p = (BigUInt::from(1u32) << 255).sub_from(&19u32.into());
+    let exponent = num("10000000000000000000000000000000000000007");
+    assert_eq!(BigUInt::from(3u32).modpow(&exponent, &p),
+               num("11306134312905107978177136053487146481167995017258987163935575453434518412241"));
+    let base = BigUInt::from_hex_str("1234567890abcdef1234567890abcdef").expect("invalid hex string");
+    assert_eq!(base.modpow(&65537u32.into(), &(BigUInt::from(1u32) << 200)),
+               num("1146718579443007199012934157721634888177976242285205970996719"));
+    assert_eq!(BigUInt::from(5u32).modpow(&3u32.into(), &1u32.into()), BigUInt::new());
+}
+
+#[test]
+fn test_inverse() {
+    let p = (BigUInt::from(1u32) << 255).sub_from(&19u32.into());
+    let modulus = Modulus::new(p.clone());
+    let value = modulus.element(&num("12345678901234567890"));
+    let inverse = value.inverse().expect("no inverse");
+    assert_eq!(inverse.to_biguint(),
+               num("55335905831616906258934597032528017175332098981730720091099231045619009907308"));
+    assert_eq!(num("12345678901234567890").mod_inverse(&p), Some(inverse.to_biguint()));
+    assert_eq!(&value * &inverse, modulus.one());
+    assert!(modulus.zero().inverse().is_err());
+
+    let modulus = Modulus::new(BigUInt::from(1u32) << 64);
+    assert!(modulus.element(&6u32.into()).inverse().is_err());
+    let value = modulus.element(&12345u32.into());
+    assert_eq!(&value / &value, modulus.one());
+}
+
+#[test]
+#[should_panic]
+fn test_mixed_moduli() {
+    let a = Modulus::new(7u32.into());
+    let b = Modulus::new(11u32.into());
+    let _ = a.one() + b.one();
+}
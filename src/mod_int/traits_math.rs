@@ -0,0 +1,164 @@
+use std::ops::{AddAssign, Add, SubAssign, Sub, MulAssign, Mul, DivAssign, Div, Neg};
+
+use super::ModInt;
+
+impl<'a> Add for ModInt<'a> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.add_to(&other)
+    }
+}
+
+impl<'a, 'b> Add<&'b Self> for ModInt<'a> {
+    type Output = Self;
+
+    fn add(self, other: &'b Self) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl<'a, 'b> Add<&'b ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn add(self, other: &'b ModInt<'a>) -> Self::Output {
+        self.add_to(other)
+    }
+}
+
+impl<'a> AddAssign for ModInt<'a> {
+    fn add_assign(&mut self, other: Self) {
+        self.add_into(&other);
+    }
+}
+
+impl<'a, 'b> AddAssign<&'b Self> for ModInt<'a> {
+    fn add_assign(&mut self, other: &'b Self) {
+        self.add_into(other);
+    }
+}
+
+impl<'a> Sub for ModInt<'a> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.sub_from(&other)
+    }
+}
+
+impl<'a, 'b> Sub<&'b Self> for ModInt<'a> {
+    type Output = Self;
+
+    fn sub(self, other: &'b Self) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl<'a, 'b> Sub<&'b ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn sub(self, other: &'b ModInt<'a>) -> Self::Output {
+        self.sub_from(other)
+    }
+}
+
+impl<'a> SubAssign for ModInt<'a> {
+    fn sub_assign(&mut self, other: Self) {
+        self.sub_into(&other);
+    }
+}
+
+impl<'a, 'b> SubAssign<&'b Self> for ModInt<'a> {
+    fn sub_assign(&mut self, other: &'b Self) {
+        self.sub_into(other);
+    }
+}
+
+impl<'a> Mul for ModInt<'a> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.mul_with(&other)
+    }
+}
+
+impl<'a, 'b> Mul<&'b Self> for ModInt<'a> {
+    type Output = Self;
+
+    fn mul(self, other: &'b Self) -> Self::Output {
+        self.mul_with(other)
+    }
+}
+
+impl<'a, 'b> Mul<&'b ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn mul(self, other: &'b ModInt<'a>) -> Self::Output {
+        self.mul_with(other)
+    }
+}
+
+impl<'a> MulAssign for ModInt<'a> {
+    fn mul_assign(&mut self, other: Self) {
+        self.mul_into(&other);
+    }
+}
+
+impl<'a, 'b> MulAssign<&'b Self> for ModInt<'a> {
+    fn mul_assign(&mut self, other: &'b Self) {
+        self.mul_into(other);
+    }
+}
+
+// Division panics if the divisor has no inverse, use ModInt::div_by to handle that case
+impl<'a> Div for ModInt<'a> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self.div_by(&other).expect("Divisor has no inverse")
+    }
+}
+
+impl<'a, 'b> Div<&'b Self> for ModInt<'a> {
+    type Output = Self;
+
+    fn div(self, other: &'b Self) -> Self::Output {
+        self.div_by(other).expect("Divisor has no inverse")
+    }
+}
+
+impl<'a, 'b> Div<&'b ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn div(self, other: &'b ModInt<'a>) -> Self::Output {
+        self.div_by(other).expect("Divisor has no inverse")
+    }
+}
+
+impl<'a> DivAssign for ModInt<'a> {
+    fn div_assign(&mut self, other: Self) {
+        self.div_into(&other).expect("Divisor has no inverse");
+    }
+}
+
+impl<'a, 'b> DivAssign<&'b Self> for ModInt<'a> {
+    fn div_assign(&mut self, other: &'b Self) {
+        self.div_into(other).expect("Divisor has no inverse");
+    }
+}
+
+impl<'a> Neg for ModInt<'a> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl<'a> Neg for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn neg(self) -> Self::Output {
+        self.negate()
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{BigUInt, ModInt};
+use std::fmt::{Debug, Formatter, Display};
+use std::hash::{Hash, Hasher};
+
+impl PartialEq for ModInt<'_> {
+    /// ModInts are equal if they have the same modulus and residue
+    fn eq(&self, other: &Self) -> bool {
+        self.modulus.modulus == other.modulus.modulus && self.value == other.value
+    }
+}
+
+impl Eq for ModInt<'_> {}
+
+impl Hash for ModInt<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl From<ModInt<'_>> for BigUInt {
+    fn from(value: ModInt<'_>) -> Self {
+        value.to_biguint()
+    }
+}
+
+impl From<&ModInt<'_>> for BigUInt {
+    fn from(value: &ModInt<'_>) -> Self {
+        value.to_biguint()
+    }
+}
+
+impl Debug for ModInt<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} (mod {:?})", self.to_biguint(), self.modulus.modulus)
+    }
+}
+
+impl Display for ModInt<'_> {
+    /// Display the residue in the range 0..m
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_biguint())
+    }
+}
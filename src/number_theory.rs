@@ -0,0 +1,432 @@
+//! Number theoretic functions on BigUInt and BigInt values.
+
+use crate::{BigInt, BigUInt, ModInt, Modulus};
+
+#[cfg(test)]
+mod test;
+mod factor;
+mod arithmetic;
+mod diophantine;
+
+pub use factor::*;
+pub use arithmetic::*;
+pub use diophantine::*;
+
+/// Solve a system of congruences x = residue (mod modulus) with the Chinese Remainder Theorem.
+///
+/// The moduli do not need to be coprime. Residues may be larger than their modulus.
+///
+/// # Returns
+/// The solution x as the smallest non-negative value and the modulus of the solution, which is
+/// the least common multiple of all moduli, or None if the congruences are inconsistent.
+/// An empty system has the solution (0, 1).
+///
+/// # Panics
+/// Panics if a modulus is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::crt;
+/// let congruences = [(2u32, 3u32), (3, 5), (2, 7)].map(|(r, m)| (BigUInt::from(r), BigUInt::from(m)));
+/// assert_eq!(crt(&congruences), Some((BigUInt::from(23u32), BigUInt::from(105u32))));
+/// let inconsistent = [(1u32, 4u32), (2, 6)].map(|(r, m)| (BigUInt::from(r), BigUInt::from(m)));
+/// assert_eq!(crt(&inconsistent), None);
+/// ```
+pub fn crt(congruences: &[(BigUInt, BigUInt)]) -> Option<(BigUInt, BigUInt)> {
+    let mut solution = BigUInt::new();
+    let mut modulus = BigUInt::from(1u32);
+    for (residue, other) in congruences {
+        assert!(!other.is_zero(), "Division by zero");
+        let residue = residue.div_mod(other).1;
+        // solution + modulus * t = residue (mod other) is solvable if gcd divides the difference
+        let gcd = modulus.gcd(other);
+        let current = solution.div_mod(other).1;
+        let difference = if residue >= current {
+            residue.sub_from(&current)
+        } else {
+            residue.add_to(other).sub_from(&current)
+        };
+        let (difference, remainder) = difference.div_mod(&gcd);
+        if !remainder.is_zero() {
+            return None;
+        }
+        let reduced = other.div_by(&gcd);
+        let t = if reduced == BigUInt::from(1u32) {
+            BigUInt::new()
+        } else {
+            let inverse = modulus.div_by(&gcd).mod_inverse(&reduced)
+                .expect("Unexpected missing inverse of coprime values");
+            difference.mul_with(&inverse).div_mod(&reduced).1
+        };
+        solution.add_into(&modulus.mul_with(&t));
+        modulus.mul_into(&reduced);
+    }
+    Some((solution, modulus))
+}
+
+/// Calculate the Jacobi symbol (a/n) for an odd positive n.
+///
+/// # Returns
+/// 0 if a and n are not coprime, otherwise 1 or -1
+///
+/// # Panics
+/// Panics if n is even.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::{BigInt, BigUInt};
+/// use simple_big_int::number_theory::jacobi;
+/// assert_eq!(jacobi(&BigInt::from(1001), &BigUInt::from(9907u32)), -1);
+/// assert_eq!(jacobi(&BigInt::from(-2), &BigUInt::from(15u32)), -1);
+/// assert_eq!(jacobi(&BigInt::from(6), &BigUInt::from(15u32)), 0);
+/// ```
+pub fn jacobi(a: &BigInt, n: &BigUInt) -> i32 {
+    assert!(n.is_odd(), "the Jacobi symbol is only defined for odd positive n");
+    let mut a = residue(a, n);
+    let mut n = n.clone();
+    let mut res = 1;
+    while !a.is_zero() {
+        let zeros = a.trailing_zeros() as usize;
+        a >>= zeros;
+        // (2/n) = -1 for n = 3, 5 (mod 8)
+        let n_mod_8 = low_bits_u32(&n, 3);
+        if zeros & 1 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+            res = -res;
+        }
+        // quadratic reciprocity, the sign changes if both are 3 (mod 4)
+        if n_mod_8 & 3 == 3 && low_bits_u32(&a, 2) == 3 {
+            res = -res;
+        }
+        let next = n.div_mod(&a).1;
+        n = std::mem::replace(&mut a, next);
+    }
+    if n == BigUInt::from(1u32) {
+        res
+    } else {
+        0
+    }
+}
+
+/// Calculate the Legendre symbol (a/p) for an odd prime p.
+///
+/// This is the Jacobi symbol, the primality of p is not checked.
+///
+/// # Returns
+/// 0 if p divides a, 1 if a is a quadratic residue modulo p and -1 otherwise
+///
+/// # Examples
+/// ```
+/// use simple_big_int::{BigInt, BigUInt};
+/// use simple_big_int::number_theory::legendre;
+/// assert_eq!(legendre(&BigInt::from(2), &BigUInt::from(7u32)), 1);
+/// assert_eq!(legendre(&BigInt::from(3), &BigUInt::from(7u32)), -1);
+/// ```
+pub fn legendre(a: &BigInt, p: &BigUInt) -> i32 {
+    jacobi(a, p)
+}
+
+/// Calculate the Kronecker symbol (a/n), the extension of the Jacobi symbol to all integers n.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigInt;
+/// use simple_big_int::number_theory::kronecker;
+/// assert_eq!(kronecker(&BigInt::from(5), &BigInt::from(6)), 1);
+/// assert_eq!(kronecker(&BigInt::from(-5), &BigInt::from(-6)), 1);
+/// assert_eq!(kronecker(&BigInt::from(-5), &BigInt::from(6)), -1);
+/// assert_eq!(kronecker(&BigInt::from(3), &BigInt::from(0)), 0);
+/// ```
+pub fn kronecker(a: &BigInt, n: &BigInt) -> i32 {
+    if n.is_zero() {
+        return if *a.magnitude() == BigUInt::from(1u32) { 1 } else { 0 };
+    }
+    // (a/-1) is -1 for negative a
+    let mut res = if n.is_negative() && a.is_negative() { -1 } else { 1 };
+    let mut odd = n.magnitude().clone();
+    let zeros = odd.trailing_zeros() as usize;
+    if zeros > 0 {
+        let a_mod_8 = low_bits_u32(&residue(a, &BigUInt::from(8u32)), 3);
+        if a_mod_8 & 1 == 0 {
+            return 0;
+        }
+        // (a/2) = -1 for a = 3, 5 (mod 8)
+        if zeros & 1 == 1 && (a_mod_8 == 3 || a_mod_8 == 5) {
+            res = -res;
+        }
+        odd >>= zeros;
+    }
+    res * jacobi(a, &odd)
+}
+
+/// Find a square root of a modulo an odd prime p or 2.
+///
+/// Uses the Tonelli–Shanks algorithm or, when p - 1 is divisible by a large power of two,
+/// Cipolla's algorithm. The primality of p is not checked, for composite p the result is
+/// None or a valid square root.
+///
+/// # Returns
+/// The smaller of both square roots or None if a is not a quadratic residue
+///
+/// # Panics
+/// Panics if p is less than 2.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::sqrt_mod_prime;
+/// let p = BigUInt::from(113u32);
+/// assert_eq!(sqrt_mod_prime(&BigUInt::from(2u32), &p), Some(BigUInt::from(51u32)));
+/// assert_eq!(sqrt_mod_prime(&BigUInt::from(3u32), &p), None);
+/// ```
+pub fn sqrt_mod_prime(a: &BigUInt, p: &BigUInt) -> Option<BigUInt> {
+    assert!(*p > BigUInt::from(1u32), "modulus must be at least 2");
+    let a = a.div_mod(p).1;
+    if a.is_zero() || *p == BigUInt::from(2u32) {
+        return Some(a);
+    }
+    if p.is_even() || jacobi(&BigInt::from(a.clone()), p) != 1 {
+        return None;
+    }
+    let modulus = Modulus::new(p.clone());
+    let p_minus_1 = p.sub_from(&1u32.into());
+    let s = p_minus_1.trailing_zeros() as usize;
+    let root = if s == 1 {
+        // p = 3 (mod 4), a^((p + 1) / 4) is a root
+        modulus.element(&a).pow(&p.shift_right(2).add_to(&1u32.into()))
+    } else if s * (s - 1) > 8 * p.length() + 20 {
+        cipolla(&modulus, &a)?
+    } else {
+        tonelli_shanks(&modulus, &a, s)?
+    };
+    if root.mul_with(&root) != modulus.element(&a) {
+        return None;
+    }
+    let root = root.to_biguint();
+    let other = p.sub_from(&root);
+    Some(BigUInt::min(root, other))
+}
+
+/// Find a square root of a modulo p^k for a prime p by Hensel lifting a root modulo p.
+///
+/// # Returns
+/// One of the square roots or None if a is not a quadratic residue modulo p^k
+///
+/// # Panics
+/// Panics if p is less than 2 or k is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::sqrt_mod_prime_power;
+/// let p = BigUInt::from(7u32);
+/// let root = sqrt_mod_prime_power(&BigUInt::from(2u32), &p, 4).unwrap();
+/// assert_eq!(root.mul_with(&root).div_mod(&BigUInt::from(2401u32)).1, BigUInt::from(2u32));
+/// assert_eq!(sqrt_mod_prime_power(&BigUInt::from(17u32), &BigUInt::from(2u32), 10),
+///            Some(BigUInt::from(233u32)));
+/// ```
+pub fn sqrt_mod_prime_power(a: &BigUInt, p: &BigUInt, k: u32) -> Option<BigUInt> {
+    assert!(*p > BigUInt::from(1u32), "modulus must be at least 2");
+    assert!(k > 0, "exponent must be at least 1");
+    let a = a.div_mod(&p.powi(k)).1;
+    if a.is_zero() {
+        return Some(a);
+    }
+    // a = p^v * b with b coprime to p, a root is p^(v/2) * sqrt(b) (mod p^(k - v)) for even v
+    let mut b = a;
+    let mut v = 0;
+    loop {
+        let (quotient, remainder) = b.div_mod(p);
+        if !remainder.is_zero() {
+            break;
+        }
+        b = quotient;
+        v += 1;
+    }
+    if v & 1 == 1 {
+        return None;
+    }
+    let root = if *p == BigUInt::from(2u32) {
+        sqrt_unit_mod_power_of_two(&b, k - v)?
+    } else {
+        sqrt_unit_mod_prime_power(&b, p, k - v)?
+    };
+    Some(root.mul_with(&p.powi(v / 2)))
+}
+
+/// Lift a square root of a unit b modulo p to p^k for an odd prime p
+fn sqrt_unit_mod_prime_power(b: &BigUInt, p: &BigUInt, k: u32) -> Option<BigUInt> {
+    let mut root = sqrt_mod_prime(b, p)?;
+    let mut precision = 1;
+    while precision < k {
+        // Newton step r - (r^2 - b) / 2r doubles the precision
+        precision = u32::min(2 * precision, k);
+        let power = p.powi(precision);
+        let square = root.mul_with(&root).div_mod(&power).1;
+        let b = b.div_mod(&power).1;
+        let error = square.add_to(&power).sub_from(&b);
+        let inverse = root.add_to(&root).mod_inverse(&power).expect("Unexpected missing inverse of a unit");
+        let correction = error.mul_with(&inverse).div_mod(&power).1;
+        root = root.add_to(&power).sub_from(&correction).div_mod(&power).1;
+    }
+    Some(root)
+}
+
+/// Find a square root of an odd b modulo 2^k
+fn sqrt_unit_mod_power_of_two(b: &BigUInt, k: u32) -> Option<BigUInt> {
+    let k = k as usize;
+    let required = usize::min(k, 3);
+    // odd squares are 1 (mod 8)
+    if low_bits_u32(b, required) != low_bits_u32(&BigUInt::from(1u32), required) {
+        return None;
+    }
+    let mut root = BigUInt::from(1u32);
+    for precision in 3..k {
+        // root^2 = b (mod 2^precision), adding 2^(precision - 1) changes bit precision of the square
+        if root.mul_with(&root).low_bits(precision + 1) != b.low_bits(precision + 1) {
+            root.add_into(&(BigUInt::from(1u32) << (precision - 1)));
+        }
+    }
+    Some(root)
+}
+
+/// Tonelli–Shanks for p - 1 = q * 2^s and a quadratic residue a
+fn tonelli_shanks<'a>(modulus: &'a Modulus, a: &BigUInt, s: usize) -> Option<ModInt<'a>> {
+    let q = modulus.modulus().shift_right(s);
+    // find a quadratic non residue, a perfect square p would never yield one
+    let mut z = BigUInt::from(2u32);
+    while jacobi(&BigInt::from(z.clone()), modulus.modulus()) != -1 {
+        z.add_into(&1u32.into());
+        if z >= *modulus.modulus() {
+            return None;
+        }
+    }
+    let one = modulus.one();
+    let mut m = s;
+    let mut c = modulus.element(&z).pow(&q);
+    let mut t = modulus.element(a).pow(&q);
+    let mut root = modulus.element(a).pow(&q.shift_right(1).add_to(&1u32.into()));
+    while t != one {
+        // find the least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut square = t.clone();
+        while square != one {
+            square.mul_into(&square.clone());
+            i += 1;
+            if i == m {
+                return None;
+            }
+        }
+        let mut b = c;
+        for _ in 0..m - i - 1 {
+            b.mul_into(&b.clone());
+        }
+        m = i;
+        c = b.mul_with(&b);
+        t.mul_into(&c);
+        root.mul_into(&b);
+    }
+    Some(root)
+}
+
+/// Cipolla's algorithm, calculating (w + sqrt(w^2 - a))^((p + 1) / 2) in the field F_p^2
+fn cipolla<'a>(modulus: &'a Modulus, a: &BigUInt) -> Option<ModInt<'a>> {
+    let a = modulus.element(a);
+    let mut w = modulus.one();
+    let mut d = w.mul_with(&w).sub_from(&a);
+    while jacobi(&BigInt::from(d.to_biguint()), modulus.modulus()) != -1 {
+        w.add_into(&modulus.one());
+        if w.is_zero() {
+            return None;
+        }
+        d = w.mul_with(&w).sub_from(&a);
+    }
+    let mul = |(x1, y1): (&ModInt<'a>, &ModInt<'a>), (x2, y2): (&ModInt<'a>, &ModInt<'a>)| {
+        (x1.mul_with(x2).add_to(&y1.mul_with(y2).mul_with(&d)), x1.mul_with(y2).add_to(&x2.mul_with(y1)))
+    };
+    let exponent = modulus.modulus().shift_right(1).add_to(&1u32.into());
+    let mut res = (modulus.one(), modulus.zero());
+    for bit in exponent.iter() {
+        res = mul((&res.0, &res.1), (&res.0, &res.1));
+        if bit {
+            res = mul((&res.0, &res.1), (&w, &modulus.one()));
+        }
+    }
+    Some(res.0)
+}
+
+/// Check if n is probably prime with trial division and the Miller–Rabin test.
+///
+/// The test is deterministic, the first `rounds` primes are used as witnesses, at most the 168
+/// primes below 1000. It is proven correct for n below 3.18 * 10^23 with 12 or more rounds and
+/// below 3.3 * 10^24 with 13 or more rounds. Beyond that, composites that are strong
+/// pseudoprimes to all the fixed bases can be constructed and are reported as prime.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::is_probable_prime;
+/// let mersenne = (BigUInt::from(1u32) << 127).sub_from(&1u32.into());
+/// assert!(is_probable_prime(&mersenne, 20));
+/// assert!(!is_probable_prime(&mersenne.add_to(&2u32.into()), 20));
+/// // 3825123056546413051 is a strong pseudoprime to the first 11 prime bases
+/// assert!(is_probable_prime(&BigUInt::from(3825123056546413051u64), 11));
+/// assert!(!is_probable_prime(&BigUInt::from(3825123056546413051u64), 12));
+/// ```
+pub fn is_probable_prime(n: &BigUInt, rounds: usize) -> bool {
+    let primes = small_primes(1000);
+    if let Some(small) = n.to_u64().filter(|n| *n < 1000) {
+        return primes.contains(&small);
+    }
+    if primes.iter().any(|p| n.div_mod(&(*p).into()).1.is_zero()) {
+        return false;
+    }
+    // n - 1 = d * 2^s
+    let n_minus_1 = n.sub_from(&1u32.into());
+    let s = n_minus_1.trailing_zeros() as usize;
+    let d = n_minus_1.shift_right(s);
+    let modulus = Modulus::new(n.clone());
+    let one = modulus.one();
+    let minus_one = one.negate();
+    primes.iter().take(rounds).all(|base| {
+        let mut x = modulus.element(&(*base).into()).pow(&d);
+        if x == one || x == minus_one {
+            return true;
+        }
+        for _ in 1..s {
+            x.mul_into(&x.clone());
+            if x == minus_one {
+                return true;
+            }
+        }
+        false
+    })
+}
+
+/// The primes below limit by the sieve of Eratosthenes
+pub(crate) fn small_primes(limit: u64) -> Vec<u64> {
+    let mut composite = vec![false; limit as usize];
+    let mut primes = vec![];
+    for n in 2..limit {
+        if !composite[n as usize] {
+            primes.push(n);
+            (n * n..limit).step_by(n as usize).for_each(|multiple| composite[multiple as usize] = true);
+        }
+    }
+    primes
+}
+
+/// The residue of a modulo n in the range 0..n
+pub(crate) fn residue(a: &BigInt, n: &BigUInt) -> BigUInt {
+    let remainder = a.magnitude().div_mod(n).1;
+    if a.is_negative() && !remainder.is_zero() {
+        n.sub_from(&remainder)
+    } else {
+        remainder
+    }
+}
+
+/// The lowest bits of value, bits must be at most 32
+fn low_bits_u32(value: &BigUInt, bits: usize) -> u32 {
+    value.low_bits(bits).to_u64().expect("Unexpected value larger than 32 bits") as u32
+}
@@ -0,0 +1,293 @@
+use crate::BigUInt;
+use super::factorize;
+
+/// Euler's totient φ(n), the number of values in 1..=n coprime to n.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::totient;
+/// assert_eq!(totient(&BigUInt::from(1u32)), BigUInt::from(1u32));
+/// assert_eq!(totient(&BigUInt::from(36u32)), BigUInt::from(12u32));
+/// ```
+pub fn totient(n: &BigUInt) -> BigUInt {
+    totient_from_factors(&factorize(n))
+}
+
+/// Euler's totient φ(n) = Π p^(e-1) (p - 1) from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, as returned by
+///   [factorize](super::factorize). Factors with exponent 0 do not divide n and are skipped.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::totient_from_factors;
+/// // 2^3 * 3^2 * 5
+/// let factors = [(2u32, 3u32), (3, 2), (5, 1)].map(|(p, e)| (BigUInt::from(p), e));
+/// assert_eq!(totient_from_factors(&factors), BigUInt::from(96u32));
+/// ```
+pub fn totient_from_factors(factors: &[(BigUInt, u32)]) -> BigUInt {
+    factors.iter().filter(|(_, e)| *e > 0).fold(BigUInt::from(1u32), |acc, (p, e)| {
+        acc.mul_with(&p.powi(e - 1)).mul_with(&p.sub_from(&1u32.into()))
+    })
+}
+
+/// Carmichael's function λ(n), the exponent of the multiplicative group modulo n, so the smallest
+/// m > 0 with a^m = 1 (mod n) for all a coprime to n.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::carmichael;
+/// assert_eq!(carmichael(&BigUInt::from(8u32)), BigUInt::from(2u32));
+/// // 561 = 3 * 11 * 17 is a Carmichael number, so λ(561) divides 560
+/// assert_eq!(carmichael(&BigUInt::from(561u32)), BigUInt::from(80u32));
+/// ```
+pub fn carmichael(n: &BigUInt) -> BigUInt {
+    carmichael_from_factors(&factorize(n))
+}
+
+/// Carmichael's function λ(n) from the factorization of n, the least common multiple of λ(p^e)
+/// over the prime powers, with λ(2^e) = 2^(e-2) for e >= 3 and λ(p^e) = φ(p^e) otherwise.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::carmichael_from_factors;
+/// // 2^5 * 7
+/// let factors = [(2u32, 5u32), (7, 1)].map(|(p, e)| (BigUInt::from(p), e));
+/// assert_eq!(carmichael_from_factors(&factors), BigUInt::from(24u32));
+/// ```
+pub fn carmichael_from_factors(factors: &[(BigUInt, u32)]) -> BigUInt {
+    factors.iter().filter(|(_, e)| *e > 0).fold(BigUInt::from(1u32), |acc, (p, e)| {
+        let lambda = if *p == BigUInt::from(2u32) && *e >= 3 {
+            BigUInt::from(1u32) << (*e as usize - 2)
+        } else {
+            p.powi(e - 1).mul_with(&p.sub_from(&1u32.into()))
+        };
+        let gcd = acc.gcd(&lambda);
+        acc.div_by(&gcd).mul_with(&lambda)
+    })
+}
+
+/// The number of divisors d(n) of n.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::divisor_count;
+/// assert_eq!(divisor_count(&BigUInt::from(1u32)), BigUInt::from(1u32));
+/// assert_eq!(divisor_count(&BigUInt::from(720u32)), BigUInt::from(30u32));
+/// ```
+pub fn divisor_count(n: &BigUInt) -> BigUInt {
+    divisor_count_from_factors(&factorize(n))
+}
+
+/// The number of divisors d(n) = Π (e + 1) from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+pub fn divisor_count_from_factors(factors: &[(BigUInt, u32)]) -> BigUInt {
+    factors.iter().filter(|(_, e)| *e > 0).fold(BigUInt::from(1u32), |acc, (_, e)| acc.mul_with(&(e + 1).into()))
+}
+
+/// The divisor function σ_k(n), the sum of the k-th powers of all divisors of n.
+///
+/// σ_0 is the number of divisors and σ_1 the sum of divisors.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::divisor_sum;
+/// // 28 is perfect
+/// assert_eq!(divisor_sum(&BigUInt::from(28u32), 1), BigUInt::from(56u32));
+/// // 1 + 4 + 9 + 36
+/// assert_eq!(divisor_sum(&BigUInt::from(6u32), 2), BigUInt::from(50u32));
+/// ```
+pub fn divisor_sum(n: &BigUInt, k: u32) -> BigUInt {
+    divisor_sum_from_factors(&factorize(n), k)
+}
+
+/// The divisor function σ_k(n) = Π (1 + p^k + p^2k + ... + p^ek) from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+/// * k - the power of the divisors
+pub fn divisor_sum_from_factors(factors: &[(BigUInt, u32)], k: u32) -> BigUInt {
+    factors.iter().filter(|(_, e)| *e > 0).fold(BigUInt::from(1u32), |acc, (p, e)| {
+        let power = p.powi(k);
+        // Horner's scheme for the geometric series
+        let sum = (0..*e).fold(BigUInt::from(1u32), |sum, _| sum.mul_with(&power).add_to(&1u32.into()));
+        acc.mul_with(&sum)
+    })
+}
+
+/// An iterator over all divisors of n.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::divisors;
+/// let mut all: Vec<BigUInt> = divisors(&BigUInt::from(12u32)).collect();
+/// all.sort();
+/// assert_eq!(all, [1u32, 2, 3, 4, 6, 12].map(BigUInt::from).to_vec());
+/// ```
+pub fn divisors(n: &BigUInt) -> Divisors {
+    Divisors::new(factorize(n))
+}
+
+/// An iterator over all divisors of n from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+pub fn divisors_from_factors(factors: &[(BigUInt, u32)]) -> Divisors {
+    Divisors::new(factors.iter().filter(|(_, e)| *e > 0).cloned().collect())
+}
+
+/// Iterator over the divisors of a number, created by [divisors] and [divisors_from_factors].
+///
+/// The divisors are not sorted, they are generated by counting through the exponents of the prime
+/// factors, the exponent of the first factor changing fastest.
+#[derive(Clone, Debug)]
+pub struct Divisors {
+    factors: Vec<(BigUInt, u32)>,
+    exponents: Vec<u32>,
+    done: bool,
+}
+
+impl Divisors {
+    fn new(factors: Vec<(BigUInt, u32)>) -> Divisors {
+        let exponents = vec![0; factors.len()];
+        Divisors { factors, exponents, done: false }
+    }
+}
+
+impl Iterator for Divisors {
+    type Item = BigUInt;
+
+    fn next(&mut self) -> Option<BigUInt> {
+        if self.done {
+            return None;
+        }
+        let divisor = self.factors.iter().zip(&self.exponents)
+            .fold(BigUInt::from(1u32), |acc, ((p, _), e)| acc.mul_with(&p.powi(*e)));
+        // advance the exponents like an odometer, done after the last one wraps around
+        self.done = true;
+        for ((_, max), e) in self.factors.iter().zip(self.exponents.iter_mut()) {
+            if *e < *max {
+                *e += 1;
+                self.done = false;
+                break;
+            }
+            *e = 0;
+        }
+        Some(divisor)
+    }
+}
+
+/// The Möbius function μ(n), 0 if n has a squared prime factor, otherwise 1 for an even and -1
+/// for an odd number of prime factors.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::moebius;
+/// assert_eq!(moebius(&BigUInt::from(1u32)), 1);
+/// assert_eq!(moebius(&BigUInt::from(30u32)), -1);
+/// assert_eq!(moebius(&BigUInt::from(12u32)), 0);
+/// ```
+pub fn moebius(n: &BigUInt) -> i32 {
+    moebius_from_factors(&factorize(n))
+}
+
+/// The Möbius function μ(n) from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+pub fn moebius_from_factors(factors: &[(BigUInt, u32)]) -> i32 {
+    if !is_squarefree_from_factors(factors) {
+        0
+    } else if factors.iter().filter(|(_, e)| *e > 0).count().is_multiple_of(2) {
+        1
+    } else {
+        -1
+    }
+}
+
+/// The radical of n, the product of its distinct prime factors.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::radical;
+/// assert_eq!(radical(&BigUInt::from(504u32)), BigUInt::from(42u32));
+/// ```
+pub fn radical(n: &BigUInt) -> BigUInt {
+    radical_from_factors(&factorize(n))
+}
+
+/// The radical of n from the factorization of n.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+pub fn radical_from_factors(factors: &[(BigUInt, u32)]) -> BigUInt {
+    factors.iter().filter(|(_, e)| *e > 0).fold(BigUInt::from(1u32), |acc, (p, _)| acc.mul_with(p))
+}
+
+/// Check if n is not divisible by the square of a prime.
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::is_squarefree;
+/// assert!(is_squarefree(&BigUInt::from(1u32)));
+/// assert!(is_squarefree(&BigUInt::from(1155u32)));
+/// assert!(!is_squarefree(&BigUInt::from(1156u32)));
+/// ```
+pub fn is_squarefree(n: &BigUInt) -> bool {
+    is_squarefree_from_factors(&factorize(n))
+}
+
+/// Check if n is squarefree from the factorization of n, so no exponent is larger than 1.
+///
+/// # Arguments
+/// * factors - the distinct prime factors of n with their exponents, factors with exponent 0
+///   are skipped
+pub fn is_squarefree_from_factors(factors: &[(BigUInt, u32)]) -> bool {
+    factors.iter().all(|(_, e)| *e <= 1)
+}
@@ -0,0 +1,92 @@
+use crate::{BigInt, BigUInt};
+use super::residue;
+
+/// Solve the linear Diophantine equation a * x + b * y = c in integers.
+///
+/// The equation is solvable if gcd(a, b) divides c. All solutions are then
+/// (x0 + k * dx, y0 + k * dy) for integers k, with the step (dx, dy) = (b / g, -a / g).
+///
+/// # Returns
+/// The particular solution (x0, y0) with the smallest non-negative x0, or the smallest
+/// non-negative y0 if b is zero, and the step (dx, dy), or None if there is no solution
+///
+/// # Panics
+/// Panics if a and b are both zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigInt;
+/// use simple_big_int::number_theory::solve_linear_diophantine;
+/// // 12 x + 42 y = 30
+/// let (solution, step) = solve_linear_diophantine(&12.into(), &42.into(), &30.into()).unwrap();
+/// assert_eq!(solution, (BigInt::from(6), BigInt::from(-1)));
+/// assert_eq!(step, (BigInt::from(7), BigInt::from(-2)));
+/// assert_eq!(solve_linear_diophantine(&12.into(), &42.into(), &31.into()), None);
+/// ```
+pub fn solve_linear_diophantine(a: &BigInt, b: &BigInt, c: &BigInt) -> Option<((BigInt, BigInt), (BigInt, BigInt))> {
+    assert!(!a.is_zero() || !b.is_zero(), "a and b must not both be zero");
+    let (gcd, x, y) = a.extended_gcd(b);
+    let (quotient, remainder) = c.magnitude().div_mod(&gcd);
+    if !remainder.is_zero() {
+        return None;
+    }
+    let gcd = BigInt::from_sign_magnitude(false, gcd);
+    let dx = b.div_by(&gcd);
+    let dy = BigInt::from_sign_magnitude(!a.is_negative(), a.magnitude().div_by(gcd.magnitude()));
+    let quotient = BigInt::from_sign_magnitude(c.is_negative(), quotient);
+    // reduce the solution scaled from the Bézout coefficients to the smallest non-negative x or y
+    let solution = if b.is_zero() {
+        let y = BigInt::from_sign_magnitude(false, residue(&y.mul_with(&quotient), dy.magnitude()));
+        (c.sub_from(&b.mul_with(&y)).div_by(a).normalized(), y)
+    } else {
+        let x = BigInt::from_sign_magnitude(false, residue(&x.mul_with(&quotient), dx.magnitude()));
+        (x.clone(), c.sub_from(&a.mul_with(&x)).div_by(b).normalized())
+    };
+    Some((solution, (dx.normalized(), dy)))
+}
+
+/// Solve Pell's equation x² - d * y² = 1 with the continued fraction of √d.
+///
+/// The continued fraction of √d is periodic, √d = [a0; a1, ..., ar] with ar = 2 * a0. The
+/// fundamental solution is the convergent before the end of the first period if the period r is
+/// even, otherwise before the end of the second period. All positive solutions (xk, yk) follow
+/// from xk + yk √d = (x1 + y1 √d)^k.
+///
+/// # Returns
+/// The fundamental solution, the smallest positive (x, y), or None if d is a perfect square
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::solve_pell;
+/// assert_eq!(solve_pell(&2u32.into()), Some((BigUInt::from(3u32), BigUInt::from(2u32))));
+/// assert_eq!(solve_pell(&61u32.into()), Some((BigUInt::from(1766319049u64), BigUInt::from(226153980u64))));
+/// assert_eq!(solve_pell(&16u32.into()), None);
+/// ```
+pub fn solve_pell(d: &BigUInt) -> Option<(BigUInt, BigUInt)> {
+    let a0 = d.sqrt();
+    if a0.mul_with(&a0) == *d {
+        return None;
+    }
+    let twice_a0 = a0.shift_left(1);
+    // the complete quotients (√d + m) / q with the partial quotients a = ⌊(a0 + m) / q⌋
+    let mut m = BigUInt::new();
+    let mut q = BigUInt::from(1u32);
+    let mut a = a0.clone();
+    // the convergents h / k
+    let (mut h, mut h_previous) = (a0.clone(), BigUInt::from(1u32));
+    let (mut k, mut k_previous) = (BigUInt::from(1u32), BigUInt::new());
+    for index in 1usize.. {
+        m = q.mul_with(&a).sub_from(&m);
+        q = d.sub_from(&m.mul_with(&m)).div_by(&q);
+        a = a0.add_to(&m).div_by(&q);
+        if a == twice_a0 && index.is_multiple_of(2) {
+            return Some((h, k));
+        }
+        let h_next = a.mul_with(&h).add_to(&h_previous);
+        h_previous = std::mem::replace(&mut h, h_next);
+        let k_next = a.mul_with(&k).add_to(&k_previous);
+        k_previous = std::mem::replace(&mut k, k_next);
+    }
+    unreachable!("the continued fraction of √d is periodic")
+}
@@ -0,0 +1,134 @@
+use crate::{BigUInt, ModInt, Modulus};
+use super::{is_probable_prime, small_primes};
+
+/// Trial division removes all prime factors below this bound before Pollard's rho is used
+const TRIAL_DIVISION_LIMIT: u64 = 1000;
+
+/// The number of Miller-Rabin rounds deciding whether a cofactor is prime
+const PRIMALITY_ROUNDS: usize = 24;
+
+/// The number of rho steps whose differences are multiplied together before taking a gcd
+const RHO_BATCH: usize = 128;
+
+/// Factorize n into its prime factors.
+///
+/// Small factors are found by trial division, larger ones with Brent's variant of Pollard's rho
+/// algorithm, which takes time in the order of the square root of the second largest prime
+/// factor. Primality of the factors is decided by [is_probable_prime], so above 3.3 * 10^24 a
+/// constructed strong pseudoprime may be returned as a prime factor.
+///
+/// # Returns
+/// The distinct prime factors in ascending order with their exponents, empty for n = 1
+///
+/// # Panics
+/// Panics if n is zero.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::number_theory::factorize;
+/// let factors = [(2u32, 3u32), (3, 2), (5, 1), (7, 1)].map(|(p, e)| (BigUInt::from(p), e));
+/// assert_eq!(factorize(&BigUInt::from(2520u32)), factors.to_vec());
+/// // the sixth Fermat number
+/// let f6 = (BigUInt::from(1u32) << 64).add_to(&1u32.into());
+/// assert_eq!(factorize(&f6), vec![(BigUInt::from(274177u32), 1), (BigUInt::from(67280421310721u64), 1)]);
+/// assert!(factorize(&BigUInt::from(1u32)).is_empty());
+/// ```
+pub fn factorize(n: &BigUInt) -> Vec<(BigUInt, u32)> {
+    assert!(!n.is_zero(), "zero has no factorization");
+    let mut factors = vec![];
+    let mut rest = n.clone();
+    for p in small_primes(TRIAL_DIVISION_LIMIT) {
+        let mut exponent = 0;
+        loop {
+            let (quotient, remainder) = rest.div_mod_u64(p);
+            if remainder != 0 {
+                break;
+            }
+            rest = quotient;
+            exponent += 1;
+        }
+        if exponent > 0 {
+            factors.push((BigUInt::from(p), exponent));
+        }
+    }
+
+    let mut large_primes = vec![];
+    let mut composites = vec![];
+    if rest != BigUInt::from(1u32) {
+        composites.push(rest);
+    }
+    while let Some(m) = composites.pop() {
+        // all factors of m are at least TRIAL_DIVISION_LIMIT, so m below its square is prime
+        if m < BigUInt::from(TRIAL_DIVISION_LIMIT * TRIAL_DIVISION_LIMIT) || is_probable_prime(&m, PRIMALITY_ROUNDS) {
+            large_primes.push(m);
+        } else {
+            let divisor = pollard_brent(&m);
+            composites.push(m.div_by(&divisor));
+            composites.push(divisor);
+        }
+    }
+    large_primes.sort();
+    for p in large_primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == p => *exponent += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// A nontrivial divisor of the odd composite n with Brent's variant of Pollard's rho algorithm
+fn pollard_brent(n: &BigUInt) -> BigUInt {
+    let one = BigUInt::from(1u32);
+    let modulus = Modulus::new(n.clone());
+    // the iteration x -> x^2 + c fails for some c, in which case the next c is tried
+    for c in 1u32.. {
+        let c = modulus.element(&c.into());
+        let mut y = modulus.element(&2u32.into());
+        let mut x;
+        let mut saved = y.clone();
+        let mut product = modulus.one();
+        let mut divisor = one.clone();
+        let mut length = 1;
+        loop {
+            x = y.clone();
+            for _ in 0..length {
+                y = rho_step(&y, &c);
+            }
+            let mut k = 0;
+            while k < length && divisor == one {
+                saved = y.clone();
+                for _ in 0..RHO_BATCH.min(length - k) {
+                    y = rho_step(&y, &c);
+                    product.mul_into(&x.sub_from(&y));
+                }
+                divisor = product.to_biguint().gcd(n);
+                k += RHO_BATCH;
+            }
+            length *= 2;
+            if divisor != one {
+                break;
+            }
+        }
+        if divisor == *n {
+            // the batch contained the collision, repeat its steps one by one
+            loop {
+                saved = rho_step(&saved, &c);
+                divisor = x.sub_from(&saved).to_biguint().gcd(n);
+                if divisor != one {
+                    break;
+                }
+            }
+        }
+        if divisor != *n {
+            return divisor;
+        }
+    }
+    unreachable!("no divisor found")
+}
+
+/// The pseudorandom map x -> x^2 + c iterated by [pollard_brent]
+fn rho_step<'a>(x: &ModInt<'a>, c: &ModInt<'a>) -> ModInt<'a> {
+    x.mul_with(x).add_to(c)
+}
@@ -0,0 +1,305 @@
+use rand::Rng;
+use crate::BigUInt;
+use crate::BigInt;
+use super::{carmichael, carmichael_from_factors, crt, divisor_count, divisor_count_from_factors, divisor_sum,
+            divisor_sum_from_factors, divisors, divisors_from_factors, factorize, is_probable_prime, is_squarefree,
+            is_squarefree_from_factors, jacobi, kronecker, legendre, moebius, moebius_from_factors, radical,
+            radical_from_factors, small_primes, sqrt_mod_prime, solve_linear_diophantine, solve_pell,
+            sqrt_mod_prime_power, totient, totient_from_factors};
+
+fn congruences(src: &[(u64, u64)]) -> Vec<(BigUInt, BigUInt)> {
+    src.iter().map(|(residue, modulus)| (BigUInt::from(*residue), BigUInt::from(*modulus))).collect()
+}
+
+#[test]
+fn test_crt() {
+    assert_eq!(crt(&[]), Some((BigUInt::new(), BigUInt::from(1u32))));
+    assert_eq!(crt(&congruences(&[(10, 7)])), Some((3u32.into(), 7u32.into())));
+    // non-coprime moduli
+    assert_eq!(crt(&congruences(&[(3, 4), (5, 6)])), Some((11u32.into(), 12u32.into())));
+    assert_eq!(crt(&congruences(&[(3, 4), (4, 6)])), None);
+    assert_eq!(crt(&congruences(&[(5, 12), (1, 4), (2, 3)])), Some((5u32.into(), 12u32.into())));
+
+    // the three largest primes below 2^64
+    let primes = [u64::MAX - 58, u64::MAX - 82, u64::MAX - 94];
+    let mut rng = rand::thread_rng();
+    for _ in 0..20 {
+        let x = (0..3).fold(BigUInt::new(), |acc, _| (acc << 60).add_to(&rng.gen::<u32>().into()));
+        let system: Vec<(BigUInt, BigUInt)> = primes.iter()
+            .map(|p| (x.div_mod(&BigUInt::from(*p)).1, BigUInt::from(*p)))
+            .collect();
+        let (solution, modulus) = crt(&system).expect("consistent system");
+        assert_eq!(modulus, primes.iter().fold(BigUInt::from(1u32), |acc, p| acc.mul_with(&BigUInt::from(*p))));
+        assert_eq!(solution, x);
+    }
+}
+
+#[test]
+fn test_crt_brute_force() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..200 {
+        let system: Vec<(u64, u64)> = (0..3).map(|_| {
+            let modulus = rng.gen_range(1..30u64);
+            (rng.gen_range(0..100u64), modulus)
+        }).collect();
+        let lcm = system.iter().fold(1u64, |acc, (_, m)| {
+            let g = BigUInt::from(acc).gcd(&BigUInt::from(*m)).to_u64().unwrap();
+            acc / g * m
+        });
+        let expected = (0..lcm).find(|x| system.iter().all(|(r, m)| x % m == r % m));
+        assert_eq!(crt(&congruences(&system)),
+                   expected.map(|x| (BigUInt::from(x), BigUInt::from(lcm))), "{:?}", system);
+    }
+}
+
+fn is_small_prime(n: u64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| !n.is_multiple_of(d))
+}
+
+#[test]
+fn test_jacobi_kronecker() {
+    // Euler's criterion for odd primes, multiplicativity for composites
+    let odd_primes: Vec<u64> = (3..60).filter(|n| is_small_prime(*n)).collect();
+    for p in odd_primes.iter() {
+        for a in -70i64..70 {
+            let expected = match BigUInt::from(a.rem_euclid(*p as i64) as u64)
+                .modpow(&((p - 1) / 2).into(), &(*p).into()).to_u64().unwrap() {
+                0 => 0,
+                1 => 1,
+                _ => -1,
+            };
+            assert_eq!(legendre(&BigInt::from(a), &(*p).into()), expected, "({}/{})", a, p);
+        }
+    }
+    for n in (1..200u64).step_by(2) {
+        for a in -30i64..30 {
+            let mut expected = 1;
+            let mut rest = n;
+            for p in odd_primes.iter().chain([61u64, 67, 71, 73, 79, 83, 89, 97].iter()) {
+                while rest % p == 0 {
+                    expected *= legendre(&BigInt::from(a), &(*p).into());
+                    rest /= p;
+                }
+            }
+            if rest > 1 {
+                expected *= legendre(&BigInt::from(a), &rest.into());
+            }
+            assert_eq!(jacobi(&BigInt::from(a), &n.into()), expected, "({}/{})", a, n);
+        }
+    }
+    assert_eq!(jacobi(&BigInt::from(5), &1u32.into()), 1);
+    for (a, n, expected) in [(2, 8, 0), (3, 8, -1), (7, 8, 1), (7, -8, 1), (-7, -8, -1), (-7, 8, 1),
+                             (5, 0, 0), (-1, 0, 1), (0, 1, 1), (0, -1, 1), (-3, -1, -1), (11, -12, -1)] {
+        assert_eq!(kronecker(&BigInt::from(a), &BigInt::from(n)), expected, "({}/{})", a, n);
+    }
+}
+
+#[test]
+fn test_sqrt_mod_prime() {
+    // all residues for small primes, 97, 113 and 257 use Tonelli–Shanks, 5 * 2^13 + 1 uses Cipolla
+    for p in [2u64, 3, 5, 7, 13, 17, 97, 113, 257, 40961] {
+        let mut is_square = vec![false; p as usize];
+        (0..p).for_each(|x| is_square[(x * x % p) as usize] = true);
+        for a in 0..u64::min(p, 2000) {
+            let root = sqrt_mod_prime(&a.into(), &p.into()).map(|r| r.to_u64().unwrap());
+            if is_square[a as usize] {
+                let root = root.expect("missing root");
+                assert_eq!(root * root % p, a, "sqrt({}) mod {}", a, p);
+                assert!(root <= p - root, "sqrt({}) mod {}", a, p);
+            } else {
+                assert_eq!(root, None, "sqrt({}) mod {}", a, p);
+            }
+        }
+    }
+    let mut rng = rand::thread_rng();
+    let ed25519 = (BigUInt::from(1u32) << 255).sub_from(&19u32.into());
+    // 3 * 2^30 + 1 and 15 * 2^27 + 1 use Cipolla
+    for p in [ed25519.clone(), BigUInt::from(u64::MAX - 58), BigUInt::from(3221225473u64), BigUInt::from(2013265921u64)] {
+        for _ in 0..10 {
+            let x = (0..5).fold(BigUInt::new(), |acc, _| (acc << 64).add_to(&rng.gen::<u64>().into()));
+            let square = x.mul_with(&x).div_mod(&p).1;
+            let root = sqrt_mod_prime(&square, &p).expect("missing root");
+            let x = x.div_mod(&p).1;
+            assert!(root == x || root == p.sub_from(&x));
+        }
+    }
+    // 2 is a non residue modulo primes 3, 5 (mod 8)
+    assert_eq!(sqrt_mod_prime(&2u32.into(), &ed25519), None);
+}
+
+#[test]
+fn test_sqrt_mod_prime_power() {
+    for (p, k) in [(2u64, 1u32), (2, 2), (2, 3), (2, 7), (3, 4), (5, 3), (7, 2), (13, 2)] {
+        let power = p.pow(k);
+        let squares: Vec<u64> = (0..power).map(|x| x * x % power).collect();
+        for a in 0..power {
+            let root = sqrt_mod_prime_power(&a.into(), &p.into(), k).map(|r| r.to_u64().unwrap());
+            if squares.contains(&a) {
+                let root = root.expect("missing root");
+                assert_eq!(root * root % power, a, "sqrt({}) mod {}^{}", a, p, k);
+            } else {
+                assert_eq!(root, None, "sqrt({}) mod {}^{}", a, p, k);
+            }
+        }
+    }
+    let p = BigUInt::from(u64::MAX - 58);
+    let power = p.powi(5);
+    let x = BigUInt::from_dec_str("123456789012345678901234567890123456789").unwrap();
+    let root = sqrt_mod_prime_power(&x.mul_with(&x), &p, 5).expect("missing root");
+    assert_eq!(root.mul_with(&root).div_mod(&power).1, x.mul_with(&x).div_mod(&power).1);
+}
+
+#[test]
+fn test_is_probable_prime() {
+    for n in 0u64..3000 {
+        assert_eq!(is_probable_prime(&BigUInt::from(n), 8), is_small_prime(n), "{}", n);
+    }
+    assert_eq!(small_primes(30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    // Carmichael numbers and a product of two large primes
+    for n in [561u64, 41041, 825265, 321197185, 4294967291 * 4294967279] {
+        assert!(!is_probable_prime(&BigUInt::from(n), 8), "{}", n);
+    }
+    let prime = (BigUInt::from(1u32) << 521).sub_from(&1u32.into());
+    assert!(is_probable_prime(&prime, 8));
+    assert!(!is_probable_prime(&prime.mul_with(&BigUInt::from(2147483647u32)), 8));
+}
+
+fn product(factors: &[(BigUInt, u32)]) -> BigUInt {
+    factors.iter().fold(BigUInt::from(1u32), |acc, (p, e)| acc.mul_with(&p.powi(*e)))
+}
+
+#[test]
+fn test_factorize() {
+    for n in 1u64..2000 {
+        let factors = factorize(&BigUInt::from(n));
+        assert_eq!(product(&factors), BigUInt::from(n));
+        assert!(factors.iter().all(|(p, _)| is_small_prime(p.to_u64().unwrap())), "{}", n);
+        assert!(factors.windows(2).all(|w| w[0].0 < w[1].0), "{}", n);
+    }
+    // products of primes beyond trial division, including repeated ones
+    let p = BigUInt::from(4294967291u64);
+    let q = BigUInt::from(4294967279u64);
+    let r = BigUInt::from(1000003u32);
+    let expected = vec![(BigUInt::from(3u32), 2), (r.clone(), 3), (q.clone(), 1), (p.clone(), 2)];
+    assert_eq!(factorize(&product(&expected)), expected);
+}
+
+#[test]
+fn test_arithmetic_functions() {
+    let gcd = |a: u64, b: u64| BigUInt::from(a).gcd(&BigUInt::from(b)).to_u64().unwrap();
+    for n in 1u64..300 {
+        let big = BigUInt::from(n);
+        let all: Vec<u64> = (1..=n).filter(|d| n.is_multiple_of(*d)).collect();
+        let mut found: Vec<u64> = divisors(&big).map(|d| d.to_u64().unwrap()).collect();
+        found.sort();
+        assert_eq!(found, all);
+        assert_eq!(divisor_count(&big), BigUInt::from(all.len() as u64));
+        for k in 0..4 {
+            assert_eq!(divisor_sum(&big, k), BigUInt::from(all.iter().map(|d| d.pow(k)).sum::<u64>()));
+        }
+        let units: Vec<u64> = (1..=n).filter(|a| gcd(*a, n) == 1).collect();
+        assert_eq!(totient(&big), BigUInt::from(units.len() as u64));
+        // the smallest exponent m with a^m = 1 for all units
+        let lambda = (1..=n).find(|m| units.iter().all(|a| {
+            BigUInt::from(*a).modpow(&BigUInt::from(*m), &big) == BigUInt::from(1u32) || n == 1
+        })).unwrap();
+        assert_eq!(carmichael(&big), BigUInt::from(lambda), "{}", n);
+        let squarefree = (2..=n).all(|d| !n.is_multiple_of(d * d));
+        assert_eq!(is_squarefree(&big), squarefree);
+        let radical_value = all.iter().filter(|d| is_small_prime(**d)).product::<u64>();
+        assert_eq!(radical(&big), BigUInt::from(radical_value));
+        let omega = all.iter().filter(|d| is_small_prime(**d)).count();
+        assert_eq!(moebius(&big), if squarefree { 1 - 2 * (omega as i32 % 2) } else { 0 });
+    }
+    // Möbius inversion, the sum of μ(d) over all divisors of n > 1 is zero
+    let n = BigUInt::from(2u32 * 3 * 5 * 7 * 11 * 13 * 64);
+    assert_eq!(divisors(&n).map(|d| moebius(&d)).sum::<i32>(), 0);
+    // the factorization variants work on numbers too large to factor
+    let p = (BigUInt::from(1u32) << 521).sub_from(&1u32.into());
+    let factors = [(BigUInt::from(2u32), 4), (p.clone(), 1)];
+    assert_eq!(carmichael_from_factors(&factors), p.sub_from(&1u32.into()).shift_left(1));
+    // factors with exponent 0 do not divide n
+    let factors = [(BigUInt::from(2u32), 3), (BigUInt::from(3u32), 0), (BigUInt::from(5u32), 1)];
+    assert_eq!(totient_from_factors(&factors), BigUInt::from(16u32));
+    assert_eq!(carmichael_from_factors(&factors), BigUInt::from(4u32));
+    assert_eq!(totient_from_factors(&[(BigUInt::from(7u32), 0)]), BigUInt::from(1u32));
+    // [(2, 1), (3, 0)] describes n = 2
+    let factors = [(BigUInt::from(2u32), 1), (BigUInt::from(3u32), 0)];
+    assert_eq!(moebius_from_factors(&factors), -1);
+    assert!(is_squarefree_from_factors(&factors));
+    assert_eq!(radical_from_factors(&factors), BigUInt::from(2u32));
+    assert_eq!(divisor_count_from_factors(&factors), BigUInt::from(2u32));
+    assert_eq!(divisor_sum_from_factors(&factors, 1), BigUInt::from(3u32));
+    assert_eq!(divisors_from_factors(&factors).collect::<Vec<_>>(), [1u32, 2].map(BigUInt::from).to_vec());
+}
+
+#[test]
+fn test_solve_linear_diophantine() {
+    for a in -12i64..=12 {
+        for b in -12i64..=12 {
+            if a == 0 && b == 0 {
+                continue;
+            }
+            for c in -30i64..=30 {
+                let solutions: Vec<(i64, i64)> = (-40i64..=40)
+                    .flat_map(|x| (-40i64..=40).map(move |y| (x, y)))
+                    .filter(|(x, y)| a * x + b * y == c)
+                    .collect();
+                let result = solve_linear_diophantine(&a.into(), &b.into(), &c.into());
+                let Some(((x0, y0), (dx, dy))) = result else {
+                    assert!(solutions.is_empty(), "{} {} {}", a, b, c);
+                    continue;
+                };
+                let (x0, y0, dx, dy) = (x0.to_i64().unwrap(), y0.to_i64().unwrap(), dx.to_i64().unwrap(), dy.to_i64().unwrap());
+                assert_eq!(a * x0 + b * y0, c);
+                assert_eq!((a * dx + b * dy, a * dy <= 0), (0, true));
+                if b == 0 {
+                    assert!(0 <= y0 && y0 < dy.abs().max(1), "{} {} {}", a, b, c);
+                } else {
+                    assert!(0 <= x0 && x0 < dx.abs(), "{} {} {}", a, b, c);
+                }
+                // every solution in the window is on the line of solutions
+                for (x, y) in solutions {
+                    let k = if dx != 0 { (x - x0) / dx } else { (y - y0) / dy };
+                    assert_eq!((x0 + k * dx, y0 + k * dy), (x, y), "{} {} {}", a, b, c);
+                }
+            }
+        }
+    }
+    // coefficients beyond 128 bits
+    let a = BigInt::from(3).pow(100);
+    let b = BigInt::from(-2).pow(101);
+    let c = BigInt::from(7).pow(40);
+    let ((x, y), (dx, dy)) = solve_linear_diophantine(&a, &b, &c).unwrap();
+    assert_eq!(a.mul_with(&x).add_to(&b.mul_with(&y)), c);
+    assert_eq!((dx, dy), (b.clone(), a.mul_with(&BigInt::from(-1))));
+}
+
+#[test]
+fn test_solve_pell() {
+    for d in 0u64..200 {
+        let solution = solve_pell(&d.into());
+        let root = (d as f64).sqrt() as u64;
+        if root * root == d {
+            assert_eq!(solution, None);
+            continue;
+        }
+        let (x, y) = solution.unwrap();
+        let big_d = BigUInt::from(d);
+        assert_eq!(x.mul_with(&x), big_d.mul_with(&y).mul_with(&y).add_to(&1u32.into()), "{}", d);
+        // no smaller solution exists
+        if let Some(y) = y.to_u64() {
+            for smaller in 1..y.min(1000) {
+                let square = BigUInt::from(d * smaller * smaller + 1);
+                let root = square.sqrt();
+                assert_ne!(root.mul_with(&root), square, "{} {}", d, smaller);
+            }
+        }
+    }
+    // the well known fundamental solution for 991 and one with thousands of bits
+    let (x, y) = solve_pell(&991u32.into()).unwrap();
+    assert_eq!((x.to_u128(), y.to_u128()), (Some(379516400906811930638014896080), Some(12055735790331359447442538767)));
+    let (x, y) = solve_pell(&1000099u32.into()).unwrap();
+    assert_eq!(x.mul_with(&x), BigUInt::from(1000099u32).mul_with(&y).mul_with(&y).add_to(&1u32.into()));
+    assert!(x.length() > 3700);
+}
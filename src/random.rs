@@ -0,0 +1,286 @@
+//! Random BigUInt, BigInt and Rational values drawn from any [rand::Rng].
+//!
+//! [RandBigInt] extends every Rng with uniform sampling of big values, [RandomBits] is a
+//! distribution of values with a given number of random bits, and BigUInt and BigInt implement
+//! [SampleUniform], so `rng.gen_range(low..high)` and [rand::distributions::Uniform] work with
+//! them as with primitive integers.
+
+use rand::Rng;
+use rand::distributions::Distribution;
+use rand::distributions::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+
+use crate::BigUInt;
+
+#[cfg(feature = "big_int")]
+use crate::BigInt;
+
+#[cfg(feature = "rational")]
+use crate::{Rational, RoundingMode};
+
+#[cfg(test)]
+mod test;
+
+/// Uniform sampling of big values, implemented for every [Rng]
+pub trait RandBigInt {
+    /// Generate a random BigUInt of up to bits bits, uniform in 0..2^bits.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use simple_big_int::random::RandBigInt;
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// assert!(rng.gen_biguint(100).length() <= 100);
+    /// assert!(rng.gen_biguint(0).is_zero());
+    /// ```
+    fn gen_biguint(&mut self, bits: usize) -> BigUInt;
+
+    /// Generate a random BigUInt uniform in 0..bound, by rejection sampling.
+    ///
+    /// # Panics
+    /// Panics if bound is zero.
+    fn gen_biguint_below(&mut self, bound: &BigUInt) -> BigUInt;
+
+    /// Generate a random BigUInt uniform in low..high.
+    ///
+    /// # Panics
+    /// Panics if low >= high.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::random::RandBigInt;
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let low = BigUInt::from(1u32) << 100;
+    /// let high = low.add_to(&BigUInt::from(10u32));
+    /// let value = rng.gen_biguint_range(&low, &high);
+    /// assert!(value >= low && value < high);
+    /// ```
+    fn gen_biguint_range(&mut self, low: &BigUInt, high: &BigUInt) -> BigUInt;
+
+    /// Generate a random BigInt with a magnitude of up to bits bits and a random sign, uniform
+    /// in -2^bits < x < 2^bits.
+    #[cfg(feature = "big_int")]
+    fn gen_bigint(&mut self, bits: usize) -> BigInt;
+
+    /// Generate a random BigInt uniform in low..high.
+    ///
+    /// # Panics
+    /// Panics if low >= high.
+    #[cfg(feature = "big_int")]
+    fn gen_bigint_range(&mut self, low: &BigInt, high: &BigInt) -> BigInt;
+
+    /// Generate a random Rational uniform among the multiples of 1 / denominator in low..high.
+    ///
+    /// # Panics
+    /// Panics if denominator is zero or there is no such multiple in low..high.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    /// use simple_big_int::{BigUInt, Rational};
+    /// use simple_big_int::random::RandBigInt;
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let low = Rational::from((-1, 3));
+    /// let high = Rational::from((1, 3));
+    /// let value = rng.gen_rational_range(&low, &high, &BigUInt::from(10u32));
+    /// assert!(value >= low && value < high);
+    /// assert_eq!(value.mul_by(&Rational::from(10)).is_integer(), true);
+    /// ```
+    #[cfg(feature = "rational")]
+    fn gen_rational_range(&mut self, low: &Rational, high: &Rational, denominator: &BigUInt) -> Rational;
+}
+
+impl<R: Rng + ?Sized> RandBigInt for R {
+    fn gen_biguint(&mut self, bits: usize) -> BigUInt {
+        let mut bytes = vec![0u8; bits.div_ceil(8)];
+        self.fill_bytes(&mut bytes);
+        if !bits.is_multiple_of(8) {
+            bytes[0] &= 0xFF >> (8 - bits % 8);
+        }
+        BigUInt::from_bytes_be(&bytes)
+    }
+
+    fn gen_biguint_below(&mut self, bound: &BigUInt) -> BigUInt {
+        assert!(!bound.is_zero(), "bound must not be zero");
+        // every candidate is accepted with a probability of more than 1/2
+        loop {
+            let candidate = self.gen_biguint(bound.length());
+            if candidate < *bound {
+                return candidate;
+            }
+        }
+    }
+
+    fn gen_biguint_range(&mut self, low: &BigUInt, high: &BigUInt) -> BigUInt {
+        assert!(low < high, "empty range");
+        low.add_to(&self.gen_biguint_below(&high.sub_from(low)))
+    }
+
+    #[cfg(feature = "big_int")]
+    fn gen_bigint(&mut self, bits: usize) -> BigInt {
+        // reject a negative zero, it would make zero twice as likely as any other value
+        loop {
+            let magnitude = self.gen_biguint(bits);
+            let negative = self.gen::<bool>();
+            if !(negative && magnitude.is_zero()) {
+                return BigInt::from_sign_magnitude(negative, magnitude);
+            }
+        }
+    }
+
+    #[cfg(feature = "big_int")]
+    fn gen_bigint_range(&mut self, low: &BigInt, high: &BigInt) -> BigInt {
+        assert!(low < high, "empty range");
+        let width = high.sub_from(low).as_unsigned();
+        low.add_to(&BigInt::from(self.gen_biguint_below(&width)))
+    }
+
+    #[cfg(feature = "rational")]
+    fn gen_rational_range(&mut self, low: &Rational, high: &Rational, denominator: &BigUInt) -> Rational {
+        assert!(!denominator.is_zero(), "Division by zero");
+        let scale = Rational::from(denominator.clone());
+        // the numerators k with low <= k / denominator < high
+        let first = low.mul_by(&scale).to_integer(RoundingMode::Ceiling);
+        let end = high.mul_by(&scale).to_integer(RoundingMode::Ceiling);
+        let numerator = self.gen_bigint_range(&first, &end);
+        Rational::from(numerator).div_by(&scale)
+    }
+}
+
+/// A distribution of BigUInt values uniform in 0..2^bits and BigInt values uniform in
+/// -2^bits < x < 2^bits.
+///
+/// # Examples
+/// ```
+/// use rand::{Rng, SeedableRng};
+/// use rand::rngs::StdRng;
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::random::RandomBits;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let values: Vec<BigUInt> = (&mut rng).sample_iter(RandomBits::new(256)).take(10).collect();
+/// assert!(values.iter().all(|value| value.length() <= 256));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBits {
+    bits: usize,
+}
+
+impl RandomBits {
+    pub fn new(bits: usize) -> RandomBits {
+        RandomBits { bits }
+    }
+}
+
+impl Distribution<BigUInt> for RandomBits {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUInt {
+        rng.gen_biguint(self.bits)
+    }
+}
+
+#[cfg(feature = "big_int")]
+impl Distribution<BigInt> for RandomBits {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt {
+        rng.gen_bigint(self.bits)
+    }
+}
+
+/// The uniform sampler of BigUInt values, used by [rand::distributions::Uniform] and
+/// [Rng::gen_range]
+///
+/// # Examples
+/// ```
+/// use rand::{Rng, SeedableRng};
+/// use rand::rngs::StdRng;
+/// use simple_big_int::BigUInt;
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let value = rng.gen_range(BigUInt::from(10u32)..=BigUInt::from(12u32));
+/// assert!(value >= BigUInt::from(10u32) && value <= BigUInt::from(12u32));
+/// ```
+#[derive(Clone, Debug)]
+pub struct UniformBigUInt {
+    low: BigUInt,
+    width: BigUInt,
+}
+
+impl UniformSampler for UniformBigUInt {
+    type X = BigUInt;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+        where B1: SampleBorrow<BigUInt> + Sized,
+              B2: SampleBorrow<BigUInt> + Sized {
+        let (low, high) = (low.borrow(), high.borrow());
+        assert!(low < high, "empty range");
+        UniformBigUInt {
+            low: low.clone(),
+            width: high.sub_from(low),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+        where B1: SampleBorrow<BigUInt> + Sized,
+              B2: SampleBorrow<BigUInt> + Sized {
+        let (low, high) = (low.borrow(), high.borrow());
+        assert!(low <= high, "empty range");
+        UniformBigUInt {
+            low: low.clone(),
+            width: high.sub_from(low).add_to(&1u32.into()),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUInt {
+        self.low.add_to(&rng.gen_biguint_below(&self.width))
+    }
+}
+
+impl SampleUniform for BigUInt {
+    type Sampler = UniformBigUInt;
+}
+
+/// The uniform sampler of BigInt values, used by [rand::distributions::Uniform] and
+/// [Rng::gen_range]
+#[cfg(feature = "big_int")]
+#[derive(Clone, Debug)]
+pub struct UniformBigInt {
+    low: BigInt,
+    width: BigUInt,
+}
+
+#[cfg(feature = "big_int")]
+impl UniformSampler for UniformBigInt {
+    type X = BigInt;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+        where B1: SampleBorrow<BigInt> + Sized,
+              B2: SampleBorrow<BigInt> + Sized {
+        let (low, high) = (low.borrow(), high.borrow());
+        assert!(low < high, "empty range");
+        UniformBigInt {
+            low: low.clone(),
+            width: high.sub_from(low).as_unsigned(),
+        }
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+        where B1: SampleBorrow<BigInt> + Sized,
+              B2: SampleBorrow<BigInt> + Sized {
+        let (low, high) = (low.borrow(), high.borrow());
+        assert!(low <= high, "empty range");
+        UniformBigInt {
+            low: low.clone(),
+            width: high.sub_from(low).as_unsigned().add_to(&1u32.into()),
+        }
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt {
+        self.low.add_to(&BigInt::from(rng.gen_biguint_below(&self.width)))
+    }
+}
+
+#[cfg(feature = "big_int")]
+impl SampleUniform for BigInt {
+    type Sampler = UniformBigInt;
+}
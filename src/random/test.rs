@@ -0,0 +1,107 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use crate::BigUInt;
+use super::{RandBigInt, RandomBits};
+
+#[cfg(feature = "big_int")]
+use rand::distributions::Uniform;
+
+#[cfg(feature = "big_int")]
+use crate::BigInt;
+
+#[cfg(feature = "rational")]
+use crate::Rational;
+
+#[test]
+fn test_gen_biguint() {
+    let mut rng = StdRng::seed_from_u64(44);
+    for bits in [0, 1, 7, 8, 9, 63, 64, 65, 200] {
+        let max = (0..100).map(|_| rng.gen_biguint(bits).length()).max().unwrap();
+        // the top bit is set in about half of the values
+        assert_eq!(max, bits, "{}", bits);
+    }
+    let values: Vec<BigUInt> = (&mut rng).sample_iter(RandomBits::new(70)).take(100).collect();
+    assert!(values.iter().all(|value| value.length() <= 70));
+}
+
+#[cfg(feature = "big_int")]
+#[test]
+fn test_gen_bigint() {
+    let mut rng = StdRng::seed_from_u64(44);
+    let values: Vec<BigInt> = (&mut rng).sample_iter(RandomBits::new(3)).take(200).collect();
+    assert!(values.iter().all(|value| value.abs() < BigInt::from(8)));
+    assert!(values.iter().any(|value| value.is_negative()));
+    assert!(values.iter().any(|value| *value == BigInt::from(-7)));
+}
+
+#[test]
+fn test_gen_below_uniform() {
+    let mut rng = StdRng::seed_from_u64(44);
+    // 10 buckets below a bound just above a power of two, where rejection happens most often
+    let bound = BigUInt::from(10u32);
+    let mut counts = [0u32; 10];
+    for _ in 0..10000 {
+        let value = rng.gen_biguint_below(&bound).to_u64().unwrap();
+        counts[value as usize] += 1;
+    }
+    assert!(counts.iter().all(|count| (850..1150).contains(count)), "{:?}", counts);
+    assert!(rng.gen_biguint_below(&BigUInt::from(1u32)).is_zero());
+
+    let low = BigUInt::from_dec_str("123456789012345678901234567890").unwrap();
+    let high = low.add_to(&BigUInt::from(3u32));
+    let mut seen = [false; 3];
+    for _ in 0..100 {
+        let value = rng.gen_biguint_range(&low, &high);
+        seen[value.sub_from(&low).to_u64().unwrap() as usize] = true;
+        let value = rng.gen_range(low.clone()..=high.clone());
+        assert!(value >= low && value <= high);
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[cfg(feature = "big_int")]
+#[test]
+fn test_gen_bigint_range() {
+    let mut rng = StdRng::seed_from_u64(44);
+    let (low, high) = (BigInt::from(-3), BigInt::from(2));
+    let mut counts = [0u32; 5];
+    for _ in 0..5000 {
+        let value = rng.gen_bigint_range(&low, &high).to_i64().unwrap();
+        counts[(value + 3) as usize] += 1;
+    }
+    assert!(counts.iter().all(|count| (850..1150).contains(count)), "{:?}", counts);
+
+    let uniform = Uniform::new_inclusive(BigInt::from(-1), BigInt::from(1));
+    let values: Vec<BigInt> = (&mut rng).sample_iter(&uniform).take(100).collect();
+    assert!(values.iter().all(|value| value.abs() <= BigInt::from(1)));
+    assert!(values.iter().any(|value| *value == BigInt::from(1)));
+    assert!(values.iter().any(|value| *value == BigInt::from(-1)));
+
+    let big_low = BigInt::from_i128(-(1 << 100));
+    let value = rng.gen_range(big_low.clone()..BigInt::from(-5));
+    assert!(value >= big_low && value < BigInt::from(-5));
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn test_gen_rational_range() {
+    let mut rng = StdRng::seed_from_u64(44);
+    let (low, high) = (Rational::from((-2, 3)), Rational::from((1, 4)));
+    let denominator = BigUInt::from(12u32);
+    let mut seen = [false; 11];
+    for _ in 0..500 {
+        let value = rng.gen_rational_range(&low, &high, &denominator);
+        assert!(value >= low && value < high);
+        // the multiples -8/12 ..= 2/12
+        let numerator = value.mul_by(&Rational::from(12)).to_integer(crate::RoundingMode::Floor);
+        seen[(numerator.to_i64().unwrap() + 8) as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+}
+
+#[test]
+#[should_panic(expected = "empty range")]
+fn test_empty_range() {
+    let mut rng = StdRng::seed_from_u64(44);
+    rng.gen_biguint_range(&BigUInt::from(5u32), &BigUInt::from(5u32));
+}
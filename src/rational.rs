@@ -14,6 +14,15 @@ pub use traits_math::*;
 mod math;
 pub use math::*;
 
+mod approx;
+
+mod decimal;
+pub(crate) use decimal::pow10;
+
+mod parse;
+
+mod transcendental;
+
 #[derive(Clone)]
 pub struct Rational {
     signed: bool,
@@ -50,6 +59,26 @@ impl Rational {
         self.numerator.is_zero()
     }
 
+    /// Create a Rational from already reduced parts, making sure zero is never signed
+    #[inline]
+    fn with_sign(signed: bool, numerator: BigUInt, denominator: BigUInt) -> Rational {
+        Rational {
+            signed: signed && !numerator.is_zero(),
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Create a Rational from parts that might not be reduced yet
+    fn reduced(signed: bool, numerator: BigUInt, denominator: BigUInt) -> Rational {
+        let gcd = numerator.gcd(&denominator);
+        if gcd > 1u32.into() {
+            Rational::with_sign(signed, numerator.div_by(&gcd), denominator.div_by(&gcd))
+        } else {
+            Rational::with_sign(signed, numerator, denominator)
+        }
+    }
+
 }
 
 impl<'a> Rational {
@@ -0,0 +1,165 @@
+use crate::{BigInt, BigUInt};
+use super::Rational;
+
+impl Rational {
+    /// Find the closest rational number with a denominator of at most max_denominator.
+    ///
+    /// Works like python's `Fraction.limit_denominator`: the continued fraction expansion of self
+    /// is walked until the next convergent would exceed max_denominator, then the closer of the
+    /// last convergent and the best semiconvergent is returned.
+    ///
+    /// # Arguments
+    /// * max_denominator - the largest denominator allowed in the result, must not be zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Rational, BigUInt};
+    /// let pi = Rational::from((3141592653589793u64, 1000000000000000u64));
+    /// assert_eq!(pi.limit_denominator(&BigUInt::from(10u32)), Rational::from((22u32, 7u32)));
+    /// assert_eq!(pi.limit_denominator(&BigUInt::from(1000u32)), Rational::from((355u32, 113u32)));
+    /// ```
+    pub fn limit_denominator(&self, max_denominator: &BigUInt) -> Rational {
+        assert!(!max_denominator.is_zero(), "max_denominator must be at least 1");
+        if self.denominator <= *max_denominator {
+            return self.clone();
+        }
+
+        // p0/q0 and p1/q1 are the last two convergents
+        let mut p0 = BigUInt::new();
+        let mut q0 = BigUInt::from(1u32);
+        let mut p1 = BigUInt::from(1u32);
+        let mut q1 = BigUInt::new();
+        let mut n = self.numerator.clone();
+        let mut d = self.denominator.clone();
+
+        while !d.is_zero() {
+            let (a, rem) = n.div_mod(&d);
+            let q2 = q0.add_to(&a.mul_with(&q1));
+            if q2 > *max_denominator {
+                break;
+            }
+            let p2 = p0.add_to(&a.mul_with(&p1));
+            p0 = std::mem::replace(&mut p1, p2);
+            q0 = std::mem::replace(&mut q1, q2);
+            n = std::mem::replace(&mut d, rem);
+        }
+
+        if d.is_zero() {
+            // only possible for a non reduced self, the last convergent is exact
+            return Rational::with_sign(self.signed, p1, q1);
+        }
+
+        // the best semiconvergent (p0 + k * p1) / (q0 + k * q1)
+        let k = max_denominator.sub_from(&q0).div_by(&q1);
+        let q_semi = q0.add_to(&k.mul_with(&q1));
+        // The distance between the two candidates is 1/(q1 * q_semi), the distance from
+        // p1/q1 to self is d/(q1 * denominator) so 2 * d * q_semi is compared to the denominator
+        if (d.mul_with(&q_semi) << 1) <= self.denominator {
+            Rational::with_sign(self.signed, p1, q1)
+        } else {
+            Rational::with_sign(self.signed, p0.add_to(&k.mul_with(&p1)), q_semi)
+        }
+    }
+
+    /// Find the simplest rational number in the closed interval [lo, hi].
+    ///
+    /// The simplest rational is the one with the smallest denominator and, amongst those, the
+    /// smallest absolute numerator. It is the first fraction reached when descending the
+    /// Stern–Brocot tree towards the interval.
+    ///
+    /// # Arguments
+    /// * lo - the lower bound of the interval
+    /// * hi - the upper bound of the interval, must not be less than lo
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let lo = Rational::from((31u32, 100u32));
+    /// let hi = Rational::from((32u32, 100u32));
+    /// assert_eq!(Rational::simplest_in_range(&lo, &hi), Rational::from((5u32, 16u32)));
+    /// ```
+    pub fn simplest_in_range(lo: &Rational, hi: &Rational) -> Rational {
+        assert!(lo <= hi, "lower bound must not be greater than upper bound");
+        if lo.is_zero() || hi.is_zero() || lo.signed != hi.signed {
+            // the interval contains zero
+            Rational::new()
+        } else if lo.signed {
+            // both negative, mirror to the positive side
+            let (numerator, denominator) = Rational::simplest_in_positive_range(
+                (&hi.numerator, &hi.denominator), (&lo.numerator, &lo.denominator));
+            Rational::with_sign(true, numerator, denominator)
+        } else {
+            let (numerator, denominator) = Rational::simplest_in_positive_range(
+                (&lo.numerator, &lo.denominator), (&hi.numerator, &hi.denominator));
+            Rational::with_sign(false, numerator, denominator)
+        }
+    }
+
+    fn simplest_in_positive_range(lo: (&BigUInt, &BigUInt), hi: (&BigUInt, &BigUInt)) -> (BigUInt, BigUInt) {
+        // 0 < lo <= hi
+        // If there is an integer in [lo, hi] the smallest one is the answer, otherwise
+        // lo and hi share the integer part a and the answer is a + 1 / simplest(1 / (hi - a), 1 / (lo - a)).
+        // The recursion is unrolled by collecting the integer parts and folding them back afterwards.
+        let (mut lo_num, mut lo_den) = (lo.0.clone(), lo.1.clone());
+        let (mut hi_num, mut hi_den) = (hi.0.clone(), hi.1.clone());
+        let mut terms = vec![];
+        let last = loop {
+            let (floor, rem) = lo_num.div_mod(&lo_den);
+            let ceil = if rem.is_zero() { floor.clone() } else { floor.add_to(&1u32.into()) };
+            if ceil.mul_with(&hi_den) <= hi_num {
+                break ceil;
+            }
+            // lo is not an integer and floor(lo) == floor(hi)
+            let next_lo = (hi_den.clone(), hi_num.sub_from(&floor.mul_with(&hi_den)));
+            let next_hi = (lo_den, rem);
+            terms.push(floor);
+            lo_num = next_lo.0;
+            lo_den = next_lo.1;
+            hi_num = next_hi.0;
+            hi_den = next_hi.1;
+        };
+
+        let mut numerator = last;
+        let mut denominator = BigUInt::from(1u32);
+        for term in terms.iter().rev() {
+            // term + 1 / (numerator / denominator)
+            let next = term.mul_with(&numerator).add_to(&denominator);
+            denominator = std::mem::replace(&mut numerator, next);
+        }
+        (numerator, denominator)
+    }
+
+    /// Recover a fraction n / d from its residue n * d^-1 mod modulus (Wang's rational
+    /// reconstruction).
+    ///
+    /// A fraction with |n|, d <= sqrt(modulus / 2) and d coprime to modulus is uniquely determined
+    /// by its residue, which lets multi-modular algorithms compute rational results from
+    /// calculations modulo primes.
+    ///
+    /// # Returns
+    /// The fraction or None if the residue does not correspond to a fraction within these bounds
+    ///
+    /// # Panics
+    /// Panics if modulus is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, Rational};
+    /// let modulus = BigUInt::from(1000003u32);
+    /// // -2/3 mod 1000003
+    /// let residue = BigUInt::from(666668u32);
+    /// assert_eq!(Rational::reconstruct(&residue, &modulus), Some(Rational::from((-2, 3))));
+    /// ```
+    pub fn reconstruct(residue: &BigUInt, modulus: &BigUInt) -> Option<Rational> {
+        assert!(!modulus.is_zero(), "Division by zero");
+        let bound = modulus.shift_right(1).sqrt();
+        let residue = residue.div_mod(modulus).1;
+        // every row satisfies s * modulus + t * residue = r, so r / t = residue (mod modulus)
+        let [_, (remainder, _, t)] = BigInt::euclid_rows(modulus, &residue, &bound);
+        if t.is_zero() || *t.magnitude() > bound || remainder.gcd(t.magnitude()) != BigUInt::from(1u32) {
+            None
+        } else {
+            Some(Rational::with_sign(t.is_negative(), remainder, t.magnitude().clone()))
+        }
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{BigUInt, RoundingMode};
+use super::Rational;
+
+/// Calculate 10^exp
+pub(crate) fn pow10(exp: usize) -> BigUInt {
+    BigUInt::from(10u32).powi(exp as u32)
+}
+
+/// Format a scaled integer as decimal with the given number of fractional digits
+fn insert_decimal_point(signed: bool, scaled: &BigUInt, digits: usize) -> String {
+    let mut res = String::new();
+    if signed && !scaled.is_zero() {
+        res.push('-');
+    }
+    let dec_str = scaled.to_dec_string();
+    if digits == 0 {
+        res.push_str(&dec_str);
+    } else if dec_str.len() <= digits {
+        res.push_str("0.");
+        (dec_str.len()..digits).for_each(|_| res.push('0'));
+        res.push_str(&dec_str);
+    } else {
+        let (int_part, frac_part) = dec_str.split_at(dec_str.len() - digits);
+        res.push_str(int_part);
+        res.push('.');
+        res.push_str(frac_part);
+    }
+    res
+}
+
+/// Produce the next decimal digit of remainder / denominator and update the remainder
+fn next_digit(remainder: &mut BigUInt, denominator: &BigUInt) -> char {
+    let (digit, modulo) = remainder.mul_with(&BigUInt::from(10u32)).div_mod(denominator);
+    *remainder = modulo;
+    char::from(b'0' + digit.to_u64().expect("Unexpected big decimal digit") as u8)
+}
+
+impl Rational {
+    /// Format the Rational as a decimal number with a fixed number of fractional digits.
+    ///
+    /// # Arguments
+    /// * digits - the number of digits after the decimal point
+    /// * mode - the rounding mode applied to the last digit
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Rational, RoundingMode};
+    /// let rat = Rational::from((-2, 3));
+    /// assert_eq!(rat.to_decimal_string(3, RoundingMode::HalfEven), "-0.667");
+    /// assert_eq!(rat.to_decimal_string(3, RoundingMode::TowardZero), "-0.666");
+    /// ```
+    pub fn to_decimal_string(&self, digits: usize, mode: RoundingMode) -> String {
+        let (mut scaled, remainder) = self.numerator.mul_with(&pow10(digits)).div_mod(&self.denominator);
+        if mode.round_up(self.signed, &scaled, &remainder, &self.denominator) {
+            scaled.add_into(&1u32.into());
+        }
+        insert_decimal_point(self.signed, &scaled, digits)
+    }
+
+    /// Format the Rational in scientific notation like `1.2345e-7`.
+    ///
+    /// # Arguments
+    /// * significant_digits - the number of significant digits to display, at least 1
+    /// * mode - the rounding mode applied to the last digit
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Rational, RoundingMode};
+    /// let rat = Rational::from((1, 3000));
+    /// assert_eq!(rat.to_scientific_string(4, RoundingMode::HalfEven), "3.333e-4");
+    /// ```
+    pub fn to_scientific_string(&self, significant_digits: usize, mode: RoundingMode) -> String {
+        assert!(significant_digits > 0, "at least one significant digit is required");
+        let (mantissa, exponent) = if self.is_zero() {
+            (BigUInt::new(), 0)
+        } else {
+            let mut exponent = self.decimal_exponent();
+            // scale to significant_digits digits before the decimal point
+            let shift = significant_digits as i64 - 1 - exponent;
+            let (numerator, denominator) = if shift >= 0 {
+                (self.numerator.mul_with(&pow10(shift as usize)), self.denominator.clone())
+            } else {
+                (self.numerator.clone(), self.denominator.mul_with(&pow10(shift.unsigned_abs() as usize)))
+            };
+            let (mut mantissa, remainder) = numerator.div_mod(&denominator);
+            if mode.round_up(self.signed, &mantissa, &remainder, &denominator) {
+                mantissa.add_into(&1u32.into());
+                if mantissa == pow10(significant_digits) {
+                    // rounded up to the next power of ten
+                    mantissa = pow10(significant_digits - 1);
+                    exponent += 1;
+                }
+            }
+            (mantissa, exponent)
+        };
+        format!("{}e{}", insert_decimal_point(self.signed, &mantissa, significant_digits - 1), exponent)
+    }
+
+    /// Format the Rational as an exact decimal, enclosing the repeating digits in parentheses.
+    ///
+    /// Terminating decimals are printed without parentheses. Please be aware that the period of
+    /// n/d can be up to d - 1 digits long.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((1, 6)).to_repeating_decimal(), "0.1(6)");
+    /// assert_eq!(Rational::from((-4, 3)).to_repeating_decimal(), "-1.(3)");
+    /// assert_eq!(Rational::from((1, 4)).to_repeating_decimal(), "0.25");
+    /// ```
+    pub fn to_repeating_decimal(&self) -> String {
+        let (int_part, remainder) = self.numerator.div_mod(&self.denominator);
+        let mut res = String::new();
+        if self.signed && !self.is_zero() {
+            res.push('-');
+        }
+        res.push_str(&int_part.to_dec_string());
+        if remainder.is_zero() {
+            return res;
+        }
+        res.push('.');
+
+        // work on the reduced fraction, its factors 2 and 5 determine the length of the
+        // non repeating part
+        let gcd = remainder.gcd(&self.denominator);
+        let mut remainder = remainder.div_by(&gcd);
+        let denominator = self.denominator.div_by(&gcd);
+
+        let twos = denominator.trailing_zeros() as usize;
+        let mut fives = 0usize;
+        let mut work = denominator.clone();
+        loop {
+            let (quotient, modulo) = work.div_mod(&BigUInt::from(5u32));
+            if !modulo.is_zero() {
+                break;
+            }
+            work = quotient;
+            fives += 1;
+        }
+
+        for _ in 0..usize::max(twos, fives) {
+            res.push(next_digit(&mut remainder, &denominator));
+        }
+
+        if !remainder.is_zero() {
+            // from here on the digits are purely periodic, the period ends when the
+            // remainder comes back to its start value
+            let start = remainder.clone();
+            res.push('(');
+            loop {
+                res.push(next_digit(&mut remainder, &denominator));
+                if remainder == start {
+                    break;
+                }
+            }
+            res.push(')');
+        }
+        res
+    }
+
+    /// Find e so that 10^e <= |self| < 10^(e+1), self must not be zero
+    fn decimal_exponent(&self) -> i64 {
+        // estimate from the bit lengths, log10(2) ~ 0.30103, then correct the estimate
+        let bits = self.numerator.length() as i64 - self.denominator.length() as i64;
+        let mut exponent = (bits as f64 * std::f64::consts::LOG10_2).floor() as i64;
+        loop {
+            let power = pow10(exponent.unsigned_abs() as usize);
+            let (numerator, denominator) = if exponent >= 0 {
+                (self.numerator.clone(), self.denominator.mul_with(&power))
+            } else {
+                (self.numerator.mul_with(&power), self.denominator.clone())
+            };
+            // compare |self| / 10^e to 1 and 10
+            if numerator < denominator {
+                exponent -= 1;
+            } else if numerator >= denominator.mul_with(&BigUInt::from(10u32)) {
+                exponent += 1;
+            } else {
+                return exponent;
+            }
+        }
+    }
+}
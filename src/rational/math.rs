@@ -1,18 +1,15 @@
 use super::Rational;
 use std::cmp::Ordering;
-use crate::BigUInt;
+use crate::{BigInt, BigUInt, RoundingMode};
 
 use lazy_static::lazy_static;
 
 
 lazy_static! {
     static ref  MAX_MANTISSA: f64 = 2.0f64.powi(f64::MANTISSA_DIGITS as i32);
-    static ref  EPSILON: Rational = Rational::from_f64(f64::EPSILON);
     static ref TOLERANCE: Rational = Rational::from_f64(10.0 / 2.0f64.powi(f64::MANTISSA_DIGITS as i32));
 }
 
-const SQRT_MAX_ITERATIONS: usize = 100;
-
 impl Rational {
     pub fn to_f64(&self) -> Result<f64,String> {
         let numerator = self.numerator.to_f64()?;
@@ -299,79 +296,252 @@ impl Rational {
         }
     }
 
-    pub fn powi(&self, power: u32) -> Rational {
-        let mut res = self.clone();
-        for _ in 1..power {
-            res.mul_into(self);
+    /// Raise self to the given power, a negative power yields the reciprocal.
+    ///
+    /// `x.powi(0)` is 1 for any x.
+    ///
+    /// # Panics
+    /// Panics if self is zero and power is negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((2, 3)).powi(3), Rational::from((8, 27)));
+    /// assert_eq!(Rational::from((-1, 2)).powi(-3), Rational::from(-8));
+    /// assert_eq!(Rational::from((-1, 2)).powi(0), Rational::from(1));
+    /// ```
+    pub fn powi<T: Into<i64>>(&self, power: T) -> Rational {
+        let power: i64 = power.into();
+        let magnitude = power.unsigned_abs();
+        let mut res = if let Ok(small) = u32::try_from(magnitude) {
+            Rational::with_sign(
+                self.signed && (small & 0x1) == 0x1,
+                self.numerator.powi(small),
+                self.denominator.powi(small))
+        } else {
+            self.pow_big(&BigUInt::from(magnitude), usize::MAX)
+                .expect("Unexpected error raising Rational to a power")
+        };
+        if power < 0 {
+            assert!(!res.is_zero(), "attempt to raise zero to a negative power");
+            res.invert_into();
         }
         res
     }
 
+    /// Raise self to a power given as BigUInt, failing if numerator or denominator of the
+    /// result would exceed max_bits bits. See [BigUInt::pow_big].
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigUInt, Rational};
+    /// let rat = Rational::from((-2, 3));
+    /// assert_eq!(rat.pow_big(&BigUInt::from(3u32), 64).unwrap(), Rational::from((-8, 27)));
+    /// assert!(rat.pow_big(&BigUInt::from(100u32), 64).is_err());
+    /// ```
+    pub fn pow_big(&self, exponent: &BigUInt, max_bits: usize) -> Result<Rational, String> {
+        let odd = exponent.iter().last().unwrap_or(false);
+        Ok(Rational::with_sign(
+            self.signed && odd,
+            self.numerator.pow_big(exponent, max_bits)?,
+            self.denominator.pow_big(exponent, max_bits)?))
+    }
+
+    /// Round towards zero to an integer value.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((-7, 2)).trunc(), Rational::from(-3));
+    /// ```
     pub fn trunc(&self) -> Rational {
-        match self.numerator.cmp(self.denominator()) {
-            Ordering::Less => Rational::new(),
-            Ordering::Equal => 1u32.into(),
-            Ordering::Greater => {
-                Rational{
-                    signed: self.signed,
-                    numerator: self.numerator.div_by(self.denominator()),
-                    denominator: 1u32.into()
-                }
-            }
-        }
+        self.round(RoundingMode::TowardZero)
     }
 
-    pub fn sqrt(&self) -> Rational {
-        // TODO: optimize
-        // use newton's algorithm to solve 'x^2 - self = 0'
-        // https://en.wikipedia.org/wiki/Newton%27s_method
-
-        // Find a good starting value
-        // see https://en.wikipedia.org/wiki/Methods_of_computing_square_roots - Binary Estimates
-        // a) Don't worry about it, if self is small (<= 2^3)
-        // b) Otherwise S => a * 2^2n 1 <= a <= 10 [binary]
-
-        let mut x0: Rational = if self.numerator.length() > self.denominator.length() + 2 {
-            // numerator is about 4 times denominator
-            (BigUInt::from(1u32)<<((self.numerator.length() - self.denominator.length()) >> 1)).into()
-        } else {
-            1u32.into()
-        };
-        let mut x1: Rational = Rational::new();
-
-        let mut x_curr = &mut x0;
-        let mut x_next = &mut x1;
-        let mut found = false;
-
-        for _ in 0..SQRT_MAX_ITERATIONS {
-            let y = x_curr.powi(2).sub_from(self);
-            // left shift 1 of numerator instead of multiplication
-            // TODO: gcd reduction on y_prime ?
-            let y_prime = Rational{
-                signed: x_curr.signed,
-                numerator: x_curr.numerator.shift_left(1),
-                denominator: x_curr.denominator.clone()
-            };
+    /// Round towards negative infinity to an integer value.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((-7, 2)).floor(), Rational::from(-4));
+    /// ```
+    pub fn floor(&self) -> Rational {
+        self.round(RoundingMode::Floor)
+    }
 
-            if y_prime.abs() < *EPSILON {
-                panic!("Rational::sqrt() is not converging - y_prime is too small");
-            }
+    /// Round towards positive infinity to an integer value.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((-7, 2)).ceil(), Rational::from(-3));
+    /// ```
+    pub fn ceil(&self) -> Rational {
+        self.round(RoundingMode::Ceiling)
+    }
 
-            *x_next = x_curr.sub_from( &(y/y_prime));
+    /// Round to an integer value using the given rounding mode.
+    ///
+    /// # Arguments
+    /// * mode - the rounding mode
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{Rational, RoundingMode};
+    /// let rat = Rational::from((-5, 2));
+    /// assert_eq!(rat.round(RoundingMode::HalfEven), Rational::from(-2));
+    /// assert_eq!(rat.round(RoundingMode::HalfUp), Rational::from(-2));
+    /// assert_eq!(rat.round(RoundingMode::HalfAwayFromZero), Rational::from(-3));
+    /// ```
+    pub fn round(&self, mode: RoundingMode) -> Rational {
+        self.to_integer(mode).into()
+    }
 
-            if x_curr.sub_from(x_next).abs() <= *TOLERANCE {
-                found = true;
-                // eprintln!("Rational::sqrt() found after {} iterations", idx);
-                break;
-            }
+    /// The fractional part of self, self - self.trunc(), carrying the sign of self.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from((-7, 2)).fract(), Rational::from((-1, 2)));
+    /// ```
+    pub fn fract(&self) -> Rational {
+        self.sub_from(&self.trunc())
+    }
 
-            std::mem::swap(&mut x_curr, &mut x_next);
+    /// Round to an integer value using the given rounding mode and return it as a BigInt.
+    ///
+    /// # Arguments
+    /// * mode - the rounding mode
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigInt, Rational, RoundingMode};
+    /// assert_eq!(Rational::from((7, 3)).to_integer(RoundingMode::Ceiling), BigInt::from(3));
+    /// ```
+    pub fn to_integer(&self, mode: RoundingMode) -> BigInt {
+        let (mut quotient, remainder) = self.numerator.div_mod(&self.denominator);
+        if mode.round_up(self.signed, &quotient, &remainder, &self.denominator) {
+            quotient.add_into(&1u32.into());
+        }
+        let mut res = BigInt::from(quotient);
+        if self.signed && !res.is_zero() {
+            res.set_negative();
         }
+        res
+    }
+
+    /// Divide self by an integer, returning the integer quotient rounded towards zero and the
+    /// remainder, so that self == quotient * divisor + remainder.
+    ///
+    /// The remainder carries the sign of self like the % operator on primitive integers.
+    ///
+    /// # Arguments
+    /// * divisor - the integer divisor, must not be zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::{BigInt, Rational};
+    /// // split 100.05 into shares of 3
+    /// let amount = Rational::from((2001, 20));
+    /// let (shares, rest) = amount.div_rem(&BigInt::from(3));
+    /// assert_eq!(shares, BigInt::from(33));
+    /// assert_eq!(rest, Rational::from((21, 20)));
+    /// ```
+    pub fn div_rem(&self, divisor: &BigInt) -> (BigInt, Rational) {
+        assert!(!divisor.is_zero(), "Division by zero");
+        let divisor = Rational::from(divisor.clone());
+        let quotient = self.div_by(&divisor).to_integer(RoundingMode::TowardZero);
+        let remainder = self.sub_from(&divisor.mul_by(&quotient.clone().into()));
+        (quotient, remainder)
+    }
 
-        if !found {
-            panic!("Rational::sqrt({}) is not converging - too many iterations", self);
+    /// Calculate the square root with a fixed tolerance of about 10 * 2^-53.
+    ///
+    /// Panics if self is negative, see Rational::sqrt_with_precision for a non panicking
+    /// version with a configurable error bound.
+    pub fn sqrt(&self) -> Rational {
+        self.sqrt_with_precision(&TOLERANCE)
+            .expect("Rational::sqrt() failed")
+    }
+
+    /// Calculate the square root with an error no larger than tolerance.
+    ///
+    /// Square roots of perfect squares are returned exactly, all other results are dyadic
+    /// fractions with a denominator of about 1 / tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// The square root or an error if self is negative or tolerance is not positive
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// assert_eq!(Rational::from((9, 4)).sqrt_with_precision(&tolerance), Ok(Rational::from((3, 2))));
+    /// let root = Rational::from(2).sqrt_with_precision(&tolerance).expect("sqrt failed");
+    /// assert!((root.mul_by(&root) - Rational::from(2)).abs() < Rational::from((3, 1000000)));
+    /// ```
+    pub fn sqrt_with_precision(&self, tolerance: &Rational) -> Result<Rational, String> {
+        self.nth_root(2, tolerance)
+    }
+
+    /// Calculate the n-th root with an error no larger than tolerance.
+    ///
+    /// Roots of perfect powers are returned exactly, all other results are dyadic fractions with
+    /// a denominator of about 1 / tolerance. Odd roots of negative values are negative.
+    ///
+    /// # Arguments
+    /// * n - the degree of the root, must not be zero
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// The n-th root or an error if n is zero, an even root of a negative value is requested or
+    /// tolerance is not positive
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// assert_eq!(Rational::from((-27, 8)).nth_root(3, &tolerance), Ok(Rational::from((-3, 2))));
+    /// assert!(Rational::from(-4).nth_root(2, &tolerance).is_err());
+    /// ```
+    pub fn nth_root(&self, n: u32, tolerance: &Rational) -> Result<Rational, String> {
+        if n == 0 {
+            return Err(String::from("the zeroth root is undefined"));
+        }
+        if self.signed && !self.is_zero() && n & 1 == 0 {
+            return Err(format!("even root of the negative value {}", self));
+        }
+        let bits = Rational::tolerance_bits(tolerance)?;
+
+        let gcd = self.numerator.gcd(&self.denominator);
+        let numerator = self.numerator.div_by(&gcd);
+        let denominator = self.denominator.div_by(&gcd);
+
+        let num_root = numerator.nth_root(n);
+        let den_root = denominator.nth_root(n);
+        if num_root.powi(n) == numerator && den_root.powi(n) == denominator {
+            // perfect power
+            return Ok(Rational::with_sign(self.signed, num_root, den_root));
         }
 
-        (*x_next).clone()
+        // With m = floor(self * 2^(n * bits)) and r = floor(m^(1/n)):
+        // r <= 2^bits * self^(1/n) < r + 1, so r / 2^bits is off by less than 2^-bits
+        let scaled = numerator.shift_left(n as usize * bits).div_by(&denominator);
+        Ok(Rational::reduced(self.signed, scaled.nth_root(n), BigUInt::from(1u32) << bits))
+    }
+
+    /// Find the number of bits b so that 2^-b <= tolerance
+    pub(crate) fn tolerance_bits(tolerance: &Rational) -> Result<usize, String> {
+        if tolerance.is_zero() || tolerance.signed {
+            Err(format!("tolerance must be greater than zero, got {}", tolerance))
+        } else if tolerance.numerator >= tolerance.denominator {
+            Ok(0)
+        } else {
+            // numerator >= 2^(len(numerator) - 1) and denominator < 2^len(denominator)
+            Ok(tolerance.denominator.length() + 1 - tolerance.numerator.length())
+        }
     }
 }
@@ -0,0 +1,146 @@
+use std::str::FromStr;
+
+use crate::BigUInt;
+use super::Rational;
+use super::pow10;
+
+impl Rational {
+    /// The largest absolute decimal exponent accepted by Rational::from_dec_str
+    pub const DEFAULT_MAX_EXPONENT: u32 = 10000;
+
+    /// Parse a Rational from a string, see Rational::from_dec_str_with_max_exponent for the
+    /// accepted formats. Decimal exponents are limited to Rational::DEFAULT_MAX_EXPONENT.
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let rat = Rational::from_dec_str("-3.1e-7").expect("invalid rational");
+    /// assert_eq!(rat, Rational::from((-31i64, 100000000i64)));
+    /// ```
+    pub fn from_dec_str(src: &str) -> Result<Rational, String> {
+        Rational::from_dec_str_with_max_exponent(src, Rational::DEFAULT_MAX_EXPONENT)
+    }
+
+    /// Parse a Rational from a string, the result is exact and reduced.
+    ///
+    /// Accepted formats, all with an optional leading sign and surrounding whitespace:
+    /// * decimals with an optional exponent - `1.25`, `.5`, `-3.1e-7`, `2.5E+2`
+    /// * repeating decimals with the period in parentheses - `0.(3)`, `1.2(34)`
+    /// * fractions - `3/4`
+    /// * mixed numbers with a proper fraction - `1 1/2`
+    ///
+    /// # Arguments
+    /// * src - the string to parse
+    /// * max_exponent - the largest absolute exponent accepted, this protects against
+    ///   input like `1e999999999` allocating huge amounts of memory
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// assert_eq!(Rational::from_dec_str_with_max_exponent("0.(3)", 10), Ok(Rational::from((1, 3))));
+    /// assert_eq!(Rational::from_dec_str_with_max_exponent("-1 1/2", 10), Ok(Rational::from((-3, 2))));
+    /// assert!(Rational::from_dec_str_with_max_exponent("1e11", 10).is_err());
+    /// ```
+    pub fn from_dec_str_with_max_exponent(src: &str, max_exponent: u32) -> Result<Rational, String> {
+        let src = src.trim();
+        let (signed, body) = if let Some(body) = src.strip_prefix('-') {
+            (true, body)
+        } else if let Some(body) = src.strip_prefix('+') {
+            (false, body)
+        } else {
+            (false, src)
+        };
+
+        if body.is_empty() {
+            return Err(format!("no digits found in '{}'", src));
+        }
+
+        if let Some((left, denominator)) = body.split_once('/') {
+            let denominator = BigUInt::from_dec_str(denominator)?;
+            if denominator.is_zero() {
+                return Err(String::from("division by zero"));
+            }
+            let mut parts = left.split_whitespace();
+            let numerator = match (parts.next(), parts.next(), parts.next()) {
+                (Some(numerator), None, None) => BigUInt::from_dec_str(numerator)?,
+                (Some(whole), Some(numerator), None) => {
+                    // mixed number
+                    let numerator = BigUInt::from_dec_str(numerator)?;
+                    if numerator >= denominator {
+                        return Err(format!("the fraction of the mixed number '{}' is not proper", src));
+                    }
+                    BigUInt::from_dec_str(whole)?.mul_with(&denominator).add_to(&numerator)
+                }
+                _ => return Err(format!("invalid fraction '{}'", src)),
+            };
+            Ok(Rational::reduced(signed, numerator, denominator))
+        } else {
+            let (mantissa, exponent) = match body.find(['e', 'E']) {
+                Some(pos) => (&body[..pos], parse_exponent(&body[pos + 1..], max_exponent)?),
+                None => (body, 0),
+            };
+
+            let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+            let (non_repeating, repeating) = match frac_part.split_once('(') {
+                Some((non_repeating, rest)) => match rest.strip_suffix(')') {
+                    Some(repeating) if !repeating.is_empty() => (non_repeating, repeating),
+                    _ => return Err(format!("invalid repeating decimal '{}'", src)),
+                },
+                None => (frac_part, ""),
+            };
+
+            if int_part.is_empty() && non_repeating.is_empty() && repeating.is_empty() {
+                return Err(format!("no digits found in '{}'", src));
+            }
+
+            // value = int_part.non_repeating + repeating / (10^len(non_repeating) * (10^len(repeating) - 1))
+            let digits = format!("{}{}", int_part, non_repeating);
+            let mut numerator = if digits.is_empty() {
+                BigUInt::new()
+            } else {
+                BigUInt::from_dec_str(&digits)?
+            };
+            let mut denominator = pow10(non_repeating.len());
+            if !repeating.is_empty() {
+                let repeating_digits = BigUInt::from_dec_str(repeating)?;
+                let period = pow10(repeating.len()).sub_from(&1u32.into());
+                numerator = numerator.mul_with(&period).add_to(&repeating_digits);
+                denominator.mul_into(&period);
+            }
+
+            if exponent >= 0 {
+                numerator.mul_into(&pow10(exponent as usize));
+            } else {
+                denominator.mul_into(&pow10(exponent.unsigned_abs() as usize));
+            }
+            Ok(Rational::reduced(signed, numerator, denominator))
+        }
+    }
+}
+
+/// Parse a decimal exponent and make sure it does not exceed max_exponent
+fn parse_exponent(src: &str, max_exponent: u32) -> Result<i64, String> {
+    let (negative, digits) = if let Some(digits) = src.strip_prefix('-') {
+        (true, digits)
+    } else if let Some(digits) = src.strip_prefix('+') {
+        (false, digits)
+    } else {
+        (false, src)
+    };
+    let exponent = BigUInt::from_dec_str(digits)?;
+    match exponent.to_u64() {
+        Some(exponent) if exponent <= max_exponent as u64 => {
+            Ok(if negative { -(exponent as i64) } else { exponent as i64 })
+        }
+        _ => Err(format!("exponent {}{} exceeds the maximum exponent {}", if negative { "-" } else { "" },
+                         digits, max_exponent)),
+    }
+}
+
+impl FromStr for Rational {
+    type Err = String;
+
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        Rational::from_dec_str(src)
+    }
+}
@@ -1,5 +1,5 @@
 use super::Rational;
-use crate::BigUInt;
+use crate::{BigInt, BigUInt, RoundingMode};
 
 #[test]
 fn test_rat_from() {
@@ -69,3 +69,327 @@ fn test_rat_sub() {
     let rat2: Rational = (-3i32).into();
     assert_eq!(rat1 - rat2,13u32.into());
 }
+
+#[test]
+fn test_limit_denominator() {
+    let pi = Rational::from((3141592653589793u64, 1000000000000000u64));
+    assert_eq!(pi.limit_denominator(&10u32.into()), (22u32, 7u32).into());
+    assert_eq!(pi.limit_denominator(&100u32.into()), (311u32, 99u32).into());
+    assert_eq!(pi.limit_denominator(&1000u32.into()), (355u32, 113u32).into());
+
+    let neg_pi = Rational::from((-3141592653589793i64, 1000000000000000i64));
+    assert_eq!(neg_pi.limit_denominator(&10u32.into()), (-22i32, 7i32).into());
+
+    // ties go to the convergent like in python
+    let rat = Rational::from((5u32, 4u32));
+    assert_eq!(rat.limit_denominator(&2u32.into()), 1u32.into());
+    assert_eq!(rat.limit_denominator(&4u32.into()), rat);
+
+    // non reduced input that is exactly representable
+    let rat = Rational::from((2u32, 4u32));
+    assert_eq!(rat.limit_denominator(&3u32.into()), (1u32, 2u32).into());
+}
+
+#[test]
+fn test_simplest_in_range() {
+    let lo = Rational::from((31u32, 100u32));
+    let hi = Rational::from((32u32, 100u32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), (5u32, 16u32).into());
+
+    let lo = Rational::from((-32i32, 100i32));
+    let hi = Rational::from((-31i32, 100i32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), (-5i32, 16i32).into());
+
+    let lo = Rational::from((-1i32, 3i32));
+    let hi = Rational::from((1i32, 2i32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), Rational::new());
+
+    let lo = Rational::from((3u32, 10u32));
+    let hi = Rational::from((1u32, 3u32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), (1u32, 3u32).into());
+
+    let lo = Rational::from(2u32);
+    let hi = Rational::from((7u32, 2u32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), 2u32.into());
+
+    let lo = Rational::from((5u32, 2u32));
+    let hi = Rational::from((7u32, 2u32));
+    assert_eq!(Rational::simplest_in_range(&lo, &hi), 3u32.into());
+
+    let third = Rational::from((1u32, 3u32));
+    assert_eq!(Rational::simplest_in_range(&third, &third), third);
+}
+
+#[test]
+fn test_to_decimal_string() {
+    let rat = Rational::from((2u32, 3u32));
+    assert_eq!(rat.to_decimal_string(3, RoundingMode::HalfEven), "0.667");
+    assert_eq!(rat.to_decimal_string(3, RoundingMode::TowardZero), "0.666");
+    assert_eq!(rat.to_decimal_string(0, RoundingMode::HalfEven), "1");
+
+    let rat = Rational::from((-2i32, 3i32));
+    assert_eq!(rat.to_decimal_string(3, RoundingMode::Floor), "-0.667");
+    assert_eq!(rat.to_decimal_string(3, RoundingMode::Ceiling), "-0.666");
+    assert_eq!(rat.to_decimal_string(3, RoundingMode::AwayFromZero), "-0.667");
+
+    let rat = Rational::from((1u32, 8u32));
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfEven), "0.12");
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfUp), "0.13");
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfDown), "0.12");
+    assert_eq!(rat.to_decimal_string(5, RoundingMode::HalfEven), "0.12500");
+
+    let rat = Rational::from((-1i32, 8i32));
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfUp), "-0.12");
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfDown), "-0.13");
+    assert_eq!(rat.to_decimal_string(2, RoundingMode::HalfAwayFromZero), "-0.13");
+    assert_eq!(rat.to_decimal_string(0, RoundingMode::HalfEven), "0");
+
+    assert_eq!(Rational::from((5u32, 2u32)).to_decimal_string(0, RoundingMode::HalfEven), "2");
+    assert_eq!(Rational::from((7u32, 2u32)).to_decimal_string(0, RoundingMode::HalfEven), "4");
+    assert_eq!(Rational::from((12345u32, 100u32)).to_decimal_string(1, RoundingMode::HalfEven), "123.4");
+    assert_eq!(Rational::from((1u32, 3000u32)).to_decimal_string(6, RoundingMode::HalfEven), "0.000333");
+}
+
+#[test]
+fn test_to_scientific_string() {
+    assert_eq!(Rational::from((1u32, 3u32)).to_scientific_string(4, RoundingMode::HalfEven), "3.333e-1");
+    assert_eq!(Rational::from(123456u32).to_scientific_string(3, RoundingMode::HalfEven), "1.23e5");
+    assert_eq!(Rational::from(9999u32).to_scientific_string(2, RoundingMode::HalfEven), "1.0e4");
+    assert_eq!(Rational::from(9999u32).to_scientific_string(2, RoundingMode::TowardZero), "9.9e3");
+    assert_eq!(Rational::from((-31i64, 100000000i64)).to_scientific_string(2, RoundingMode::HalfEven), "-3.1e-7");
+    assert_eq!(Rational::from(7u32).to_scientific_string(1, RoundingMode::HalfEven), "7e0");
+    assert_eq!(Rational::new().to_scientific_string(3, RoundingMode::HalfEven), "0.00e0");
+    assert_eq!(Rational::from(1000u32).to_scientific_string(3, RoundingMode::HalfEven), "1.00e3");
+    assert_eq!(Rational::from((1u32, 1000u32)).to_scientific_string(3, RoundingMode::HalfEven), "1.00e-3");
+}
+
+#[test]
+fn test_to_repeating_decimal() {
+    assert_eq!(Rational::from((1u32, 6u32)).to_repeating_decimal(), "0.1(6)");
+    assert_eq!(Rational::from((-4i32, 3i32)).to_repeating_decimal(), "-1.(3)");
+    assert_eq!(Rational::from((1u32, 4u32)).to_repeating_decimal(), "0.25");
+    assert_eq!(Rational::from(3u32).to_repeating_decimal(), "3");
+    assert_eq!(Rational::from((1u32, 7u32)).to_repeating_decimal(), "0.(142857)");
+    assert_eq!(Rational::from((22u32, 7u32)).to_repeating_decimal(), "3.(142857)");
+    assert_eq!(Rational::from((1u32, 12u32)).to_repeating_decimal(), "0.08(3)");
+    // not reduced
+    assert_eq!(Rational::from((2u32, 12u32)).to_repeating_decimal(), "0.1(6)");
+    assert_eq!(Rational::from((3u32, 28u32)).to_repeating_decimal(), "0.10(714285)");
+}
+
+#[test]
+fn test_display_precision() {
+    let rat = Rational::from((1u32, 3u32));
+    assert_eq!(format!("{:.3}", rat), "0.333");
+    assert_eq!(format!("{}", rat), "1/3");
+    assert_eq!(format!("{:.2}", Rational::from((-5i32, 2i32))), "-2.50");
+}
+
+#[test]
+fn test_from_dec_str() {
+    assert_eq!(Rational::from_dec_str("1.25"), Ok((5u32, 4u32).into()));
+    assert_eq!(Rational::from_dec_str("-3.1e-7"), Ok((-31i64, 100000000i64).into()));
+    assert_eq!(Rational::from_dec_str("0.(3)"), Ok((1u32, 3u32).into()));
+    assert_eq!(Rational::from_dec_str("1.2(34)"), Ok((611u32, 495u32).into()));
+    assert_eq!(Rational::from_dec_str("-0.1(6)"), Ok((-1i32, 6i32).into()));
+    assert_eq!(Rational::from_dec_str("1 1/2"), Ok((3u32, 2u32).into()));
+    assert_eq!(Rational::from_dec_str("-1  1/2"), Ok((-3i32, 2i32).into()));
+    assert_eq!(Rational::from_dec_str("6/8"), Ok((3u32, 4u32).into()));
+    assert_eq!(Rational::from_dec_str(" +42 "), Ok(42u32.into()));
+    assert_eq!(Rational::from_dec_str(".5"), Ok((1u32, 2u32).into()));
+    assert_eq!(Rational::from_dec_str("5."), Ok(5u32.into()));
+    assert_eq!(Rational::from_dec_str("1e3"), Ok(1000u32.into()));
+    assert_eq!(Rational::from_dec_str("2.5E+2"), Ok(250u32.into()));
+    assert_eq!(Rational::from_dec_str("-0.000"), Ok(Rational::new()));
+    assert_eq!("0.125".parse::<Rational>(), Ok((1u32, 8u32).into()));
+
+    // the result must round trip through the decimal formatting
+    let rat = Rational::from_dec_str("123456789012345678901234567890.0987654321").expect("failed to parse");
+    assert_eq!(rat.to_decimal_string(10, RoundingMode::HalfEven), "123456789012345678901234567890.0987654321");
+    let rat = Rational::from_dec_str("3.(142857)").expect("failed to parse");
+    assert_eq!(rat, (22u32, 7u32).into());
+    assert_eq!(rat.to_repeating_decimal(), "3.(142857)");
+
+    for invalid in ["", "-", "abc", "1/0", "1.2.3", "0.(3", "0.()", "--1", "1/-2", "1 2 3/4", "1 5/2", "1 2/2", "1e", "e5",
+        "(3)"] {
+        assert!(Rational::from_dec_str(invalid).is_err(), "'{}' should not parse", invalid);
+    }
+
+    assert!(Rational::from_dec_str("1e100000").is_err());
+    assert!(Rational::from_dec_str("1e-100000000000000000000000").is_err());
+    assert_eq!(Rational::from_dec_str_with_max_exponent("1e4", 4), Ok(10000u32.into()));
+    assert!(Rational::from_dec_str_with_max_exponent("1e5", 4).is_err());
+}
+
+#[test]
+fn test_rat_rounding() {
+    // trunc used to return +1 for -1
+    assert_eq!(Rational::from(-1i32).trunc(), (-1i32).into());
+    assert_eq!(Rational::from(-1i32).floor(), (-1i32).into());
+    assert_eq!(Rational::from(1u32).ceil(), 1u32.into());
+    assert_eq!(Rational::from((1u32, 3u32)).trunc(), Rational::new());
+
+    let rat = Rational::from((-3i32, 2i32));
+    assert_eq!(rat.trunc(), (-1i32).into());
+    assert_eq!(rat.floor(), (-2i32).into());
+    assert_eq!(rat.ceil(), (-1i32).into());
+    assert_eq!(rat.fract(), (-1i32, 2i32).into());
+
+    let rat = Rational::from((-1i32, 3i32));
+    assert_eq!(rat.ceil(), Rational::new());
+    assert!(!rat.ceil().is_negative());
+    assert_eq!(rat.floor(), (-1i32).into());
+
+    let rat = Rational::from((5u32, 2u32));
+    assert_eq!(rat.round(RoundingMode::HalfEven), 2u32.into());
+    assert_eq!(rat.round(RoundingMode::HalfUp), 3u32.into());
+    assert_eq!(rat.round(RoundingMode::HalfDown), 2u32.into());
+    assert_eq!(rat.round(RoundingMode::HalfAwayFromZero), 3u32.into());
+
+    let rat = Rational::from((-5i32, 2i32));
+    assert_eq!(rat.round(RoundingMode::HalfEven), (-2i32).into());
+    assert_eq!(rat.round(RoundingMode::HalfUp), (-2i32).into());
+    assert_eq!(rat.round(RoundingMode::HalfDown), (-3i32).into());
+    assert_eq!(rat.round(RoundingMode::HalfAwayFromZero), (-3i32).into());
+    assert_eq!(Rational::from((-7i32, 2i32)).round(RoundingMode::HalfEven), (-4i32).into());
+    assert_eq!(Rational::from((-8i32, 3i32)).round(RoundingMode::HalfUp), (-3i32).into());
+
+    assert_eq!(Rational::from((7u32, 3u32)).to_integer(RoundingMode::Ceiling), BigInt::from(3));
+    assert_eq!(Rational::from((-7i32, 3i32)).to_integer(RoundingMode::AwayFromZero), BigInt::from(-3));
+    assert_eq!(Rational::from((-7i32, 3i32)).to_integer(RoundingMode::TowardZero), BigInt::from(-2));
+}
+
+#[test]
+fn test_rat_div_rem() {
+    let (quotient, remainder) = Rational::from((7u32, 2u32)).div_rem(&BigInt::from(2));
+    assert_eq!(quotient, BigInt::from(1));
+    assert_eq!(remainder, (3u32, 2u32).into());
+
+    let (quotient, remainder) = Rational::from((-7i32, 2i32)).div_rem(&BigInt::from(2));
+    assert_eq!(quotient, BigInt::from(-1));
+    assert_eq!(remainder, (-3i32, 2i32).into());
+
+    let (quotient, remainder) = Rational::from((7u32, 2u32)).div_rem(&BigInt::from(-2));
+    assert_eq!(quotient, BigInt::from(-1));
+    assert_eq!(remainder, (3u32, 2u32).into());
+
+    let (quotient, remainder) = Rational::from(9u32).div_rem(&BigInt::from(3));
+    assert_eq!(quotient, BigInt::from(3));
+    assert!(remainder.is_zero());
+}
+
+#[test]
+fn test_rat_nth_root() {
+    let tolerance = Rational::from((1u32, 1000000u32));
+    assert_eq!(Rational::from((9u32, 4u32)).sqrt_with_precision(&tolerance), Ok((3u32, 2u32).into()));
+    assert_eq!(Rational::from((18u32, 8u32)).sqrt_with_precision(&tolerance), Ok((3u32, 2u32).into()));
+    assert_eq!(Rational::new().sqrt_with_precision(&tolerance), Ok(Rational::new()));
+    assert_eq!(Rational::from((-27i32, 8i32)).nth_root(3, &tolerance), Ok((-3i32, 2i32).into()));
+    assert!(Rational::from(-4i32).sqrt_with_precision(&tolerance).is_err());
+    assert!(Rational::from(4u32).nth_root(0, &tolerance).is_err());
+    assert!(Rational::from(2u32).sqrt_with_precision(&Rational::new()).is_err());
+    assert!(Rational::from(2u32).sqrt_with_precision(&(-1i32).into()).is_err());
+
+    // the result is below the root by less than the tolerance
+    let tolerance = Rational::from_dec_str("1e-40").expect("invalid tolerance");
+    for (value, degree) in [(2u32, 2u32), (3, 2), (1000001, 2), (7, 5), (2, 3)] {
+        let value = Rational::from(value);
+        let root = value.nth_root(degree, &tolerance).expect("root failed");
+        assert!(root.powi(degree) <= value);
+        assert!(root.add_to(&tolerance).powi(degree) > value);
+    }
+
+    let value = Rational::from((2u32, 3u32));
+    let root = value.sqrt_with_precision(&tolerance).expect("root failed");
+    assert!(root.powi(2) <= value);
+    assert!(root.add_to(&tolerance).powi(2) > value);
+
+    // a large tolerance still produces a result within the tolerance
+    let root = Rational::from(10u32).sqrt_with_precision(&5u32.into()).expect("root failed");
+    assert_eq!(root, 3u32.into());
+}
+
+#[test]
+fn test_rat_powi() {
+    assert_eq!(Rational::from((2, 3)).powi(0), Rational::from(1));
+    assert_eq!(Rational::new().powi(0), Rational::from(1));
+    assert_eq!(Rational::from((-2, 3)).powi(3), Rational::from((-8, 27)));
+    assert_eq!(Rational::from((-2, 3)).powi(-2), Rational::from((9, 4)));
+    assert_eq!(Rational::from((-2, 3)).powi(-3i64), Rational::from((-27, 8)));
+    assert_eq!(Rational::from(1).powi(i64::MIN), Rational::from(1));
+    assert_eq!(Rational::from(-1).powi(u32::MAX), Rational::from(-1));
+
+    let rat = Rational::from((3, 2));
+    assert_eq!(rat.pow_big(&BigUInt::from(5u32), 8).unwrap(), Rational::from((243, 32)));
+    assert!(rat.pow_big(&BigUInt::from(6u32), 8).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_rat_powi_zero_negative() {
+    Rational::new().powi(-1);
+}
+
+#[test]
+fn test_rat_transcendental() {
+    let tolerance = Rational::from((1, 10)).powi(40);
+    let check = |value: Result<Rational, String>, expected: &str, tolerance: &Rational| {
+        let value = value.expect("calculation failed");
+        let expected = Rational::from_dec_str(expected).expect("invalid reference");
+        assert!((value.sub_from(&expected)).abs() <= *tolerance, "{} != {}", value.to_decimal_string(45, RoundingMode::HalfEven), expected);
+    };
+
+    check(Rational::pi(&tolerance), "3.14159265358979323846264338327950288419716939937510582", &tolerance);
+    check(Rational::e(&tolerance), "2.71828182845904523536028747135266249775724709369995957", &tolerance);
+    check(Rational::ln2(&tolerance), "0.693147180559945309417232121458176568075500134360255254", &tolerance);
+    check(Rational::from(1).sin(&tolerance), "0.841470984807896506652502321630298999622563060798371065", &tolerance);
+    check(Rational::from(1).cos(&tolerance), "0.540302305868139717400936607442976603732310420617922227", &tolerance);
+    check(Rational::from(10).sin(&tolerance), "-0.544021110889369813404747661851377281683643012916223891", &tolerance);
+    check(Rational::from(10).ln(&tolerance), "2.30258509299404568401799145468436420760110148862877297", &tolerance);
+    check(Rational::from(-10).exp(&tolerance), "0.0000453999297624848515355915155605506102379180888", &tolerance);
+    check(Rational::from((1, 2)).atan(&tolerance), "0.463647609000806116214256231461214402028537054286120263", &tolerance);
+    check(Rational::from(-3).atan(&tolerance), "-1.249045772398254425829917077281", &Rational::from((1, 10)).powi(29));
+
+    // exact special cases
+    assert_eq!(Rational::new().exp(&tolerance), Ok(Rational::from(1)));
+    assert_eq!(Rational::from(1).ln(&tolerance), Ok(Rational::new()));
+    assert_eq!(Rational::new().sin(&tolerance), Ok(Rational::new()));
+    assert_eq!(Rational::new().cos(&tolerance), Ok(Rational::from(1)));
+    assert_eq!(Rational::new().atan(&tolerance), Ok(Rational::new()));
+
+    // identities
+    let x = Rational::from((-314, 3));
+    let sin = x.sin(&tolerance).expect("sin failed");
+    let cos = x.cos(&tolerance).expect("cos failed");
+    assert!((sin.powi(2).add_to(&cos.powi(2)) - Rational::from(1)).abs() < Rational::from((1, 10)).powi(39));
+    let x = Rational::from((77, 5));
+    let exp = x.exp(&tolerance).expect("exp failed");
+    check(exp.ln(&tolerance), "15.4", &Rational::from((1, 10)).powi(39));
+    check(Rational::from((1, 3)).ln(&tolerance).map(|ln| ln.mul_by(&Rational::from(-1))), "1.098612288668109691395245236922525704647490557822749451", &tolerance);
+
+    // errors
+    assert!(Rational::new().ln(&tolerance).is_err());
+    assert!(Rational::from(-2).ln(&tolerance).is_err());
+    assert!(Rational::from(1).exp(&Rational::new()).is_err());
+    assert!(Rational::pi(&Rational::from(-1)).is_err());
+}
+
+#[test]
+fn test_rat_reconstruct() {
+    let modulus = BigUInt::from(1u32) << 127;
+    let modulus = modulus.sub_from(&1u32.into());
+    for (num, den) in [(0, 1), (1, 1), (-2, 3), (1234567, 7654321), (-3, 1000000007)] {
+        let value = Rational::from((num, den));
+        let inverse = value.denominator().mod_inverse(&modulus).expect("coprime denominator");
+        let mut residue = value.numerator().mul_with(&inverse).div_mod(&modulus).1;
+        if value.is_negative() && !residue.is_zero() {
+            residue = modulus.sub_from(&residue);
+        }
+        assert_eq!(Rational::reconstruct(&residue, &modulus), Some(value));
+    }
+    // 2^64 + 1 is too large to be recovered modulo 2^127 - 1
+    let large = (BigUInt::from(1u32) << 64).add_to(&1u32.into());
+    assert_eq!(Rational::reconstruct(&large.mul_with(&modulus.sub_from(&1u32.into())).div_mod(&modulus).1, &modulus),
+               None);
+    assert_eq!(Rational::reconstruct(&BigUInt::from(3u32), &BigUInt::from(8u32)), None);
+}
@@ -1,4 +1,4 @@
-use crate::{Rational, BigUInt, BigInt};
+use crate::{Rational, BigUInt, BigInt, RoundingMode};
 use std::fmt::{Debug, Formatter, Display};
 use std::cmp::{Ordering, PartialOrd, Eq};
 
@@ -245,8 +245,12 @@ impl Debug for Rational {
 }
 
 impl Display for Rational {
+    /// Display the Rational as a fraction or, if a precision is given as in `{:.3}`, as a decimal
+    /// number rounded half to even.
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.is_integer() {
+        if let Some(precision) = f.precision() {
+            f.write_str(self.to_decimal_string(precision, RoundingMode::HalfEven).as_str())
+        } else if self.is_integer() {
             write!(f, "{}{}", if self.signed { "-" } else { "" }, self.numerator)
         } else {
             write!(f, "{}{}/{}", if self.signed { "-" } else { "" }, self.numerator, self.denominator)
@@ -0,0 +1,418 @@
+use crate::{BigInt, BigUInt};
+use super::Rational;
+
+// All functions in this module work on fixed point numbers: a BigInt m at scale w stands for
+// m / 2^w. The *_fixed helpers return an m with |value - m / 2^w| <= 2^-w, the public functions
+// choose the scales so that the accumulated error stays within the requested tolerance.
+
+/// Build a BigInt from sign and magnitude, making sure zero is never signed
+fn signed_int(negative: bool, magnitude: BigUInt) -> BigInt {
+    let mut res = BigInt::from(magnitude);
+    if negative && !res.is_zero() {
+        res.set_negative();
+    }
+    res
+}
+
+fn int_from_i64(value: i64) -> BigInt {
+    signed_int(value < 0, BigUInt::from(value.unsigned_abs()))
+}
+
+fn int_from_u64(value: u64) -> BigInt {
+    BigInt::from(BigUInt::from(value))
+}
+
+/// Calculate value * 2^shift
+fn shl(value: &BigInt, shift: usize) -> BigInt {
+    signed_int(value.is_negative(), value.abs().as_unsigned() << shift)
+}
+
+/// Divide num by the positive den, rounding to the nearest integer
+fn div_round(num: &BigInt, den: &BigInt) -> BigInt {
+    let den = den.abs().as_unsigned();
+    let magnitude = (num.abs().as_unsigned() << 1).add_to(&den).div_by(&(den << 1));
+    signed_int(num.is_negative(), magnitude)
+}
+
+/// Convert the fixed point value m / 2^scale to a Rational
+fn fixed_to_rational(m: &BigInt, scale: usize) -> Rational {
+    Rational::reduced(m.is_negative(), m.abs().as_unsigned(), BigUInt::from(1u32) << scale)
+}
+
+/// The number of bits of the integer part of |x|
+fn int_bits(x: &Rational) -> usize {
+    x.numerator.div_by(&x.denominator).length()
+}
+
+/// The state of a binary splitting evaluation.
+///
+/// For the term ratios p(j) / q(j) the partial sum over k = a..b-1 of prod_{j=a}^{k} p(j) / q(j)
+/// is t / q and the product of all ratios from a to b - 1 is p / q.
+struct Split {
+    p: BigInt,
+    q: BigInt,
+    t: BigInt,
+}
+
+impl Split {
+    fn new(from: u64, to: u64, p: &dyn Fn(u64) -> BigInt, q: &dyn Fn(u64) -> BigInt) -> Split {
+        if to - from == 1 {
+            let p = p(from);
+            Split { t: p.clone(), p, q: q(from) }
+        } else {
+            let mid = (from + to) / 2;
+            Split::new(from, mid, p, q).join(&Split::new(mid, to, p, q))
+        }
+    }
+
+    /// Combine the ranges a..m and m..b to a..b
+    fn join(&self, right: &Split) -> Split {
+        Split {
+            p: self.p.mul_with(&right.p),
+            q: self.q.mul_with(&right.q),
+            t: self.t.mul_with(&right.q).add_to(&self.p.mul_with(&right.t)),
+        }
+    }
+}
+
+/// Sum the terms prod_{j=1}^{k} p(j) / q(j) for k = 1..n, returning the sum as t / q.
+///
+/// The ratios |p(j) / q(j)| must not exceed 1/2, so the sum of all omitted terms is no larger
+/// than the last term included. Terms are added until that term is at most 2^-bits.
+fn sum_series(p: &dyn Fn(u64) -> BigInt, q: &dyn Fn(u64) -> BigInt, bits: usize) -> Split {
+    let mut terms = 8;
+    let mut sum = Split::new(1, terms + 1, p, q);
+    while (sum.p.abs().as_unsigned() << bits) > sum.q.abs().as_unsigned() {
+        sum = sum.join(&Split::new(terms + 1, 2 * terms + 1, p, q));
+        terms *= 2;
+    }
+    sum
+}
+
+/// exp(u / v) for |u / v| <= 1 and v > 0
+fn exp_fixed(u: &BigInt, v: &BigInt, w: usize) -> BigInt {
+    // t_k = t_{k-1} * u / (k * v)
+    let sum = sum_series(&|_| u.clone(), &|j| int_from_u64(j).mul_with(v), w + 1);
+    div_round(&shl(&sum.q.add_to(&sum.t), w), &sum.q)
+}
+
+/// sin(u / v) for |u / v| < 1 and v > 0
+fn sin_fixed(u: &BigInt, v: &BigInt, w: usize) -> BigInt {
+    // sin(x) = x * sum (-x^2)^k / (2k + 1)!
+    let u2 = u.mul_with(u);
+    let v2 = v.mul_with(v);
+    let sum = sum_series(&|_| signed_int(true, u2.abs().as_unsigned()),
+                         &|j| int_from_u64(2 * j * (2 * j + 1)).mul_with(&v2), w + 1);
+    div_round(&shl(&u.mul_with(&sum.q.add_to(&sum.t)), w), &v.mul_with(&sum.q))
+}
+
+/// cos(u / v) for |u / v| < 1 and v > 0
+fn cos_fixed(u: &BigInt, v: &BigInt, w: usize) -> BigInt {
+    // cos(x) = sum (-x^2)^k / (2k)!
+    let u2 = u.mul_with(u);
+    let v2 = v.mul_with(v);
+    let sum = sum_series(&|_| signed_int(true, u2.abs().as_unsigned()),
+                         &|j| int_from_u64((2 * j - 1) * 2 * j).mul_with(&v2), w + 1);
+    div_round(&shl(&sum.q.add_to(&sum.t), w), &sum.q)
+}
+
+/// atan(u / v) for 0 <= u <= v
+fn atan_fixed(u: &BigInt, v: &BigInt, w: usize) -> BigInt {
+    // Euler's series: atan(x) = x / (1 + x^2) * sum_k prod_{j=1}^{k} 2j x^2 / ((2j + 1)(1 + x^2)),
+    // the ratios are below x^2 / (1 + x^2) <= 1/2
+    let u2 = u.mul_with(u);
+    let norm = u2.add_to(&v.mul_with(v));
+    let sum = sum_series(&|j| int_from_u64(2 * j).mul_with(&u2),
+                         &|j| int_from_u64(2 * j + 1).mul_with(&norm), w + 1);
+    div_round(&shl(&u.mul_with(v).mul_with(&sum.q.add_to(&sum.t)), w), &norm.mul_with(&sum.q))
+}
+
+/// atanh(u / v) for |u / v| <= 1/3 and v > 0
+fn atanh_fixed(u: &BigInt, v: &BigInt, w: usize) -> BigInt {
+    // atanh(x) = x * sum x^2k / (2k + 1), the ratios are (2j - 1) x^2 / (2j + 1)
+    let u2 = u.mul_with(u);
+    let v2 = v.mul_with(v);
+    let sum = sum_series(&|j| int_from_u64(2 * j - 1).mul_with(&u2),
+                         &|j| int_from_u64(2 * j + 1).mul_with(&v2), w + 1);
+    div_round(&shl(&u.mul_with(&sum.q.add_to(&sum.t)), w), &v.mul_with(&sum.q))
+}
+
+/// pi by Machin's formula pi = 16 atan(1/5) - 4 atan(1/239)
+fn pi_fixed(w: usize) -> BigInt {
+    let one = int_from_u64(1);
+    let a = atan_fixed(&one, &int_from_u64(5), w + 6);
+    let b = atan_fixed(&one, &int_from_u64(239), w + 6);
+    // error: 20 * 2^-(w + 6) from the series and 2^-(w + 1) from rounding
+    div_round(&shl(&a, 4).sub_from(&shl(&b, 2)), &shl(&one, 6))
+}
+
+/// ln(2) = 2 atanh(1/3)
+fn ln2_fixed(w: usize) -> BigInt {
+    let a = atanh_fixed(&int_from_u64(1), &int_from_u64(3), w + 2);
+    div_round(&a, &int_from_u64(2))
+}
+
+impl Rational {
+    /// Calculate pi with an error no larger than tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let pi = Rational::pi(&tolerance).expect("pi failed");
+    /// assert!((pi - Rational::from((314159265, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn pi(tolerance: &Rational) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        Ok(fixed_to_rational(&pi_fixed(bits), bits))
+    }
+
+    /// Calculate Euler's number e with an error no larger than tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let e = Rational::e(&tolerance).expect("e failed");
+    /// assert!((e - Rational::from((271828183, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn e(tolerance: &Rational) -> Result<Rational, String> {
+        Rational::from(1).exp(tolerance)
+    }
+
+    /// Calculate ln(2) with an error no larger than tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let ln2 = Rational::ln2(&tolerance).expect("ln2 failed");
+    /// assert!((ln2 - Rational::from((69314718, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn ln2(tolerance: &Rational) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        Ok(fixed_to_rational(&ln2_fixed(bits), bits))
+    }
+
+    /// Calculate e^self with an error no larger than tolerance.
+    ///
+    /// The argument is reduced to x = n ln(2) + r with |r| <= ln(2) / 2, e^r is summed by binary
+    /// splitting and scaled by 2^n.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// e^self or an error if tolerance is not positive or self is too large
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let exp = Rational::from(-2).exp(&tolerance).expect("exp failed");
+    /// assert!((exp - Rational::from((135335283, 1000000000))).abs() < tolerance);
+    /// ```
+    pub fn exp(&self, tolerance: &Rational) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        if self.is_zero() {
+            return Ok(Rational::from(1));
+        }
+        let u = signed_int(self.signed, self.numerator.clone());
+        let v = BigInt::from(self.denominator.clone());
+
+        // n = round(x / ln(2)), the guess for ln(2) is precise enough to keep |r| close to ln(2) / 2
+        let guess_bits = int_bits(self) + 8;
+        let n = div_round(&shl(&u, guess_bits), &v.mul_with(&ln2_fixed(guess_bits)));
+        let n_i64 = n.to_i64()
+            .ok_or_else(|| format!("argument too large for exp: {}", self))?;
+
+        // e^r is calculated at scale w + 1 and scaled by 2^n, an error of 2^-w in e^r results
+        // in an error of 2^(n - w) in e^x
+        let w = usize::try_from(n_i64 + bits as i64).unwrap_or(0);
+        // r = x - n * ln(2), the error of ln(2) contributes at most |n| e^|r| 2^-ln2_bits
+        let ln2_bits = w + 2 + n.abs().as_unsigned().length();
+        let r_num = shl(&u, ln2_bits).sub_from(&n.mul_with(&ln2_fixed(ln2_bits)).mul_with(&v));
+        let r_den = shl(&v, ln2_bits);
+        let m = exp_fixed(&r_num, &r_den, w + 1);
+
+        let scale = w as i64 + 1 - n_i64;
+        if scale >= 0 {
+            Ok(fixed_to_rational(&m, scale as usize))
+        } else {
+            Ok(fixed_to_rational(&shl(&m, scale.unsigned_abs() as usize), 0))
+        }
+    }
+
+    /// Calculate the natural logarithm with an error no larger than tolerance.
+    ///
+    /// The argument is reduced to x = 2^k m with 2/3 <= m < 4/3, then
+    /// ln(x) = k ln(2) + 2 atanh((m - 1) / (m + 1)).
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// ln(self) or an error if self or tolerance is not positive
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let ln = Rational::from(10).ln(&tolerance).expect("ln failed");
+    /// assert!((ln - Rational::from((230258509, 100000000))).abs() < tolerance);
+    /// assert!(Rational::from(-1).ln(&tolerance).is_err());
+    /// ```
+    pub fn ln(&self, tolerance: &Rational) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        if self.signed || self.is_zero() {
+            return Err(format!("ln is only defined for positive numbers, got {}", self));
+        }
+
+        // m = x / 2^k with 1/2 < m < 2 first, then moved into [2/3, 4/3)
+        let mut k = self.numerator.length() as i64 - self.denominator.length() as i64;
+        let (mut m_num, mut m_den) = if k >= 0 {
+            (self.numerator.clone(), self.denominator.clone() << k as usize)
+        } else {
+            (self.numerator.clone() << k.unsigned_abs() as usize, self.denominator.clone())
+        };
+        let three = BigUInt::from(3u32);
+        if m_num.mul_with(&three) >= (m_den.clone() << 2) {
+            k += 1;
+            m_den <<= 1;
+        } else if m_num.mul_with(&three) < (m_den.clone() << 1) {
+            k -= 1;
+            m_num <<= 1;
+        }
+
+        // |z| = |(m - 1) / (m + 1)| <= 1/5
+        let m_num = BigInt::from(m_num);
+        let m_den = BigInt::from(m_den);
+        let z_num = m_num.sub_from(&m_den);
+        let z_den = m_num.add_to(&m_den);
+
+        // at scale w both k ln(2) and 2 atanh(z) are off by at most 1.5 and 1 units
+        let w = bits + 3;
+        let mut res = atanh_fixed(&z_num, &z_den, w + 1);
+        if k != 0 {
+            let k_bits = k.unsigned_abs().ilog2() as usize + 1;
+            let k_ln2 = int_from_i64(k).mul_with(&ln2_fixed(w + k_bits));
+            res.add_into(&div_round(&k_ln2, &shl(&int_from_u64(1), k_bits)));
+        }
+        Ok(fixed_to_rational(&res, w))
+    }
+
+    /// Calculate the sine with an error no larger than tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// sin(self) or an error if tolerance is not positive or self is too large
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let sin = Rational::from(1).sin(&tolerance).expect("sin failed");
+    /// assert!((sin - Rational::from((84147098, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn sin(&self, tolerance: &Rational) -> Result<Rational, String> {
+        self.sin_cos(tolerance, false)
+    }
+
+    /// Calculate the cosine with an error no larger than tolerance.
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Returns
+    /// cos(self) or an error if tolerance is not positive or self is too large
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let cos = Rational::from(1).cos(&tolerance).expect("cos failed");
+    /// assert!((cos - Rational::from((54030231, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn cos(&self, tolerance: &Rational) -> Result<Rational, String> {
+        self.sin_cos(tolerance, true)
+    }
+
+    fn sin_cos(&self, tolerance: &Rational, cosine: bool) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        if self.is_zero() {
+            return Ok(Rational::from(if cosine { 1 } else { 0 }));
+        }
+        let u = signed_int(self.signed, self.numerator.clone());
+        let v = BigInt::from(self.denominator.clone());
+
+        // k = round(x / (pi / 2)) so that |r| = |x - k pi / 2| is about pi / 4
+        let guess_bits = int_bits(self) + 8;
+        let k = div_round(&shl(&u, guess_bits + 1), &v.mul_with(&pi_fixed(guess_bits)));
+        let k_i64 = k.to_i64()
+            .ok_or_else(|| format!("argument too large for sin / cos: {}", self))?;
+
+        // sin and cos are 1-Lipschitz, so the error of r, |k| 2^-(pi_bits + 1), goes straight into
+        // the result, as does the error of the series
+        let w = bits;
+        let pi_bits = w + k.abs().as_unsigned().length();
+        let r_num = shl(&u, pi_bits + 1).sub_from(&k.mul_with(&pi_fixed(pi_bits)).mul_with(&v));
+        let r_den = shl(&v, pi_bits + 1);
+
+        // sin(r + k pi / 2) and cos(r + k pi / 2) by quadrant
+        let quadrant = k_i64.rem_euclid(4) + if cosine { 1 } else { 0 };
+        let mut res = if quadrant & 1 == 0 {
+            sin_fixed(&r_num, &r_den, w + 1)
+        } else {
+            cos_fixed(&r_num, &r_den, w + 1)
+        };
+        if quadrant & 2 == 2 && !res.is_zero() {
+            res.reverse_sign();
+        }
+        Ok(fixed_to_rational(&res, w + 1))
+    }
+
+    /// Calculate the arc tangent with an error no larger than tolerance.
+    ///
+    /// Arguments larger than 1 in magnitude use atan(x) = pi / 2 - atan(1 / x).
+    ///
+    /// # Arguments
+    /// * tolerance - the maximum error of the result, must be greater than zero
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::Rational;
+    /// let tolerance = Rational::from((1, 1000000));
+    /// let atan = Rational::from(1).atan(&tolerance).expect("atan failed");
+    /// assert!((atan - Rational::from((78539816, 100000000))).abs() < tolerance);
+    /// ```
+    pub fn atan(&self, tolerance: &Rational) -> Result<Rational, String> {
+        let bits = Rational::tolerance_bits(tolerance)?;
+        if self.is_zero() {
+            return Ok(Rational::new());
+        }
+        let u = BigInt::from(self.numerator.clone());
+        let v = BigInt::from(self.denominator.clone());
+        let mut res = if self.numerator <= self.denominator {
+            fixed_to_rational(&atan_fixed(&u, &v, bits), bits)
+        } else {
+            // pi / 2 at scale bits + 1 is pi at scale bits
+            let res = pi_fixed(bits).sub_from(&atan_fixed(&v, &u, bits + 1));
+            fixed_to_rational(&res, bits + 1)
+        };
+        if self.signed && !res.is_zero() {
+            res.signed = true;
+        }
+        Ok(res)
+    }
+}
@@ -0,0 +1,54 @@
+use std::cmp::Ordering;
+
+use crate::BigUInt;
+
+/// The rounding mode applied whenever an exact value has to be cut down to a limited number of
+/// digits or bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards zero, simply dropping the excess digits
+    TowardZero,
+    /// Round away from zero
+    AwayFromZero,
+    /// Round towards negative infinity
+    Floor,
+    /// Round towards positive infinity
+    Ceiling,
+    /// Round to the nearest value, ties go to the even neighbour
+    #[default]
+    HalfEven,
+    /// Round to the nearest value, ties go towards positive infinity
+    HalfUp,
+    /// Round to the nearest value, ties go towards negative infinity
+    HalfDown,
+    /// Round to the nearest value, ties go away from zero
+    HalfAwayFromZero,
+}
+
+impl RoundingMode {
+    /// Decide if a truncated magnitude has to be incremented by one to get the rounded magnitude.
+    ///
+    /// The exact value is (-1)^negative * (quotient + remainder / divisor) with
+    /// 0 <= remainder < divisor.
+    pub(crate) fn round_up(&self, negative: bool, quotient: &BigUInt, remainder: &BigUInt, divisor: &BigUInt) -> bool {
+        if remainder.is_zero() {
+            return false;
+        }
+        match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            _ => match remainder.shift_left(1).cmp(divisor) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match self {
+                    RoundingMode::HalfEven => quotient.is_odd(),
+                    RoundingMode::HalfUp => !negative,
+                    RoundingMode::HalfDown => negative,
+                    _ => true,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Textbook RSA on BigUInt values, as an example and test bed for the big number arithmetic.
+//!
+//! Keys are generated from random probable primes, encryption and signatures work on raw
+//! message representatives without padding, and keys are exchanged in PKCS#1 DER format
+//! (RFC 8017, appendix A.1). Without a padding scheme like OAEP or PSS and without constant
+//! time arithmetic this is not suitable to protect real data.
+
+use std::fmt::{Debug, Formatter};
+
+use rand::Rng;
+
+use crate::BigUInt;
+use crate::number_theory::is_probable_prime;
+use crate::random::RandBigInt;
+
+#[cfg(test)]
+mod test;
+
+mod der;
+
+/// The public exponent of generated keys
+const PUBLIC_EXPONENT: u32 = 65537;
+
+/// The number of Miller–Rabin rounds for prime candidates
+const PRIME_ROUNDS: usize = 32;
+
+/// An RSA public key (n, e)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RsaPublicKey {
+    n: BigUInt,
+    e: BigUInt,
+}
+
+/// An RSA private key with the CRT parameters dp = d mod (p - 1), dq = d mod (q - 1) and
+/// qinv = q^-1 mod p. Its Debug output only shows the public values n and e.
+#[derive(Clone, PartialEq, Eq)]
+pub struct RsaPrivateKey {
+    n: BigUInt,
+    e: BigUInt,
+    d: BigUInt,
+    p: BigUInt,
+    q: BigUInt,
+    dp: BigUInt,
+    dq: BigUInt,
+    qinv: BigUInt,
+}
+
+impl RsaPublicKey {
+    /// Create a public key from modulus and public exponent.
+    ///
+    /// # Returns
+    /// The key or an error if n is even or e is not an odd number in the range 3..n
+    pub fn new(n: BigUInt, e: BigUInt) -> Result<RsaPublicKey, String> {
+        if n.is_even() || n < BigUInt::from(15u32) {
+            return Err(format!("invalid RSA modulus {}", n));
+        }
+        if e.is_even() || e < BigUInt::from(3u32) || e >= n {
+            return Err(format!("invalid RSA public exponent {}", e));
+        }
+        Ok(RsaPublicKey { n, e })
+    }
+
+    #[inline]
+    pub fn n(&self) -> &BigUInt {
+        &self.n
+    }
+
+    #[inline]
+    pub fn e(&self) -> &BigUInt {
+        &self.e
+    }
+
+    /// Encrypt a message representative m < n, calculating m^e mod n (RSAEP).
+    ///
+    /// # Returns
+    /// The ciphertext or an error if m is not less than n
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::rsa::RsaPrivateKey;
+    /// let key = RsaPrivateKey::from_primes(61u32.into(), 53u32.into(), 17u32.into()).unwrap();
+    /// let ciphertext = key.public_key().encrypt(&65u32.into()).unwrap();
+    /// assert_eq!(ciphertext, BigUInt::from(2790u32));
+    /// assert_eq!(key.decrypt(&ciphertext), Ok(BigUInt::from(65u32)));
+    /// ```
+    pub fn encrypt(&self, message: &BigUInt) -> Result<BigUInt, String> {
+        if *message >= self.n {
+            return Err("message representative out of range".to_string());
+        }
+        Ok(message.modpow(&self.e, &self.n))
+    }
+
+    /// Verify a signature s of the message representative m by checking s^e mod n = m (RSAVP1)
+    pub fn verify(&self, message: &BigUInt, signature: &BigUInt) -> bool {
+        *signature < self.n && signature.modpow(&self.e, &self.n) == *message
+    }
+}
+
+impl RsaPrivateKey {
+    /// Generate a key with a modulus of the given number of bits and the public exponent 65537.
+    ///
+    /// # Panics
+    /// Panics if bits is less than 32.
+    pub fn generate<R: Rng + ?Sized>(bits: usize, rng: &mut R) -> RsaPrivateKey {
+        assert!(bits >= 32, "RSA modulus must have at least 32 bits");
+        let e = BigUInt::from(PUBLIC_EXPONENT);
+        loop {
+            // the two top bits of both primes are set, so n has exactly bits bits
+            let p = random_prime(bits - bits / 2, &e, rng);
+            let q = random_prime(bits / 2, &e, rng);
+            if p == q {
+                continue;
+            }
+            if let Ok(key) = RsaPrivateKey::from_primes(p, q, e.clone()) {
+                return key;
+            }
+        }
+    }
+
+    /// Create a key from two distinct primes and the public exponent, with
+    /// d = e^-1 mod lcm(p - 1, q - 1) as in RFC 8017. The primality of p and q is not checked.
+    ///
+    /// # Returns
+    /// The key or an error if p and q are equal or too small or e is not invertible
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::rsa::RsaPrivateKey;
+    /// let key = RsaPrivateKey::from_primes(61u32.into(), 53u32.into(), 17u32.into()).unwrap();
+    /// assert_eq!(*key.public_key().n(), BigUInt::from(3233u32));
+    /// assert_eq!(*key.d(), BigUInt::from(413u32));
+    /// ```
+    pub fn from_primes(p: BigUInt, q: BigUInt, e: BigUInt) -> Result<RsaPrivateKey, String> {
+        if p == q || p < BigUInt::from(3u32) || q < BigUInt::from(3u32) {
+            return Err("RSA primes must be distinct odd primes".to_string());
+        }
+        let public = RsaPublicKey::new(p.mul_with(&q), e)?;
+        let p_minus_1 = p.sub_from(&1u32.into());
+        let q_minus_1 = q.sub_from(&1u32.into());
+        let lambda = p_minus_1.mul_with(&q_minus_1).div_by(&p_minus_1.gcd(&q_minus_1));
+        let d = public.e.mod_inverse(&lambda)
+            .ok_or_else(|| format!("public exponent {} is not invertible", public.e))?;
+        let qinv = q.mod_inverse(&p).ok_or_else(|| "RSA primes must be coprime".to_string())?;
+        Ok(RsaPrivateKey {
+            n: public.n,
+            e: public.e,
+            dp: d.div_mod(&p_minus_1).1,
+            dq: d.div_mod(&q_minus_1).1,
+            d,
+            p,
+            q,
+            qinv,
+        })
+    }
+
+    pub fn public_key(&self) -> RsaPublicKey {
+        RsaPublicKey {
+            n: self.n.clone(),
+            e: self.e.clone(),
+        }
+    }
+
+    #[inline]
+    pub fn d(&self) -> &BigUInt {
+        &self.d
+    }
+
+    #[inline]
+    pub fn p(&self) -> &BigUInt {
+        &self.p
+    }
+
+    #[inline]
+    pub fn q(&self) -> &BigUInt {
+        &self.q
+    }
+
+    /// Decrypt a ciphertext c < n, calculating c^d mod n with the CRT parameters (RSADP).
+    ///
+    /// # Returns
+    /// The message representative or an error if c is not less than n
+    pub fn decrypt(&self, ciphertext: &BigUInt) -> Result<BigUInt, String> {
+        if *ciphertext >= self.n {
+            return Err("ciphertext representative out of range".to_string());
+        }
+        Ok(self.crt_pow(ciphertext))
+    }
+
+    /// Sign a message representative m < n, calculating m^d mod n with the CRT parameters
+    /// (RSASP1).
+    ///
+    /// # Returns
+    /// The signature or an error if m is not less than n
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::BigUInt;
+    /// use simple_big_int::rsa::RsaPrivateKey;
+    /// let key = RsaPrivateKey::from_primes(61u32.into(), 53u32.into(), 17u32.into()).unwrap();
+    /// let signature = key.sign(&123u32.into()).unwrap();
+    /// assert_eq!(signature, BigUInt::from(123u32).modpow(key.d(), &3233u32.into()));
+    /// assert!(key.public_key().verify(&123u32.into(), &signature));
+    /// ```
+    pub fn sign(&self, message: &BigUInt) -> Result<BigUInt, String> {
+        if *message >= self.n {
+            return Err("message representative out of range".to_string());
+        }
+        Ok(self.crt_pow(message))
+    }
+
+    /// Calculate value^d mod n as m2 + q * (qinv * (m1 - m2) mod p) with m1 = value^dp mod p and
+    /// m2 = value^dq mod q
+    fn crt_pow(&self, value: &BigUInt) -> BigUInt {
+        let m1 = value.modpow(&self.dp, &self.p);
+        let m2 = value.modpow(&self.dq, &self.q);
+        let m2_mod_p = m2.div_mod(&self.p).1;
+        let difference = if m1 >= m2_mod_p {
+            m1.sub_from(&m2_mod_p)
+        } else {
+            m1.add_to(&self.p).sub_from(&m2_mod_p)
+        };
+        let h = self.qinv.mul_with(&difference).div_mod(&self.p).1;
+        m2.add_to(&self.q.mul_with(&h))
+    }
+}
+
+impl Debug for RsaPrivateKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RsaPrivateKey")
+            .field("n", &self.n)
+            .field("e", &self.e)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Create a random prime of the given number of bits with its two top bits set and p - 1
+/// coprime to e
+fn random_prime<R: Rng + ?Sized>(bits: usize, e: &BigUInt, rng: &mut R) -> BigUInt {
+    let top = (BigUInt::from(3u32) << (bits - 2)).add_to(&1u32.into());
+    loop {
+        let candidate = rng.gen_biguint(bits) | top.clone();
+        if candidate.sub_from(&1u32.into()).gcd(e) == BigUInt::from(1u32)
+            && is_probable_prime(&candidate, PRIME_ROUNDS) {
+            return candidate;
+        }
+    }
+}
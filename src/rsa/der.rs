@@ -0,0 +1,181 @@
+use crate::BigUInt;
+use super::{RsaPrivateKey, RsaPublicKey};
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_SEQUENCE: u8 = 0x30;
+
+impl RsaPublicKey {
+    /// Encode as PKCS#1 RSAPublicKey in DER format
+    ///
+    /// # Examples
+    /// ```
+    /// use simple_big_int::rsa::RsaPublicKey;
+    /// let key = RsaPublicKey::new(3233u32.into(), 17u32.into()).unwrap();
+    /// let der = key.to_pkcs1_der();
+    /// assert_eq!(der, vec![0x30, 0x07, 0x02, 0x02, 0x0C, 0xA1, 0x02, 0x01, 0x11]);
+    /// assert_eq!(RsaPublicKey::from_pkcs1_der(&der), Ok(key));
+    /// ```
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        encode_sequence(&[&self.n, &self.e])
+    }
+
+    /// Decode a PKCS#1 RSAPublicKey in DER format
+    pub fn from_pkcs1_der(src: &[u8]) -> Result<RsaPublicKey, String> {
+        let mut reader = DerReader::new(src);
+        let mut sequence = DerReader::new(reader.read(TAG_SEQUENCE)?);
+        reader.finish()?;
+        let n = sequence.read_integer()?;
+        let e = sequence.read_integer()?;
+        sequence.finish()?;
+        RsaPublicKey::new(n, e)
+    }
+}
+
+impl RsaPrivateKey {
+    /// Encode as PKCS#1 RSAPrivateKey in DER format, version 0 with two primes
+    pub fn to_pkcs1_der(&self) -> Vec<u8> {
+        encode_sequence(&[&BigUInt::new(), &self.n, &self.e, &self.d, &self.p, &self.q, &self.dp, &self.dq,
+            &self.qinv])
+    }
+
+    /// Decode a PKCS#1 RSAPrivateKey in DER format.
+    ///
+    /// # Returns
+    /// The key or an error if the encoding is invalid, the key has more than two primes or its
+    /// values are inconsistent
+    pub fn from_pkcs1_der(src: &[u8]) -> Result<RsaPrivateKey, String> {
+        let mut reader = DerReader::new(src);
+        let mut sequence = DerReader::new(reader.read(TAG_SEQUENCE)?);
+        reader.finish()?;
+        if !sequence.read_integer()?.is_zero() {
+            return Err("only version 0 RSA private keys with two primes are supported".to_string());
+        }
+        let mut values = vec![];
+        for _ in 0..8 {
+            values.push(sequence.read_integer()?);
+        }
+        sequence.finish()?;
+        let mut values = values.into_iter();
+        let mut next = || values.next().expect("Unexpected missing value");
+        let key = RsaPrivateKey {
+            n: next(),
+            e: next(),
+            d: next(),
+            p: next(),
+            q: next(),
+            dp: next(),
+            dq: next(),
+            qinv: next(),
+        };
+        key.check()?;
+        Ok(key)
+    }
+
+    /// Check that the values of the key fit together
+    fn check(&self) -> Result<(), String> {
+        RsaPublicKey::new(self.n.clone(), self.e.clone())?;
+        // p, q >= 3 keeps p - 1 and q - 1 from underflowing or being zero
+        let three = BigUInt::from(3u32);
+        if self.p < three || self.q < three || self.p.mul_with(&self.q) != self.n {
+            return Err("inconsistent RSA private key".to_string());
+        }
+        let one = BigUInt::from(1u32);
+        let p_minus_1 = self.p.sub_from(&one);
+        let q_minus_1 = self.q.sub_from(&one);
+        if self.d.div_mod(&p_minus_1).1 != self.dp
+            || self.d.div_mod(&q_minus_1).1 != self.dq
+            || self.e.mul_with(&self.dp).div_mod(&p_minus_1).1 != one
+            || self.e.mul_with(&self.dq).div_mod(&q_minus_1).1 != one
+            || self.q.mul_with(&self.qinv).div_mod(&self.p).1 != one {
+            return Err("inconsistent RSA private key".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Encode a DER SEQUENCE of INTEGERs
+fn encode_sequence(values: &[&BigUInt]) -> Vec<u8> {
+    let mut content = vec![];
+    for value in values {
+        let mut bytes = value.to_bytes_be();
+        // a leading zero byte keeps the integer positive
+        if bytes.first().is_none_or(|byte| byte & 0x80 != 0) {
+            bytes.insert(0, 0);
+        }
+        encode_tlv(TAG_INTEGER, &bytes, &mut content);
+    }
+    let mut res = vec![];
+    encode_tlv(TAG_SEQUENCE, &content, &mut res);
+    res
+}
+
+fn encode_tlv(tag: u8, content: &[u8], dest: &mut Vec<u8>) {
+    dest.push(tag);
+    if content.len() < 0x80 {
+        dest.push(content.len() as u8);
+    } else {
+        let length = BigUInt::from(content.len() as u64).to_bytes_be();
+        dest.push(0x80 | length.len() as u8);
+        dest.extend(length);
+    }
+    dest.extend_from_slice(content);
+}
+
+/// Reads DER elements from a byte slice
+struct DerReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> DerReader<'a> {
+    fn new(data: &'a [u8]) -> DerReader<'a> {
+        DerReader { data }
+    }
+
+    /// Read an element with the given tag and return its content
+    fn read(&mut self, tag: u8) -> Result<&'a [u8], String> {
+        match self.data {
+            [found, ..] if *found != tag => Err(format!("expected DER tag 0x{:02X}, found 0x{:02X}", tag, found)),
+            [_, length, rest @ ..] => {
+                let (length, rest) = if length & 0x80 == 0 {
+                    (*length as usize, rest)
+                } else {
+                    let count = (length & 0x7F) as usize;
+                    if count == 0 || count > 4 || rest.len() < count || rest[0] == 0 {
+                        return Err("invalid DER length".to_string());
+                    }
+                    let length = rest[..count].iter().fold(0usize, |acc, byte| (acc << 8) | *byte as usize);
+                    if length < 0x80 {
+                        return Err("non minimal DER length".to_string());
+                    }
+                    (length, &rest[count..])
+                };
+                if rest.len() < length {
+                    return Err("truncated DER element".to_string());
+                }
+                self.data = &rest[length..];
+                Ok(&rest[..length])
+            }
+            _ => Err("truncated DER element".to_string()),
+        }
+    }
+
+    /// Read a non negative INTEGER
+    fn read_integer(&mut self) -> Result<BigUInt, String> {
+        let content = self.read(TAG_INTEGER)?;
+        match content {
+            [] => Err("empty DER integer".to_string()),
+            [first, ..] if first & 0x80 != 0 => Err("negative DER integer".to_string()),
+            [0, second, ..] if second & 0x80 == 0 => Err("non minimal DER integer".to_string()),
+            _ => Ok(BigUInt::from_bytes_be(content)),
+        }
+    }
+
+    /// Make sure all data has been read
+    fn finish(&self) -> Result<(), String> {
+        if self.data.is_empty() {
+            Ok(())
+        } else {
+            Err("unexpected data after DER element".to_string())
+        }
+    }
+}
@@ -0,0 +1,170 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use crate::BigUInt;
+use super::{RsaPrivateKey, RsaPublicKey};
+
+/// A 512 bit key generated with openssl
+const PRIVATE_KEY: &str = "3082013c020100024100bb58d6d5bd51ec9596cd1f76cf209fa1a72955d91636406b7b6cbf06ff0753472cbd\
+526a3dc7f8e5ade8164ae1a2181c7b5093e911a60cbdc4c672bc2d4e302b02030100010240387ed2d243b417c70861b4f24c05c77da4fe\
+17104edd6f51ba3791e797326b4f103e962bcf2e20dd613fc1475099b060461f15f34a8271025e8460f8db285299022100db22032250d4d6\
+d6c691a93845f1b5595835beccc604bc2610998f79eb26ae17022100daddd20dea4edb2edbc4b48fe8af3f0626ef344315cfd2f8d5d4f0a9\
+a37c0f0d022100cb8d2b961bb9a99b3636a616f4b9d2b81c7d4830151cdedd08fc6bcf5784c59f0221009fbfc6a10783b531d79114e5f145\
+706ff21d287d4abf5d52e8e8bb1c66ca97450221009e855041ead9310e76bcf8adec393bdc5cf6a880822cbbde8b1aab7e94dd05d9";
+
+const PUBLIC_KEY: &str = "3048024100bb58d6d5bd51ec9596cd1f76cf209fa1a72955d91636406b7b6cbf06ff0753472cbd526a3dc7f8\
+e5ade8164ae1a2181c7b5093e911a60cbdc4c672bc2d4e302b0203010001";
+
+/// The example key of oaep-int.txt from the RSA Laboratories PKCS#1 v2.1 test vectors
+const EXAMPLE_N: &str = "bbf82f090682ce9c2338ac2b9da871f7368d07eed41043a440d6b6f07454f51fb8dfbaaf035c02ab61ea48ceeb6f\
+cd4876ed520d60e1ec4619719d8a5b8b807fafb8e0a3dfc737723ee6b4b7d93a2584ee6a649d060953748834b2454598394ee0aab12d7b\
+61a51f527a9a41f6c1687fe2537298ca2a8f5946f8e5fd091dbdcb";
+const EXAMPLE_E: u32 = 0x11;
+const EXAMPLE_D: &str = "a5dafc5341faf289c4b988db30c1cdf83f31251e0668b42784813801579641b29410b3c7998d6bc465745e5c3926\
+69d6870da2c082a939e37fdcb82ec93edac97ff3ad5950accfbc111c76f1a9529444e56aaf68c56c092cd38dc3bef5d20a939926ed4f74\
+a13eddfbe1a1cecc4894af9428c2b7b8883fe4463a4bc85b1cb3c1";
+const EXAMPLE_P: &str = "eecfae81b1b9b3c908810b10a1b5600199eb9f44aef4fda493b81a9e3d84f632124ef0236e5d1e3b7e28fae7aa04\
+0a2d5b252176459d1f397541ba2a58fb6599";
+const EXAMPLE_Q: &str = "c97fb1f027f453f6341233eaaad1d9353f6c42d08866b1d05a0f2035028b9d869840b41666b42e92ea0da3b43204\
+b5cfce3352524d0416a5a441e700af461503";
+const EXAMPLE_DP: &str = "54494ca63eba0337e4e24023fcd69a5aeb07dddc0183a4d0ac9b54b051f2b13ed9490975eab77414ff59c1f769\
+2e9a2e202b38fc910a474174adc93c1f67c981";
+const EXAMPLE_DQ: &str = "471e0290ff0af0750351b7f878864ca961adbd3a8a7e991c5c0556a94c3146a7f9803f8f6f8ae342e931fd8ae4\
+7a220d1b99a495849807fe39f9245a9836da3d";
+const EXAMPLE_QINV: &str = "b06c4fdabb6301198d265bdbae9423b380f271f73453885093077fcd39e2119fc98632154f5883b167a967bf\
+402b4e9e2e0f9656e698ea3666edfb25798039f7";
+/// The OAEP encoded message EM and its RSAES-OAEP encryption C of the example
+const EXAMPLE_EM: &str = "eb7a19ace9e3006350e329504b45e2ca82310b26dcd87d5c68f1eea8f55267c31b2e8bb4251f84d7e0b2c04626\
+f5aff93edcfb25c9c2b3ff8ae10e839a2ddb4cdcfe4ff47728b4a1b7c1362baad29ab48d2869d5024121435811591be392f982fb3e87d0\
+95aeb40448db972f3ac14f7bc275195281ce32d2f1b76d4d353e2d";
+const EXAMPLE_C: &str = "1253e04dc0a5397bb44a7ab87e9bf2a039a33d1e996fc82a94ccd30074c95df763722017069e5268da5d1c0b4f\
+872cf653c11df82314a67968dfeae28def04bb6d84b1c31d654a1970e5783bd6eb96a024c2ca2f4a90fe9f2ef5c9c140e5bb48da9536ad\
+8700c84fc9130adea74e558d51a74ddf85d8b50de96838d6063e0955";
+
+fn from_hex(src: &str) -> Vec<u8> {
+    (0..src.len()).step_by(2).map(|i| u8::from_str_radix(&src[i..i + 2], 16).unwrap()).collect()
+}
+
+#[test]
+fn test_pkcs1_der() {
+    let der = from_hex(PRIVATE_KEY);
+    let key = RsaPrivateKey::from_pkcs1_der(&der).expect("invalid private key");
+    assert_eq!(key.to_pkcs1_der(), der);
+    assert_eq!(key.public_key().n().length(), 512);
+    assert_eq!(*key.public_key().e(), BigUInt::from(65537u32));
+    assert_eq!(key.public_key().to_pkcs1_der(), from_hex(PUBLIC_KEY));
+    assert_eq!(RsaPublicKey::from_pkcs1_der(&from_hex(PUBLIC_KEY)), Ok(key.public_key()));
+    // openssl uses d = e^-1 mod (p - 1)(q - 1), the CRT parameters are the same
+    let rebuilt = RsaPrivateKey::from_primes(key.p().clone(), key.q().clone(), 65537u32.into()).unwrap();
+    assert_eq!((&rebuilt.dp, &rebuilt.dq, &rebuilt.qinv), (&key.dp, &key.dq, &key.qinv));
+
+    // truncated, trailing data, wrong version and inconsistent values
+    assert!(RsaPrivateKey::from_pkcs1_der(&der[..der.len() - 1]).is_err());
+    let mut trailing = der.clone();
+    trailing.push(0);
+    assert!(RsaPrivateKey::from_pkcs1_der(&trailing).is_err());
+    let mut version = der.clone();
+    version[6] = 1;
+    assert!(RsaPrivateKey::from_pkcs1_der(&version).is_err());
+    let mut modified = der.clone();
+    let last = modified.len() - 1;
+    modified[last] ^= 2;
+    assert!(RsaPrivateKey::from_pkcs1_der(&modified).is_err());
+    // p = 0 and p = 1, q = n must not panic
+    let n = BigUInt::from(3233u32);
+    for (p, q) in [(BigUInt::new(), n.clone()), (BigUInt::from(1u32), n.clone())] {
+        let hostile = RsaPrivateKey {
+            n: n.clone(),
+            e: 17u32.into(),
+            d: 413u32.into(),
+            p,
+            q,
+            dp: 53u32.into(),
+            dq: 49u32.into(),
+            qinv: 38u32.into(),
+        };
+        assert!(RsaPrivateKey::from_pkcs1_der(&hostile.to_pkcs1_der()).is_err());
+    }
+    assert!(RsaPublicKey::from_pkcs1_der(&from_hex("300602010f020103")).is_ok());
+    assert!(RsaPublicKey::from_pkcs1_der(&from_hex("300702020080020103")).is_err());
+    assert!(RsaPublicKey::from_pkcs1_der(&from_hex("30070202000f020103")).is_err());
+}
+
+fn example_value(src: &str) -> BigUInt {
+    BigUInt::from_bytes_be(&from_hex(src))
+}
+
+#[test]
+fn test_published_example() {
+    // the example uses d = e^-1 mod (p - 1)(q - 1), the CRT parameters are the same
+    let key = RsaPrivateKey::from_primes(example_value(EXAMPLE_P), example_value(EXAMPLE_Q), EXAMPLE_E.into())
+        .expect("invalid example key");
+    assert_eq!(*key.public_key().n(), example_value(EXAMPLE_N));
+    assert_eq!((&key.dp, &key.dq, &key.qinv),
+               (&example_value(EXAMPLE_DP), &example_value(EXAMPLE_DQ), &example_value(EXAMPLE_QINV)));
+    let published = RsaPrivateKey {
+        d: example_value(EXAMPLE_D),
+        ..key.clone()
+    };
+    assert_eq!(RsaPrivateKey::from_pkcs1_der(&published.to_pkcs1_der()), Ok(published.clone()));
+
+    let message = example_value(EXAMPLE_EM);
+    let ciphertext = example_value(EXAMPLE_C);
+    assert_eq!(key.public_key().encrypt(&message), Ok(ciphertext.clone()));
+    assert_eq!(key.decrypt(&ciphertext), Ok(message.clone()));
+    assert_eq!(ciphertext.modpow(published.d(), key.public_key().n()), message);
+    // C = EM^e mod n, so EM is the RSASP1 signature of C
+    assert_eq!(key.sign(&ciphertext), Ok(message.clone()));
+    assert!(key.public_key().verify(&ciphertext, &message));
+}
+
+#[test]
+fn test_sign_verify() {
+    let key = RsaPrivateKey::from_pkcs1_der(&from_hex(PRIVATE_KEY)).expect("invalid private key");
+    let public = key.public_key();
+    let message = BigUInt::from_bytes_be(&(0u8..64).collect::<Vec<u8>>());
+    let signature = key.sign(&message).expect("sign failed");
+    assert_eq!(signature, message.modpow(key.d(), public.n()));
+    assert!(public.verify(&message, &signature));
+    assert!(!public.verify(&message.add_to(&1u32.into()), &signature));
+    assert!(key.sign(public.n()).is_err());
+    assert!(public.encrypt(public.n()).is_err());
+    assert!(key.decrypt(public.n()).is_err());
+
+    let ciphertext = public.encrypt(&message).expect("encrypt failed");
+    assert_eq!(key.decrypt(&ciphertext), Ok(message));
+}
+
+#[test]
+fn test_generate() {
+    let mut rng = StdRng::seed_from_u64(41);
+    for bits in [32, 65, 128] {
+        let key = RsaPrivateKey::generate(bits, &mut rng);
+        let public = key.public_key();
+        assert_eq!(public.n().length(), bits);
+        assert_ne!(key.p(), key.q());
+        assert_eq!(RsaPrivateKey::from_pkcs1_der(&key.to_pkcs1_der()), Ok(key.clone()));
+        let message = BigUInt::from(0x1234567u32);
+        let ciphertext = public.encrypt(&message).expect("encrypt failed");
+        assert_eq!(key.decrypt(&ciphertext), Ok(message));
+    }
+}
+
+#[test]
+fn test_debug_redacted() {
+    let key = RsaPrivateKey::from_primes(61u32.into(), 53u32.into(), 17u32.into()).unwrap();
+    let debug = format!("{:?}", key);
+    assert!(debug.contains(&format!("{:?}", key.public_key().n())));
+    for secret in [key.d(), key.p(), key.q()] {
+        assert!(!debug.contains(&format!("{:?}", secret)));
+    }
+}
+
+#[test]
+fn test_invalid_keys() {
+    assert!(RsaPublicKey::new(3232u32.into(), 17u32.into()).is_err());
+    assert!(RsaPublicKey::new(3233u32.into(), 16u32.into()).is_err());
+    assert!(RsaPublicKey::new(3233u32.into(), 3233u32.into()).is_err());
+    assert!(RsaPrivateKey::from_primes(61u32.into(), 61u32.into(), 17u32.into()).is_err());
+    // 3 divides lcm(60, 52) = 780
+    assert!(RsaPrivateKey::from_primes(61u32.into(), 53u32.into(), 3u32.into()).is_err());
+}
@@ -0,0 +1,269 @@
+//! Fibonacci, Lucas and general Lucas sequences by doubling formulas, with O(log n)
+//! multiplications, exact or modulo a [Modulus].
+//!
+//! The modular sequences are the base of the Lucas–Lehmer test of Mersenne numbers and the
+//! strong Lucas probable prime test.
+//!
+//! With the `rational` feature the module also has exact Bernoulli, Euler and harmonic numbers
+//! and the values of the zeta function at even integers.
+
+use crate::{BigInt, BigUInt, ModInt, Modulus};
+use crate::number_theory::{jacobi, residue};
+
+#[cfg(test)]
+mod test;
+
+#[cfg(feature = "rational")]
+mod bernoulli;
+
+#[cfg(feature = "rational")]
+pub use bernoulli::*;
+
+/// Calculate the Fibonacci number F(n) with F(0) = 0, F(1) = 1 and F(n + 2) = F(n + 1) + F(n).
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::sequences::fibonacci;
+/// assert_eq!(fibonacci(10), BigUInt::from(55u32));
+/// assert_eq!(fibonacci(100), BigUInt::from(354224848179261915075u128));
+/// ```
+pub fn fibonacci(n: u64) -> BigUInt {
+    fibonacci_pair(n).0
+}
+
+/// Calculate the Lucas number L(n) with L(0) = 2, L(1) = 1 and L(n + 2) = L(n + 1) + L(n).
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::sequences::lucas;
+/// assert_eq!(lucas(0), BigUInt::from(2u32));
+/// assert_eq!(lucas(10), BigUInt::from(123u32));
+/// ```
+pub fn lucas(n: u64) -> BigUInt {
+    // L(n) = F(n - 1) + F(n + 1) = 2 * F(n + 1) - F(n)
+    let (f_n, f_n1) = fibonacci_pair(n);
+    f_n1.add_to(&f_n1).sub_from(&f_n)
+}
+
+/// Calculate F(n) modulo m, for a modulus of any parity.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::{BigUInt, Modulus};
+/// use simple_big_int::sequences::fibonacci_mod;
+/// let modulus = Modulus::new(BigUInt::from(1000000u32));
+/// // the Pisano period modulo 10^6 is 1500000
+/// let period = BigUInt::from(1500000u32).mul_with(&BigUInt::from(10u32).powi(30));
+/// assert!(fibonacci_mod(&period, &modulus).is_zero());
+/// assert_eq!(fibonacci_mod(&period.add_to(&10u32.into()), &modulus).to_biguint(), BigUInt::from(55u32));
+/// ```
+pub fn fibonacci_mod<'a>(n: &BigUInt, modulus: &'a Modulus) -> ModInt<'a> {
+    fibonacci_pair_mod(n, modulus).0
+}
+
+/// Calculate L(n) modulo m, for a modulus of any parity.
+pub fn lucas_mod<'a>(n: &BigUInt, modulus: &'a Modulus) -> ModInt<'a> {
+    let (f_n, f_n1) = fibonacci_pair_mod(n, modulus);
+    f_n1.add_to(&f_n1).sub_from(&f_n)
+}
+
+/// Calculate the Lucas sequences U(n) and V(n) of the parameters P and Q, with U(0) = 0,
+/// U(1) = 1, V(0) = 2, V(1) = P and X(n + 2) = P * X(n + 1) - Q * X(n) for both sequences.
+///
+/// Fibonacci and Lucas numbers are U and V for P = 1, Q = -1.
+///
+/// # Returns
+/// The tuple (U(n), V(n))
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigInt;
+/// use simple_big_int::sequences::lucas_sequence;
+/// // P = 3, Q = 2 gives U(n) = 2^n - 1 and V(n) = 2^n + 1
+/// assert_eq!(lucas_sequence(10, &BigInt::from(3), &BigInt::from(2)), (BigInt::from(1023), BigInt::from(1025)));
+/// // P = 1, Q = 2 has negative values
+/// assert_eq!(lucas_sequence(5, &BigInt::from(1), &BigInt::from(2)), (BigInt::from(-1), BigInt::from(11)));
+/// ```
+pub fn lucas_sequence(n: u64, p: &BigInt, q: &BigInt) -> (BigInt, BigInt) {
+    let two = BigInt::from(2);
+    let d = p.mul_with(p).sub_from(&q.mul_with(&BigInt::from(4)));
+    // U(k), V(k) and Q^k, starting with k = 0
+    let (mut u, mut v, mut q_k) = (BigInt::new(), two.clone(), BigInt::from(1));
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        // U(2k) = U(k) * V(k), V(2k) = V(k)^2 - 2 * Q^k
+        u = u.mul_with(&v).normalized();
+        v = v.mul_with(&v).sub_from(&two.mul_with(&q_k)).normalized();
+        q_k = q_k.mul_with(&q_k).normalized();
+        if (n >> bit) & 1 == 1 {
+            // U(k + 1) = (P * U(k) + V(k)) / 2, V(k + 1) = (D * U(k) + P * V(k)) / 2
+            let next_u = half(&p.mul_with(&u).add_to(&v));
+            v = half(&d.mul_with(&u).add_to(&p.mul_with(&v)));
+            u = next_u;
+            q_k = q_k.mul_with(q).normalized();
+        }
+    }
+    (u, v)
+}
+
+/// Calculate the Lucas sequences U(n) and V(n) of the parameters P and Q modulo an odd modulus.
+///
+/// # Returns
+/// The tuple (U(n) mod m, V(n) mod m)
+///
+/// # Panics
+/// Panics if the modulus is even.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::{BigInt, BigUInt, Modulus};
+/// use simple_big_int::sequences::lucas_sequence_mod;
+/// let modulus = Modulus::new(BigUInt::from(1000003u32));
+/// let (u, v) = lucas_sequence_mod(&BigUInt::from(10u32), &BigInt::from(3), &BigInt::from(2), &modulus);
+/// assert_eq!((u.to_biguint(), v.to_biguint()), (BigUInt::from(1023u32), BigUInt::from(1025u32)));
+/// ```
+pub fn lucas_sequence_mod<'a>(n: &BigUInt, p: &BigInt, q: &BigInt, modulus: &'a Modulus)
+                              -> (ModInt<'a>, ModInt<'a>) {
+    let (u, v, _) = lucas_sequence_mod_q(n, p, q, modulus);
+    (u, v)
+}
+
+/// Check if the Mersenne number 2^p - 1 is prime with the Lucas–Lehmer test.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::sequences::lucas_lehmer;
+/// let exponents: Vec<u32> = (2..130).filter(|p| lucas_lehmer(*p)).collect();
+/// assert_eq!(exponents, vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127]);
+/// ```
+pub fn lucas_lehmer(p: u32) -> bool {
+    if p == 2 {
+        return true;
+    }
+    // 2^p - 1 is composite for composite p
+    if p < 2 || (2..).take_while(|d| d * d <= p).any(|d| p.is_multiple_of(d)) {
+        return false;
+    }
+    let modulus = Modulus::new((BigUInt::from(1u32) << p as usize).sub_from(&1u32.into()));
+    let two = modulus.element(&2u32.into());
+    // s(0) = 4, s(i + 1) = s(i)^2 - 2, 2^p - 1 is prime if s(p - 2) = 0
+    let mut s = modulus.element(&4u32.into());
+    for _ in 0..p - 2 {
+        s = s.mul_with(&s).sub_from(&two);
+    }
+    s.is_zero()
+}
+
+/// Check if n is a strong Lucas probable prime with the parameters of Selfridge's method A,
+/// the first D of 5, -7, 9, -11, ... with the Jacobi symbol (D / n) = -1, P = 1 and
+/// Q = (1 - D) / 4.
+///
+/// With n + 1 = d * 2^s and d odd, n is a strong Lucas probable prime if U(d) = 0 or
+/// V(d * 2^r) = 0 modulo n for some 0 <= r < s. Combined with a Miller–Rabin test to base 2 this
+/// is the Baillie–PSW test.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigUInt;
+/// use simple_big_int::sequences::is_strong_lucas_probable_prime;
+/// assert!(is_strong_lucas_probable_prime(&BigUInt::from(1000003u32)));
+/// assert!(!is_strong_lucas_probable_prime(&BigUInt::from(1000001u32)));
+/// // the smallest strong Lucas pseudoprime
+/// assert!(is_strong_lucas_probable_prime(&BigUInt::from(5459u32)));
+/// ```
+pub fn is_strong_lucas_probable_prime(n: &BigUInt) -> bool {
+    if *n == BigUInt::from(2u32) {
+        return true;
+    }
+    if n.is_even() || *n < BigUInt::from(2u32) {
+        return false;
+    }
+    let mut d = 5i64;
+    let mut tries = 0;
+    loop {
+        match jacobi(&BigInt::from(d), n) {
+            -1 => break,
+            // a common factor, unless n is |D| itself
+            0 if *n != BigUInt::from(d.unsigned_abs()) => return false,
+            _ => {}
+        }
+        tries += 1;
+        // (D / n) is never -1 for a square n
+        if tries == 10 && n.sqrt().powi(2) == *n {
+            return false;
+        }
+        d = if d > 0 { -d - 2 } else { -d + 2 };
+    }
+    let p = BigInt::from(1);
+    let q = BigInt::from((1 - d) / 4);
+    let n_plus_1 = n.add_to(&1u32.into());
+    let s = n_plus_1.trailing_zeros() as usize;
+    let modulus = Modulus::new(n.clone());
+    let (u, mut v, mut q_k) = lucas_sequence_mod_q(&n_plus_1.shift_right(s), &p, &q, &modulus);
+    if u.is_zero() || v.is_zero() {
+        return true;
+    }
+    for _ in 1..s {
+        // V(2k) = V(k)^2 - 2 * Q^k
+        v = v.mul_with(&v).sub_from(&q_k.add_to(&q_k));
+        if v.is_zero() {
+            return true;
+        }
+        q_k = q_k.mul_with(&q_k);
+    }
+    false
+}
+
+/// The pair (F(n), F(n + 1)) by fast doubling
+fn fibonacci_pair(n: u64) -> (BigUInt, BigUInt) {
+    let (mut a, mut b) = (BigUInt::new(), BigUInt::from(1u32));
+    for bit in (0..u64::BITS - n.leading_zeros()).rev() {
+        // F(2k) = F(k) * (2 * F(k + 1) - F(k)), F(2k + 1) = F(k)^2 + F(k + 1)^2
+        let c = a.mul_with(&b.add_to(&b).sub_from(&a));
+        let d = a.mul_with(&a).add_to(&b.mul_with(&b));
+        (a, b) = if (n >> bit) & 1 == 1 { (d.clone(), c.add_to(&d)) } else { (c, d) };
+    }
+    (a, b)
+}
+
+/// The pair (F(n), F(n + 1)) modulo m by fast doubling
+fn fibonacci_pair_mod<'a>(n: &BigUInt, modulus: &'a Modulus) -> (ModInt<'a>, ModInt<'a>) {
+    let (mut a, mut b) = (modulus.zero(), modulus.one());
+    for bit in n.iter() {
+        let c = a.mul_with(&b.add_to(&b).sub_from(&a));
+        let d = a.mul_with(&a).add_to(&b.mul_with(&b));
+        (a, b) = if bit { (d.clone(), c.add_to(&d)) } else { (c, d) };
+    }
+    (a, b)
+}
+
+/// The tuple (U(n), V(n), Q^n) modulo an odd modulus
+fn lucas_sequence_mod_q<'a>(n: &BigUInt, p: &BigInt, q: &BigInt, modulus: &'a Modulus)
+                            -> (ModInt<'a>, ModInt<'a>, ModInt<'a>) {
+    assert!(modulus.modulus().is_odd(), "modulus must be odd");
+    let p = modulus.element(&residue(p, modulus.modulus()));
+    let q = modulus.element(&residue(q, modulus.modulus()));
+    let four = modulus.element(&4u32.into());
+    let d = p.mul_with(&p).sub_from(&four.mul_with(&q));
+    // 1 / 2 = (m + 1) / 2 for odd m
+    let half = modulus.element(&modulus.modulus().add_to(&1u32.into()).shift_right(1));
+    let (mut u, mut v, mut q_k) = (modulus.zero(), modulus.element(&2u32.into()), modulus.one());
+    for bit in n.iter() {
+        u = u.mul_with(&v);
+        v = v.mul_with(&v).sub_from(&q_k.add_to(&q_k));
+        q_k = q_k.mul_with(&q_k);
+        if bit {
+            let next_u = p.mul_with(&u).add_to(&v).mul_with(&half);
+            v = d.mul_with(&u).add_to(&p.mul_with(&v)).mul_with(&half);
+            u = next_u;
+            q_k = q_k.mul_with(&q);
+        }
+    }
+    (u, v, q_k)
+}
+
+/// x / 2 for an even x
+fn half(x: &BigInt) -> BigInt {
+    BigInt::from_sign_magnitude(x.is_negative(), x.magnitude().shift_right(1))
+}
@@ -0,0 +1,147 @@
+use crate::{BigInt, BigUInt, Rational};
+
+/// Calculate the Bernoulli number B(n) from the tangent numbers, with
+/// B(2k) = (-1)^(k - 1) * 2k * T(k) / (2^2k * (2^2k - 1)).
+///
+/// B(1) is -1/2, the other odd Bernoulli numbers are 0.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::Rational;
+/// use simple_big_int::sequences::bernoulli;
+/// assert_eq!(bernoulli(1), Rational::from((-1, 2)));
+/// assert_eq!(bernoulli(12), Rational::from((-691, 2730)));
+/// assert!(bernoulli(13).is_zero());
+/// ```
+pub fn bernoulli(n: u64) -> Rational {
+    match n {
+        0 => Rational::from(1),
+        1 => Rational::from((-1, 2)),
+        _ if n % 2 == 1 => Rational::new(),
+        _ => {
+            let k = n / 2;
+            let tangent = tangent_numbers(k).pop().expect("Unexpected empty tangent numbers");
+            let power = BigUInt::from(1u32) << n as usize;
+            let numerator = BigInt::from_sign_magnitude(k.is_multiple_of(2), tangent.mul_with(&BigUInt::from(n)));
+            let denominator = power.mul_with(&power.sub_from(&1u32.into()));
+            Rational::from(numerator).div_by(&Rational::from(denominator))
+        }
+    }
+}
+
+/// Calculate the Euler number E(n), the coefficients of 1 / cosh(x) = sum E(n) * x^n / n!, from
+/// the secant numbers, E(2k) = (-1)^k * S(k). The odd Euler numbers are 0.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::BigInt;
+/// use simple_big_int::sequences::euler;
+/// let values: Vec<BigInt> = (0..9).map(euler).collect();
+/// assert_eq!(values, [1, 0, -1, 0, 5, 0, -61, 0, 1385].map(BigInt::from));
+/// ```
+pub fn euler(n: u64) -> BigInt {
+    if n % 2 == 1 {
+        BigInt::new()
+    } else {
+        let k = n / 2;
+        let secant = secant_numbers(k).pop().expect("Unexpected empty secant numbers");
+        BigInt::from_sign_magnitude(k % 2 == 1, secant)
+    }
+}
+
+/// Calculate the harmonic number H(n) = 1 + 1/2 + ... + 1/n, summing the fractions by binary
+/// splitting and reducing only the final result.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::Rational;
+/// use simple_big_int::sequences::harmonic;
+/// assert_eq!(harmonic(0), Rational::new());
+/// assert_eq!(harmonic(10), Rational::from((7381, 2520)));
+/// ```
+pub fn harmonic(n: u64) -> Rational {
+    if n == 0 {
+        return Rational::new();
+    }
+    let (numerator, denominator) = harmonic_range(1, n + 1);
+    Rational::from(numerator).div_by(&Rational::from(denominator))
+}
+
+/// Calculate the Riemann zeta function at an even integer, zeta(2k) = c * pi^(2k) with the
+/// rational coefficient c = (-1)^(k + 1) * B(2k) * 2^(2k - 1) / (2k)!.
+///
+/// # Returns
+/// The tuple (c, 2k) of the coefficient and the exponent of pi, zeta(0) is -1/2 * pi^0
+///
+/// # Panics
+/// Panics if s is odd.
+///
+/// # Examples
+/// ```
+/// use simple_big_int::Rational;
+/// use simple_big_int::sequences::zeta_even;
+/// // the Basel problem, zeta(2) = pi^2 / 6
+/// assert_eq!(zeta_even(2), (Rational::from((1, 6)), 2));
+/// assert_eq!(zeta_even(4), (Rational::from((1, 90)), 4));
+/// assert_eq!(zeta_even(0), (Rational::from((-1, 2)), 0));
+/// ```
+pub fn zeta_even(s: u64) -> (Rational, u64) {
+    assert!(s.is_multiple_of(2), "zeta has no closed form at odd integers");
+    let factorial = (2..=s).fold(BigUInt::from(1u32), |acc, i| acc.mul_with(&BigUInt::from(i)));
+    let power = if s == 0 {
+        Rational::from((1, 2))
+    } else {
+        Rational::from(BigUInt::from(1u32) << (s as usize - 1))
+    };
+    let mut coefficient = bernoulli(s).mul_by(&power).div_by(&Rational::from(factorial));
+    if s.is_multiple_of(4) {
+        coefficient = Rational::new().sub_from(&coefficient);
+    }
+    (coefficient, s)
+}
+
+/// The tangent numbers T(1) .. T(n), T(k) = (2k - 1)-th derivative of tan at 0, by the algorithm
+/// of Brent and Harvey with O(n^2) small multiplications
+fn tangent_numbers(n: u64) -> Vec<BigUInt> {
+    let n = n as usize;
+    let mut values = vec![BigUInt::from(1u32); n];
+    for k in 1..n {
+        values[k] = values[k - 1].mul_with(&BigUInt::from(k as u64));
+    }
+    for k in 1..n {
+        for j in k..n {
+            values[j] = values[j - 1].mul_with(&BigUInt::from((j - k) as u64))
+                .add_to(&values[j].mul_with(&BigUInt::from((j - k + 2) as u64)));
+        }
+    }
+    values
+}
+
+/// The secant numbers S(0) .. S(n), S(k) = 2k-th derivative of sec at 0, by the algorithm of
+/// Brent and Harvey
+fn secant_numbers(n: u64) -> Vec<BigUInt> {
+    let n = n as usize;
+    let mut values = vec![BigUInt::from(1u32); n + 1];
+    for k in 1..=n {
+        values[k] = values[k - 1].mul_with(&BigUInt::from(k as u64));
+    }
+    for k in 1..=n {
+        for j in k + 1..=n {
+            values[j] = values[j - 1].mul_with(&BigUInt::from((j - k) as u64))
+                .add_to(&values[j].mul_with(&BigUInt::from((j - k + 1) as u64)));
+        }
+    }
+    values
+}
+
+/// The sum 1/a + ... + 1/(b - 1) as an unreduced fraction (p, q)
+fn harmonic_range(a: u64, b: u64) -> (BigUInt, BigUInt) {
+    if b - a == 1 {
+        (BigUInt::from(1u32), BigUInt::from(a))
+    } else {
+        let middle = a + (b - a) / 2;
+        let (p1, q1) = harmonic_range(a, middle);
+        let (p2, q2) = harmonic_range(middle, b);
+        (p1.mul_with(&q2).add_to(&p2.mul_with(&q1)), q1.mul_with(&q2))
+    }
+}
@@ -0,0 +1,120 @@
+use crate::{BigInt, BigUInt, Modulus};
+use crate::number_theory::{is_probable_prime, residue};
+use super::{fibonacci, fibonacci_mod, is_strong_lucas_probable_prime, lucas, lucas_lehmer, lucas_mod, lucas_sequence,
+            lucas_sequence_mod};
+
+#[cfg(feature = "rational")]
+use crate::Rational;
+
+#[cfg(feature = "rational")]
+use super::{bernoulli, euler, harmonic, zeta_even};
+
+#[test]
+fn test_fibonacci_lucas() {
+    let (mut f, mut f_next) = (BigUInt::new(), BigUInt::from(1u32));
+    let (mut l, mut l_next) = (BigUInt::from(2u32), BigUInt::from(1u32));
+    let moduli = [Modulus::new(BigUInt::from(1000u32)), Modulus::new(BigUInt::from(997u32))];
+    for n in 0..300u64 {
+        assert_eq!(fibonacci(n), f, "{}", n);
+        assert_eq!(lucas(n), l, "{}", n);
+        for modulus in moduli.iter() {
+            assert_eq!(fibonacci_mod(&BigUInt::from(n), modulus).to_biguint(), f.div_mod(modulus.modulus()).1);
+            assert_eq!(lucas_mod(&BigUInt::from(n), modulus).to_biguint(), l.div_mod(modulus.modulus()).1);
+        }
+        (f, f_next) = (f_next.clone(), f.add_to(&f_next));
+        (l, l_next) = (l_next.clone(), l.add_to(&l_next));
+    }
+    // F(2n) = F(n) * L(n)
+    assert_eq!(fibonacci(10000), fibonacci(5000).mul_with(&lucas(5000)));
+}
+
+#[test]
+fn test_lucas_sequence() {
+    let modulus = Modulus::new(BigUInt::from(1000003u32));
+    for p in -4i64..=4 {
+        for q in -4i64..=4 {
+            let (mut u, mut u_next) = (BigInt::new(), BigInt::from(1));
+            let (mut v, mut v_next) = (BigInt::from(2), BigInt::from(p));
+            for n in 0..60u64 {
+                let (big_p, big_q) = (BigInt::from(p), BigInt::from(q));
+                let (seq_u, seq_v) = lucas_sequence(n, &big_p, &big_q);
+                assert!(seq_u.sub_from(&u).is_zero() && seq_v.sub_from(&v).is_zero(), "{} {} {}", p, q, n);
+                assert_eq!(seq_u.is_negative(), u.is_negative() && !u.is_zero());
+                let (mod_u, mod_v) = lucas_sequence_mod(&BigUInt::from(n), &big_p, &big_q, &modulus);
+                assert_eq!(mod_u.to_biguint(), residue(&u, modulus.modulus()));
+                assert_eq!(mod_v.to_biguint(), residue(&v, modulus.modulus()));
+                (u, u_next) = (u_next.clone(), big_p.mul_with(&u_next).sub_from(&big_q.mul_with(&u)));
+                (v, v_next) = (v_next.clone(), big_p.mul_with(&v_next).sub_from(&big_q.mul_with(&v)));
+            }
+        }
+    }
+    assert_eq!(lucas_sequence(1000, &BigInt::from(1), &BigInt::from(-1)), (BigInt::from(fibonacci(1000)),
+                                                                             BigInt::from(lucas(1000))));
+}
+
+#[test]
+fn test_lucas_lehmer() {
+    let exponents: Vec<u32> = (0..700).filter(|p| lucas_lehmer(*p)).collect();
+    assert_eq!(exponents, vec![2, 3, 5, 7, 13, 17, 19, 31, 61, 89, 107, 127, 521, 607]);
+}
+
+#[test]
+fn test_strong_lucas() {
+    // the strong Lucas pseudoprimes below 25000
+    let pseudoprimes = [5459u64, 5777, 10877, 16109, 18971, 22499, 24569];
+    for n in 0u64..25000 {
+        let big_n = BigUInt::from(n);
+        assert_eq!(is_strong_lucas_probable_prime(&big_n),
+                   is_probable_prime(&big_n, 2) || pseudoprimes.contains(&n), "{}", n);
+    }
+    // squares of primes
+    assert!(!is_strong_lucas_probable_prime(&BigUInt::from(1000003u64 * 1000003)));
+    let mersenne = (BigUInt::from(1u32) << 521).sub_from(&1u32.into());
+    assert!(is_strong_lucas_probable_prime(&mersenne));
+    assert!(!is_strong_lucas_probable_prime(&mersenne.add_to(&2u32.into())));
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn test_bernoulli_euler() {
+    let published = [(0, 1, 1), (1, -1, 2), (2, 1, 6), (3, 0, 1), (4, -1, 30), (6, 1, 42), (8, -1, 30), (10, 5, 66),
+        (12, -691, 2730), (14, 7, 6), (16, -3617, 510), (18, 43867, 798), (20, -174611, 330), (21, 0, 1)];
+    for (n, numerator, denominator) in published {
+        assert_eq!(bernoulli(n), Rational::from((numerator, denominator)), "{}", n);
+    }
+    let b60 = bernoulli(60);
+    assert!(b60.is_negative());
+    assert_eq!(b60.numerator().to_dec_string(), "1215233140483755572040304994079820246041491");
+    assert_eq!(b60.denominator().to_dec_string(), "56786730");
+    // von Staudt–Clausen: the denominator of B(2k) is the product of the primes p with p - 1 | 2k
+    let b100 = bernoulli(100);
+    assert_eq!(*b100.denominator(), BigUInt::from(2u32 * 3 * 5 * 11 * 101));
+
+    assert_eq!(euler(10), BigInt::from(-50521));
+    assert_eq!(euler(20), BigInt::from(370371188237525i64));
+    assert!(euler(7).is_zero());
+    assert_eq!(euler(40).to_dec_str(), "14851150718114980017877156781405826684425");
+}
+
+#[cfg(feature = "rational")]
+#[test]
+fn test_harmonic_zeta() {
+    // H(n) = H(n - 1) + 1/n
+    let mut expected = Rational::new();
+    for n in 1..60u64 {
+        expected = expected.add_to(&Rational::from((1u64, n)));
+        assert_eq!(harmonic(n), expected, "{}", n);
+    }
+    let h100 = harmonic(100);
+    assert_eq!(h100.numerator().to_dec_string(), "14466636279520351160221518043104131447711");
+    assert_eq!(h100.denominator().to_dec_string(), "2788815009188499086581352357412492142272");
+
+    let published = [(2, 1, 6), (4, 1, 90), (6, 1, 945), (8, 1, 9450), (10, 1, 93555), (12, 691, 638512875)];
+    for (s, numerator, denominator) in published {
+        assert_eq!(zeta_even(s), (Rational::from((numerator, denominator)), s), "{}", s);
+    }
+    // zeta(2k) approaches 1, so the coefficient approaches pi^-2k
+    let (coefficient, _) = zeta_even(40);
+    let pi_40 = coefficient.invert().to_f64().unwrap();
+    assert!((pi_40 / std::f64::consts::PI.powi(40) - 1.0).abs() < 1e-10);
+}